@@ -86,3 +86,10 @@ fn word_wrap(b: &mut Bencher) {
         "asdf sdf s df d sd\n sf  sfg  sg   g\n      sfdgsg\n gfdga a\n     ".word_wrap(3);
     });
 }
+
+#[bench]
+fn word_wrap_unbroken(b: &mut Bencher) {
+    use array_tool::string::WordWrap;
+    let blob = "QUJDREVGR0hJSktMTU5PUFFSU1RVVldYWVo=".repeat(1000);
+    b.iter(|| blob.word_wrap(76));
+}
@@ -1,21 +1,21 @@
 use std::cmp;
-use std::iter::IntoIterator;
+use std::collections::VecDeque;
+use std::fmt;
+use std::iter::{Fuse, FusedIterator, IntoIterator, Peekable};
 
 #[doc(hidden)]
 #[derive(Clone, Debug)]
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
 pub struct ZipOption<A, B> {
+    // There is no specialized path keeping an index and length, as the standard
+    // library's zip has: it needs the unstable `TrustedRandomAccess`.  The lengths come
+    // from the iterators themselves, which `next_back` and `ExactSizeIterator` rely on.
     a: A,
     b: B,
-    // index and len are only used by the specialized version of zip
-    #[allow(dead_code)]
-    index: usize,
-    #[allow(dead_code)]
-    len: usize,
 }
 
 /// Zips to iterators together to the longest length
-/// via Option<(Option<A>, Option<B>)>
+/// via Option<(Option<A>, Option<B>)> or Option<EitherOrBoth<A, B>>
 pub trait ZipOpt {
     /// Zip to iterators to longest length via Option<(Option<A>, Option<B>)> results.
     /// # Example
@@ -41,6 +41,100 @@ pub trait ZipOpt {
     where
         Self: Sized,
         U: IntoIterator;
+
+    /// Zip to iterators to longest length via Option<EitherOrBoth<A, B>> results, which
+    /// unlike `zip_option` can't hold an item with neither side.
+    /// # Example
+    /// ```
+    /// use array_tool::iter::{ZipOpt, EitherOrBoth};
+    ///
+    /// let a = vec!["a","b","c"];
+    /// let b = vec![1];
+    /// let mut x = a.iter().zip_longest(b.iter());
+    ///
+    /// assert_eq!(x.next(), Some(EitherOrBoth::Both(&"a", &1)));
+    /// assert_eq!(x.next(), Some(EitherOrBoth::Left(&"b")));
+    /// assert_eq!(x.next(), Some(EitherOrBoth::Left(&"c")));
+    /// assert_eq!(x.next(), None);
+    /// ```
+    fn zip_longest<U>(self, other: U) -> ZipLongest<Self, U::IntoIter>
+    where
+        Self: Sized,
+        U: IntoIterator;
+
+    /// Zip to iterators to longest length via Option<(A, B)> results, padding the
+    /// shorter side with copies of `fill_a` or `fill_b`.
+    /// # Example
+    /// ```
+    /// use array_tool::iter::ZipOpt;
+    ///
+    /// let a = vec![1, 2, 0];
+    /// let b = vec![1, 3];
+    /// let x: Vec<(u8, u8)> = a.into_iter().zip_longest_fill(b, 0, 0).collect();
+    ///
+    /// assert_eq!(x, vec![(1, 1), (2, 3), (0, 0)]);
+    /// ```
+    fn zip_longest_fill<U>(
+        self,
+        other: U,
+        fill_a: <Self as Iterator>::Item,
+        fill_b: U::Item,
+    ) -> ZipLongestFill<Self, U::IntoIter>
+    where
+        Self: Iterator + Sized,
+        U: IntoIterator,
+        <Self as Iterator>::Item: Clone,
+        U::Item: Clone;
+
+    /// Zip to iterators to longest length via Option<(A, B)> results, padding the
+    /// shorter side with default values.
+    /// # Example
+    /// ```
+    /// use array_tool::iter::ZipOpt;
+    ///
+    /// let names = vec!["x", "y"];
+    /// let sizes = vec![10];
+    /// let x: Vec<(&str, u32)> = names.into_iter().zip_longest_default(sizes).collect();
+    ///
+    /// assert_eq!(x, vec![("x", 10), ("y", 0)]);
+    /// ```
+    fn zip_longest_default<U>(self, other: U) -> ZipLongestDefault<Self, U::IntoIter>
+    where
+        Self: Iterator + Sized,
+        U: IntoIterator,
+        <Self as Iterator>::Item: Default,
+        U::Item: Default;
+
+    /// Zip to iterators to longest length via Option<(A, B)> results, padding the
+    /// shorter side with what `fill_a` or `fill_b` return for the index of the item.
+    /// # Example
+    /// ```
+    /// use array_tool::iter::ZipOpt;
+    ///
+    /// let labels = vec!["total"];
+    /// let values = vec![7, 8, 9];
+    /// let x: Vec<(String, u32)> = labels.into_iter()
+    ///   .map(String::from)
+    ///   .zip_longest_with(values, |i| format!("column {}", i), |_| 0)
+    ///   .collect();
+    ///
+    /// assert_eq!(x, vec![
+    ///   ("total".to_string(), 7),
+    ///   ("column 1".to_string(), 8),
+    ///   ("column 2".to_string(), 9),
+    /// ]);
+    /// ```
+    fn zip_longest_with<U, F, G>(
+        self,
+        other: U,
+        fill_a: F,
+        fill_b: G,
+    ) -> ZipLongestWith<Self, U::IntoIter, F, G>
+    where
+        Self: Iterator + Sized,
+        U: IntoIterator,
+        F: FnMut(usize) -> <Self as Iterator>::Item,
+        G: FnMut(usize) -> U::Item;
 }
 
 impl<I: Iterator> ZipOpt for I {
@@ -52,6 +146,67 @@ impl<I: Iterator> ZipOpt for I {
     {
         ZipOption::new(self, other.into_iter())
     }
+
+    #[inline]
+    fn zip_longest<U>(self, other: U) -> ZipLongest<Self, U::IntoIter>
+    where
+        Self: Sized,
+        U: IntoIterator,
+    {
+        ZipLongest {
+            a: self,
+            b: other.into_iter(),
+        }
+    }
+
+    #[inline]
+    fn zip_longest_fill<U>(
+        self,
+        other: U,
+        fill_a: I::Item,
+        fill_b: U::Item,
+    ) -> ZipLongestFill<Self, U::IntoIter>
+    where
+        U: IntoIterator,
+        I::Item: Clone,
+        U::Item: Clone,
+    {
+        ZipLongestFill {
+            zip: self.zip_longest(other),
+            fill_a,
+            fill_b,
+        }
+    }
+
+    #[inline]
+    fn zip_longest_default<U>(self, other: U) -> ZipLongestDefault<Self, U::IntoIter>
+    where
+        U: IntoIterator,
+        I::Item: Default,
+        U::Item: Default,
+    {
+        self.zip_longest_with(other, |_| Default::default(), |_| Default::default())
+    }
+
+    #[inline]
+    fn zip_longest_with<U, F, G>(
+        self,
+        other: U,
+        fill_a: F,
+        fill_b: G,
+    ) -> ZipLongestWith<Self, U::IntoIter, F, G>
+    where
+        U: IntoIterator,
+        F: FnMut(usize) -> I::Item,
+        G: FnMut(usize) -> U::Item,
+    {
+        ZipLongestWith {
+            zip: self.zip_longest(other),
+            index: 0,
+            fill_a,
+            fill_b,
+        }
+    }
 }
 
 impl<A, B> Iterator for ZipOption<A, B>
@@ -89,6 +244,20 @@ where
     }
 }
 
+impl<A, B> ExactSizeIterator for ZipOption<A, B>
+where
+    A: ExactSizeIterator,
+    B: ExactSizeIterator,
+{
+}
+
+impl<A, B> FusedIterator for ZipOption<A, B>
+where
+    A: FusedIterator,
+    B: FusedIterator,
+{
+}
+
 #[doc(hidden)]
 trait ZipImpl<A, B> {
     type Item;
@@ -119,12 +288,7 @@ where
 {
     type Item = (Option<A::Item>, Option<B::Item>);
     fn new(a: A, b: B) -> Self {
-        ZipOption {
-            a,
-            b,
-            index: 0, // unused
-            len: 0,   // unused
-        }
+        ZipOption { a, b }
     }
 
     #[inline]
@@ -150,23 +314,24 @@ where
         A: DoubleEndedIterator + ExactSizeIterator,
         B: DoubleEndedIterator + ExactSizeIterator,
     {
+        // Only the longer iterator has an item at the back until they are even.
         let a_sz = self.a.len();
         let b_sz = self.b.len();
-        if a_sz != b_sz {
-            // Adjust a, b to equal length
-            if a_sz > b_sz {
-                for _ in 0..a_sz - b_sz {
-                    self.a.next_back();
-                }
-            } else {
-                for _ in 0..b_sz - a_sz {
-                    self.b.next_back();
-                }
-            }
-        }
-        match (self.a.next_back(), self.b.next_back()) {
-            (None, None) => None,
-            (f, s) => Some((f, s)),
+        let first = if a_sz >= b_sz {
+            self.a.next_back()
+        } else {
+            None
+        };
+        let second = if b_sz >= a_sz {
+            self.b.next_back()
+        } else {
+            None
+        };
+
+        if first.is_some() || second.is_some() {
+            Some((first, second))
+        } else {
+            None
         }
     }
 
@@ -175,15 +340,1264 @@ where
         let (a_lower, a_upper) = self.a.size_hint();
         let (b_lower, b_upper) = self.b.size_hint();
 
-        let lower = cmp::min(a_lower, b_lower);
+        // The longer iterator decides the length.
+        let lower = cmp::max(a_lower, b_lower);
 
         let upper = match (a_upper, b_upper) {
             (Some(x), Some(y)) => Some(cmp::max(x, y)),
-            (Some(x), None) => Some(x),
-            (None, Some(y)) => Some(y),
-            (None, None) => None,
+            _ => None,
         };
 
         (lower, upper)
     }
 }
+
+/// An item from `zip_longest`: values from both iterators, or from only the one left over.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EitherOrBoth<A, B> {
+    /// Both iterators had a value.
+    Both(A, B),
+    /// Only the first iterator had a value.
+    Left(A),
+    /// Only the second iterator had a value.
+    Right(B),
+}
+
+impl<A, B> EitherOrBoth<A, B> {
+    /// True when there is a first value.
+    pub fn has_left(&self) -> bool {
+        self.as_ref().left().is_some()
+    }
+
+    /// True when there is a second value.
+    pub fn has_right(&self) -> bool {
+        self.as_ref().right().is_some()
+    }
+
+    /// True when there are both values.
+    pub fn is_both(&self) -> bool {
+        matches!(*self, EitherOrBoth::Both(..))
+    }
+
+    /// The first value, if any.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::iter::EitherOrBoth;
+    ///
+    /// assert_eq!(EitherOrBoth::Both::<u8, char>(1, 'a').left(), Some(1));
+    /// assert_eq!(EitherOrBoth::Right::<u8, char>('a').left(), None);
+    /// ```
+    pub fn left(self) -> Option<A> {
+        match self {
+            EitherOrBoth::Both(a, _) | EitherOrBoth::Left(a) => Some(a),
+            EitherOrBoth::Right(_) => None,
+        }
+    }
+
+    /// The second value, if any.
+    pub fn right(self) -> Option<B> {
+        match self {
+            EitherOrBoth::Both(_, b) | EitherOrBoth::Right(b) => Some(b),
+            EitherOrBoth::Left(_) => None,
+        }
+    }
+
+    /// Both values, if there are both.
+    pub fn both(self) -> Option<(A, B)> {
+        match self {
+            EitherOrBoth::Both(a, b) => Some((a, b)),
+            _ => None,
+        }
+    }
+
+    /// The values as `zip_option` gives them.
+    pub fn left_and_right(self) -> (Option<A>, Option<B>) {
+        match self {
+            EitherOrBoth::Both(a, b) => (Some(a), Some(b)),
+            EitherOrBoth::Left(a) => (Some(a), None),
+            EitherOrBoth::Right(b) => (None, Some(b)),
+        }
+    }
+
+    /// Borrows the values.
+    pub fn as_ref(&self) -> EitherOrBoth<&A, &B> {
+        match *self {
+            EitherOrBoth::Both(ref a, ref b) => EitherOrBoth::Both(a, b),
+            EitherOrBoth::Left(ref a) => EitherOrBoth::Left(a),
+            EitherOrBoth::Right(ref b) => EitherOrBoth::Right(b),
+        }
+    }
+
+    /// Both values, using the given ones for a missing side.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::iter::EitherOrBoth;
+    ///
+    /// assert_eq!(EitherOrBoth::Left::<u8, char>(1).or(0, '-'), (1, '-'));
+    /// ```
+    pub fn or(self, left: A, right: B) -> (A, B) {
+        match self {
+            EitherOrBoth::Both(a, b) => (a, b),
+            EitherOrBoth::Left(a) => (a, right),
+            EitherOrBoth::Right(b) => (left, b),
+        }
+    }
+
+    /// Both values, using the default for a missing side.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::iter::{ZipOpt, EitherOrBoth};
+    ///
+    /// let totals: Vec<(u32, u32)> = vec![3, 4, 5].into_iter()
+    ///   .zip_longest(vec![1])
+    ///   .map(EitherOrBoth::or_default)
+    ///   .collect();
+    /// assert_eq!(totals, vec![(3, 1), (4, 0), (5, 0)]);
+    /// ```
+    pub fn or_default(self) -> (A, B)
+    where
+        A: Default,
+        B: Default,
+    {
+        match self {
+            EitherOrBoth::Both(a, b) => (a, b),
+            EitherOrBoth::Left(a) => (a, B::default()),
+            EitherOrBoth::Right(b) => (A::default(), b),
+        }
+    }
+
+    /// Both values, calling the given closures for a missing side.
+    pub fn or_else<L, R>(self, left: L, right: R) -> (A, B)
+    where
+        L: FnOnce() -> A,
+        R: FnOnce() -> B,
+    {
+        match self {
+            EitherOrBoth::Both(a, b) => (a, b),
+            EitherOrBoth::Left(a) => (a, right()),
+            EitherOrBoth::Right(b) => (left(), b),
+        }
+    }
+
+    /// Changes the first value, if any.
+    pub fn map_left<F, L>(self, f: F) -> EitherOrBoth<L, B>
+    where
+        F: FnOnce(A) -> L,
+    {
+        self.map_any(f, |b| b)
+    }
+
+    /// Changes the second value, if any.
+    pub fn map_right<F, R>(self, f: F) -> EitherOrBoth<A, R>
+    where
+        F: FnOnce(B) -> R,
+    {
+        self.map_any(|a| a, f)
+    }
+
+    /// Changes whichever values there are, with one closure for each side.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::iter::EitherOrBoth;
+    ///
+    /// assert_eq!(
+    ///   EitherOrBoth::Both(2, "ab").map_any(|a| a * 10, str::len),
+    ///   EitherOrBoth::Both(20, 2)
+    /// );
+    /// ```
+    pub fn map_any<F, G, L, R>(self, f: F, g: G) -> EitherOrBoth<L, R>
+    where
+        F: FnOnce(A) -> L,
+        G: FnOnce(B) -> R,
+    {
+        match self {
+            EitherOrBoth::Both(a, b) => EitherOrBoth::Both(f(a), g(b)),
+            EitherOrBoth::Left(a) => EitherOrBoth::Left(f(a)),
+            EitherOrBoth::Right(b) => EitherOrBoth::Right(g(b)),
+        }
+    }
+}
+
+impl<T> EitherOrBoth<T, T> {
+    /// The values combined with `f` when there are both, otherwise the one there is.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::iter::ZipOpt;
+    ///
+    /// let sums: Vec<u32> = vec![1, 2, 3].into_iter()
+    ///   .zip_longest(vec![10, 20])
+    ///   .map(|x| x.reduce(|a, b| a + b))
+    ///   .collect();
+    /// assert_eq!(sums, vec![11, 22, 3]);
+    /// ```
+    pub fn reduce<F>(self, f: F) -> T
+    where
+        F: FnOnce(T, T) -> T,
+    {
+        match self {
+            EitherOrBoth::Both(a, b) => f(a, b),
+            EitherOrBoth::Left(x) | EitherOrBoth::Right(x) => x,
+        }
+    }
+}
+
+/// Iterator for `zip_longest`, continuing until both iterators are exhausted.
+#[derive(Clone, Debug)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct ZipLongest<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A, B> Iterator for ZipLongest<A, B>
+where
+    A: Iterator,
+    B: Iterator,
+{
+    type Item = EitherOrBoth<A::Item, B::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.a.next(), self.b.next()) {
+            (Some(a), Some(b)) => Some(EitherOrBoth::Both(a, b)),
+            (Some(a), None) => Some(EitherOrBoth::Left(a)),
+            (None, Some(b)) => Some(EitherOrBoth::Right(b)),
+            (None, None) => None,
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+        let upper = match (a_upper, b_upper) {
+            (Some(x), Some(y)) => Some(cmp::max(x, y)),
+            _ => None,
+        };
+        (cmp::max(a_lower, b_lower), upper)
+    }
+}
+
+impl<A, B> DoubleEndedIterator for ZipLongest<A, B>
+where
+    A: DoubleEndedIterator + ExactSizeIterator,
+    B: DoubleEndedIterator + ExactSizeIterator,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (a_len, b_len) = (self.a.len(), self.b.len());
+        if a_len > b_len {
+            self.a.next_back().map(EitherOrBoth::Left)
+        } else if b_len > a_len {
+            self.b.next_back().map(EitherOrBoth::Right)
+        } else {
+            match (self.a.next_back(), self.b.next_back()) {
+                (Some(a), Some(b)) => Some(EitherOrBoth::Both(a, b)),
+                _ => None,
+            }
+        }
+    }
+}
+
+impl<A, B> ExactSizeIterator for ZipLongest<A, B>
+where
+    A: ExactSizeIterator,
+    B: ExactSizeIterator,
+{
+}
+
+impl<A, B> FusedIterator for ZipLongest<A, B>
+where
+    A: FusedIterator,
+    B: FusedIterator,
+{
+}
+
+/// Zips any number of iterators of the same type together to the longest length
+/// via Option<Vec<Option<T>>>
+pub trait ZipOptAll {
+    /// The type of the zipped iterators.
+    type Iter: Iterator;
+
+    /// Zip a collection of iterators to the longest length, each row holding one
+    /// Option for every iterator in the order they were given.
+    /// # Example
+    /// ```
+    /// use array_tool::iter::ZipOptAll;
+    ///
+    /// let hosts = vec![vec![1, 2, 3], vec![4], vec![5, 6]];
+    /// let mut x = hosts.iter().map(|h| h.iter()).collect::<Vec<_>>().zip_option_all();
+    ///
+    /// assert_eq!(x.next(), Some(vec![Some(&1), Some(&4), Some(&5)]));
+    /// assert_eq!(x.next(), Some(vec![Some(&2), None, Some(&6)]));
+    /// assert_eq!(x.next(), Some(vec![Some(&3), None, None]));
+    /// assert_eq!(x.next(), None);
+    /// ```
+    fn zip_option_all(self) -> ZipOptionAll<Self::Iter>;
+}
+
+impl<C> ZipOptAll for C
+where
+    C: IntoIterator,
+    C::Item: IntoIterator,
+{
+    type Iter = <C::Item as IntoIterator>::IntoIter;
+
+    #[inline]
+    fn zip_option_all(self) -> ZipOptionAll<Self::Iter> {
+        ZipOptionAll {
+            iters: self.into_iter().map(IntoIterator::into_iter).collect(),
+        }
+    }
+}
+
+/// Iterator for `zip_option_all`, continuing until every iterator is exhausted.
+#[derive(Clone, Debug)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct ZipOptionAll<I> {
+    iters: Vec<I>,
+}
+
+impl<I: Iterator> Iterator for ZipOptionAll<I> {
+    type Item = Vec<Option<I::Item>>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let row: Vec<Option<I::Item>> = self.iters.iter_mut().map(Iterator::next).collect();
+        if row.iter().any(Option::is_some) {
+            Some(row)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iters
+            .iter()
+            .map(Iterator::size_hint)
+            .fold((0, Some(0)), |(lower, upper), (l, u)| {
+                let upper = match (upper, u) {
+                    (Some(x), Some(y)) => Some(cmp::max(x, y)),
+                    _ => None,
+                };
+                (cmp::max(lower, l), upper)
+            })
+    }
+}
+
+impl<I> DoubleEndedIterator for ZipOptionAll<I>
+where
+    I: DoubleEndedIterator + ExactSizeIterator,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let longest = self
+            .iters
+            .iter()
+            .map(ExactSizeIterator::len)
+            .max()
+            .unwrap_or(0);
+        if longest == 0 {
+            return None;
+        }
+        // Only the iterators reaching the last row have an item in it.
+        Some(
+            self.iters
+                .iter_mut()
+                .map(|iter| {
+                    if iter.len() == longest {
+                        iter.next_back()
+                    } else {
+                        None
+                    }
+                })
+                .collect(),
+        )
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for ZipOptionAll<I> {}
+
+impl<I: FusedIterator> FusedIterator for ZipOptionAll<I> {}
+
+/// Iterator for `zip_longest_fill`, padding the shorter side with a copy of a value.
+#[derive(Clone, Debug)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct ZipLongestFill<A: Iterator, B: Iterator> {
+    zip: ZipLongest<A, B>,
+    fill_a: A::Item,
+    fill_b: B::Item,
+}
+
+impl<A, B> Iterator for ZipLongestFill<A, B>
+where
+    A: Iterator,
+    A::Item: Clone,
+    B: Iterator,
+    B::Item: Clone,
+{
+    type Item = (A::Item, B::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.zip.next()?;
+        Some(item.or(self.fill_a.clone(), self.fill_b.clone()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.zip.size_hint()
+    }
+}
+
+impl<A, B> DoubleEndedIterator for ZipLongestFill<A, B>
+where
+    A: DoubleEndedIterator + ExactSizeIterator,
+    A::Item: Clone,
+    B: DoubleEndedIterator + ExactSizeIterator,
+    B::Item: Clone,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.zip.next_back()?;
+        Some(item.or(self.fill_a.clone(), self.fill_b.clone()))
+    }
+}
+
+impl<A, B> ExactSizeIterator for ZipLongestFill<A, B>
+where
+    A: ExactSizeIterator,
+    A::Item: Clone,
+    B: ExactSizeIterator,
+    B::Item: Clone,
+{
+}
+
+impl<A, B> FusedIterator for ZipLongestFill<A, B>
+where
+    A: FusedIterator,
+    A::Item: Clone,
+    B: FusedIterator,
+    B::Item: Clone,
+{
+}
+
+/// Iterator for `zip_longest_with` and `zip_longest_default`, padding the shorter side
+/// with what a closure returns for the index of the item.
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct ZipLongestWith<A, B, F, G> {
+    zip: ZipLongest<A, B>,
+    index: usize,
+    fill_a: F,
+    fill_b: G,
+}
+
+/// Iterator for `zip_longest_default`.
+pub type ZipLongestDefault<A, B> =
+    ZipLongestWith<A, B, fn(usize) -> <A as Iterator>::Item, fn(usize) -> <B as Iterator>::Item>;
+
+impl<A: fmt::Debug, B: fmt::Debug, F, G> fmt::Debug for ZipLongestWith<A, B, F, G> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ZipLongestWith")
+            .field("zip", &self.zip)
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+impl<A, B, F, G> ZipLongestWith<A, B, F, G>
+where
+    A: Iterator,
+    B: Iterator,
+    F: FnMut(usize) -> A::Item,
+    G: FnMut(usize) -> B::Item,
+{
+    fn fill(&mut self, item: EitherOrBoth<A::Item, B::Item>, index: usize) -> (A::Item, B::Item) {
+        let (fill_a, fill_b) = (&mut self.fill_a, &mut self.fill_b);
+        item.or_else(|| fill_a(index), || fill_b(index))
+    }
+}
+
+impl<A, B, F, G> Iterator for ZipLongestWith<A, B, F, G>
+where
+    A: Iterator,
+    B: Iterator,
+    F: FnMut(usize) -> A::Item,
+    G: FnMut(usize) -> B::Item,
+{
+    type Item = (A::Item, B::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.zip.next()?;
+        let index = self.index;
+        self.index += 1;
+        Some(self.fill(item, index))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.zip.size_hint()
+    }
+}
+
+impl<A, B, F, G> DoubleEndedIterator for ZipLongestWith<A, B, F, G>
+where
+    A: DoubleEndedIterator + ExactSizeIterator,
+    B: DoubleEndedIterator + ExactSizeIterator,
+    F: FnMut(usize) -> A::Item,
+    G: FnMut(usize) -> B::Item,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // The last item keeps the index it has when counting from the front.
+        let index = self.index + self.zip.len().checked_sub(1)?;
+        let item = self.zip.next_back()?;
+        Some(self.fill(item, index))
+    }
+}
+
+impl<A, B, F, G> ExactSizeIterator for ZipLongestWith<A, B, F, G>
+where
+    A: ExactSizeIterator,
+    B: ExactSizeIterator,
+    F: FnMut(usize) -> A::Item,
+    G: FnMut(usize) -> B::Item,
+{
+}
+
+impl<A, B, F, G> FusedIterator for ZipLongestWith<A, B, F, G>
+where
+    A: FusedIterator,
+    B: FusedIterator,
+    F: FnMut(usize) -> A::Item,
+    G: FnMut(usize) -> B::Item,
+{
+}
+
+/// Groups the items of an iterator into vectors, lazily, in the manner of Ruby's
+/// `each_slice`, `each_cons`, `slice_when` and `chunk_while`.
+pub trait Chunking: Iterator + Sized {
+    /// Groups every `n` items, the last group holding what is left.
+    ///
+    /// Panics when `n` is 0.
+    /// # Example
+    /// ```
+    /// use array_tool::iter::Chunking;
+    ///
+    /// let x: Vec<Vec<u8>> = (1..8).each_slice(3).collect();
+    ///
+    /// assert_eq!(x, vec![vec![1, 2, 3], vec![4, 5, 6], vec![7]]);
+    /// ```
+    fn each_slice(self, n: usize) -> EachSlice<Self>;
+
+    /// Groups every `n` items like `each_slice`, filling the last group up to `n` items
+    /// with copies of `pad`.
+    ///
+    /// Panics when `n` is 0.
+    /// # Example
+    /// ```
+    /// use array_tool::iter::Chunking;
+    ///
+    /// let x: Vec<Vec<u8>> = (1..8).each_slice_pad(3, 0).collect();
+    ///
+    /// assert_eq!(x, vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 0, 0]]);
+    /// ```
+    fn each_slice_pad(self, n: usize, pad: Self::Item) -> EachSlice<Self>
+    where
+        Self::Item: Clone;
+
+    /// Every run of `n` consecutive items, moving along one item at a time.  Gives
+    /// nothing when there are fewer than `n` items.
+    ///
+    /// Panics when `n` is 0.
+    /// # Example
+    /// ```
+    /// use array_tool::iter::Chunking;
+    ///
+    /// let x: Vec<Vec<u8>> = (1..5).each_cons(2).collect();
+    ///
+    /// assert_eq!(x, vec![vec![1, 2], vec![2, 3], vec![3, 4]]);
+    /// ```
+    fn each_cons(self, n: usize) -> EachCons<Self>
+    where
+        Self::Item: Clone;
+
+    /// Splits between two neighbouring items whenever `predicate` is true for them.
+    /// # Example
+    /// ```
+    /// use array_tool::iter::Chunking;
+    ///
+    /// let x: Vec<Vec<u8>> = vec![1, 2, 4, 9, 10, 11, 12, 15, 16, 19, 20, 21]
+    ///   .into_iter()
+    ///   .slice_when(|a, b| a + 1 != *b)
+    ///   .collect();
+    ///
+    /// assert_eq!(x, vec![vec![1, 2], vec![4], vec![9, 10, 11, 12], vec![15, 16], vec![19, 20, 21]]);
+    /// ```
+    fn slice_when<F>(self, predicate: F) -> SliceWhen<Self, F>
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> bool;
+
+    /// Keeps neighbouring items together while `predicate` is true for them, splitting
+    /// where it is false.
+    /// # Example
+    /// ```
+    /// use array_tool::iter::Chunking;
+    ///
+    /// let x: Vec<Vec<u8>> = vec![1, 2, 4, 9, 10, 11]
+    ///   .into_iter()
+    ///   .chunk_while(|a, b| a + 1 == *b)
+    ///   .collect();
+    ///
+    /// assert_eq!(x, vec![vec![1, 2], vec![4], vec![9, 10, 11]]);
+    /// ```
+    fn chunk_while<F>(self, predicate: F) -> ChunkWhile<Self, F>
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> bool;
+}
+
+impl<I: Iterator> Chunking for I {
+    #[inline]
+    fn each_slice(self, n: usize) -> EachSlice<Self> {
+        assert!(n != 0, "slice size must be non-zero");
+        EachSlice {
+            iter: self,
+            n,
+            pad: None,
+        }
+    }
+
+    #[inline]
+    fn each_slice_pad(self, n: usize, pad: I::Item) -> EachSlice<Self>
+    where
+        I::Item: Clone,
+    {
+        assert!(n != 0, "slice size must be non-zero");
+        EachSlice {
+            iter: self,
+            n,
+            pad: Some((pad, Clone::clone)),
+        }
+    }
+
+    #[inline]
+    fn each_cons(self, n: usize) -> EachCons<Self>
+    where
+        I::Item: Clone,
+    {
+        assert!(n != 0, "window size must be non-zero");
+        EachCons {
+            iter: self,
+            n,
+            window: VecDeque::with_capacity(n),
+        }
+    }
+
+    #[inline]
+    fn slice_when<F>(self, predicate: F) -> SliceWhen<Self, F>
+    where
+        F: FnMut(&I::Item, &I::Item) -> bool,
+    {
+        SliceWhen {
+            iter: self,
+            next: None,
+            predicate,
+        }
+    }
+
+    #[inline]
+    fn chunk_while<F>(self, predicate: F) -> ChunkWhile<Self, F>
+    where
+        F: FnMut(&I::Item, &I::Item) -> bool,
+    {
+        ChunkWhile {
+            iter: self,
+            next: None,
+            predicate,
+        }
+    }
+}
+
+/// Iterator for `each_slice` and `each_slice_pad`.
+#[derive(Clone, Debug)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct EachSlice<I: Iterator> {
+    iter: I,
+    n: usize,
+    pad: Option<Pad<I::Item>>,
+}
+
+// The padding for `each_slice_pad`, with how to copy it as the item type is only known
+// to be `Clone` there.
+type Pad<T> = (T, fn(&T) -> T);
+
+impl<I: Iterator> Iterator for EachSlice<I> {
+    type Item = Vec<I::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.iter.next()?;
+        let mut slice = Vec::with_capacity(self.n);
+        slice.push(first);
+        slice.extend(self.iter.by_ref().take(self.n - 1));
+        if let Some((ref pad, copy)) = self.pad {
+            while slice.len() < self.n {
+                slice.push(copy(pad));
+            }
+        }
+        Some(slice)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let slices = |len: usize| len.div_ceil(self.n);
+        (slices(lower), upper.map(slices))
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for EachSlice<I> {}
+
+/// Iterator for `each_cons`.
+#[derive(Clone, Debug)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct EachCons<I: Iterator> {
+    iter: I,
+    n: usize,
+    // The items the next window starts with.
+    window: VecDeque<I::Item>,
+}
+
+impl<I> Iterator for EachCons<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.window.len() < self.n {
+            self.window.push_back(self.iter.next()?);
+        }
+        let window = self.window.iter().cloned().collect();
+        self.window.pop_front();
+        Some(window)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let buffered = self.window.len();
+        let windows = |len: usize| len.saturating_sub(self.n - 1);
+        (
+            windows(lower.saturating_add(buffered)),
+            upper
+                .and_then(|upper| upper.checked_add(buffered))
+                .map(windows),
+        )
+    }
+}
+
+impl<I> FusedIterator for EachCons<I>
+where
+    I: FusedIterator,
+    I::Item: Clone,
+{
+}
+
+/// Iterator for `slice_when`.
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct SliceWhen<I: Iterator, F> {
+    iter: I,
+    // The first item of the next slice, taken while finding the end of the last one.
+    next: Option<I::Item>,
+    predicate: F,
+}
+
+/// Iterator for `chunk_while`.
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct ChunkWhile<I: Iterator, F> {
+    iter: I,
+    // The first item of the next chunk, taken while finding the end of the last one.
+    next: Option<I::Item>,
+    predicate: F,
+}
+
+/// The items up to where `split` says to split, keeping the item after in `next`.
+fn next_run<I, F>(iter: &mut I, next: &mut Option<I::Item>, mut split: F) -> Option<Vec<I::Item>>
+where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    let first = next.take().or_else(|| iter.next())?;
+    let mut run = vec![first];
+    for item in iter {
+        if split(&run[run.len() - 1], &item) {
+            *next = Some(item);
+            break;
+        }
+        run.push(item);
+    }
+    Some(run)
+}
+
+/// The size hint for `slice_when` and `chunk_while`: at least one run if there are any
+/// items, and at most one run per item.
+fn run_size_hint<I: Iterator>(iter: &I, next: &Option<I::Item>) -> (usize, Option<usize>) {
+    let (lower, upper) = iter.size_hint();
+    let pending = usize::from(next.is_some());
+    (
+        cmp::min(lower.saturating_add(pending), 1),
+        upper.and_then(|upper| upper.checked_add(pending)),
+    )
+}
+
+impl<I, F> Iterator for SliceWhen<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    type Item = Vec<I::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        next_run(&mut self.iter, &mut self.next, &mut self.predicate)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        run_size_hint(&self.iter, &self.next)
+    }
+}
+
+impl<I, F> Iterator for ChunkWhile<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    type Item = Vec<I::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let predicate = &mut self.predicate;
+        next_run(&mut self.iter, &mut self.next, |a, b| !predicate(a, b))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        run_size_hint(&self.iter, &self.next)
+    }
+}
+
+impl<I, F> FusedIterator for SliceWhen<I, F>
+where
+    I: FusedIterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+}
+
+impl<I, F> FusedIterator for ChunkWhile<I, F>
+where
+    I: FusedIterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+}
+
+impl<I, F> fmt::Debug for SliceWhen<I, F>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SliceWhen")
+            .field("iter", &self.iter)
+            .field("next", &self.next)
+            .finish()
+    }
+}
+
+impl<I, F> fmt::Debug for ChunkWhile<I, F>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ChunkWhile")
+            .field("iter", &self.iter)
+            .field("next", &self.next)
+            .finish()
+    }
+}
+
+/// Weaves the items of an iterator together with those of another iterator, or with
+/// separators.
+pub trait Interleaving: Iterator + Sized {
+    /// Takes items from each iterator in turn, then the rest from the longer one.
+    /// # Example
+    /// ```
+    /// use array_tool::iter::Interleaving;
+    ///
+    /// let x: Vec<u8> = vec![1, 3, 5, 7].into_iter().interleave(vec![2, 4]).collect();
+    ///
+    /// assert_eq!(x, vec![1, 2, 3, 4, 5, 7]);
+    /// ```
+    fn interleave<U>(self, other: U) -> Interleave<Self, U::IntoIter>
+    where
+        U: IntoIterator<Item = Self::Item>;
+
+    /// Takes items from each iterator in turn, stopping at the first turn of an
+    /// iterator that has run out.
+    /// # Example
+    /// ```
+    /// use array_tool::iter::Interleaving;
+    ///
+    /// let x: Vec<u8> = vec![1, 3, 5, 7].into_iter().interleave_shortest(vec![2, 4]).collect();
+    ///
+    /// assert_eq!(x, vec![1, 2, 3, 4, 5]);
+    /// ```
+    fn interleave_shortest<U>(self, other: U) -> InterleaveShortest<Self, U::IntoIter>
+    where
+        U: IntoIterator<Item = Self::Item>;
+
+    /// Puts a copy of `separator` between every two items.
+    /// # Example
+    /// ```
+    /// use array_tool::iter::Interleaving;
+    ///
+    /// let x: Vec<&str> = vec!["a", "b", "c"].into_iter().intersperse_items(",").collect();
+    ///
+    /// assert_eq!(x, vec!["a", ",", "b", ",", "c"]);
+    /// ```
+    fn intersperse_items(self, separator: Self::Item) -> Intersperse<Self>
+    where
+        Self::Item: Clone;
+
+    /// Puts what `separator` returns between every two items.
+    /// # Example
+    /// ```
+    /// use array_tool::iter::Interleaving;
+    ///
+    /// let mut count = 0;
+    /// let x: Vec<String> = vec!["a", "b", "c"]
+    ///   .into_iter()
+    ///   .map(String::from)
+    ///   .intersperse_items_with(|| { count += 1; count.to_string() })
+    ///   .collect();
+    ///
+    /// assert_eq!(x, vec!["a", "1", "b", "2", "c"]);
+    /// ```
+    fn intersperse_items_with<G>(self, separator: G) -> IntersperseWith<Self, G>
+    where
+        G: FnMut() -> Self::Item;
+}
+
+impl<I: Iterator> Interleaving for I {
+    #[inline]
+    fn interleave<U>(self, other: U) -> Interleave<Self, U::IntoIter>
+    where
+        U: IntoIterator<Item = I::Item>,
+    {
+        Interleave {
+            a: self.fuse(),
+            b: other.into_iter().fuse(),
+            a_next: true,
+        }
+    }
+
+    #[inline]
+    fn interleave_shortest<U>(self, other: U) -> InterleaveShortest<Self, U::IntoIter>
+    where
+        U: IntoIterator<Item = I::Item>,
+    {
+        InterleaveShortest {
+            a: self,
+            b: other.into_iter(),
+            a_next: true,
+        }
+    }
+
+    #[inline]
+    fn intersperse_items(self, separator: I::Item) -> Intersperse<Self>
+    where
+        I::Item: Clone,
+    {
+        Intersperse {
+            iter: self.peekable(),
+            separator,
+            separator_next: false,
+        }
+    }
+
+    #[inline]
+    fn intersperse_items_with<G>(self, separator: G) -> IntersperseWith<Self, G>
+    where
+        G: FnMut() -> I::Item,
+    {
+        IntersperseWith {
+            iter: self.peekable(),
+            separator,
+            separator_next: false,
+        }
+    }
+}
+
+/// Iterator for `interleave`.
+#[derive(Clone, Debug)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct Interleave<A, B> {
+    a: Fuse<A>,
+    b: Fuse<B>,
+    a_next: bool,
+}
+
+impl<A, B> Iterator for Interleave<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let a_turn = self.a_next;
+        self.a_next = !a_turn;
+        if a_turn {
+            self.a.next().or_else(|| self.b.next())
+        } else {
+            self.b.next().or_else(|| self.a.next())
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+        let upper = match (a_upper, b_upper) {
+            (Some(x), Some(y)) => x.checked_add(y),
+            _ => None,
+        };
+        (a_lower.saturating_add(b_lower), upper)
+    }
+}
+
+impl<A, B> FusedIterator for Interleave<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+{
+}
+
+/// Iterator for `interleave_shortest`.
+#[derive(Clone, Debug)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct InterleaveShortest<A, B> {
+    a: A,
+    b: B,
+    a_next: bool,
+}
+
+impl<A, B> Iterator for InterleaveShortest<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = if self.a_next {
+            self.a.next()
+        } else {
+            self.b.next()
+        };
+        if item.is_some() {
+            self.a_next = !self.a_next;
+        }
+        item
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (next, other) = if self.a_next {
+            (self.a.size_hint(), self.b.size_hint())
+        } else {
+            (self.b.size_hint(), self.a.size_hint())
+        };
+        // With `next` items to go first and `other` after, each `next` item is followed
+        // by an `other` one until one side runs out.
+        let woven = |next: usize, other: usize| {
+            if next <= other {
+                next.saturating_mul(2)
+            } else {
+                other.saturating_mul(2).saturating_add(1)
+            }
+        };
+        let lower = woven(next.0, other.0);
+        let upper = match (next.1, other.1) {
+            (Some(x), Some(y)) => Some(woven(x, y)),
+            (Some(x), None) => x.checked_mul(2),
+            (None, Some(y)) => y.checked_mul(2).and_then(|y| y.checked_add(1)),
+            (None, None) => None,
+        };
+        (lower, upper)
+    }
+}
+
+impl<A, B> FusedIterator for InterleaveShortest<A, B>
+where
+    A: FusedIterator,
+    B: FusedIterator<Item = A::Item>,
+{
+}
+
+/// Iterator for `intersperse_items`.
+#[derive(Clone, Debug)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct Intersperse<I: Iterator> {
+    iter: Peekable<I>,
+    separator: I::Item,
+    separator_next: bool,
+}
+
+/// Iterator for `intersperse_items_with`.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct IntersperseWith<I: Iterator, G> {
+    iter: Peekable<I>,
+    separator: G,
+    separator_next: bool,
+}
+
+/// The next item, or a separator from `separator` when there is another item to come.
+fn intersperse_next<I, G>(
+    iter: &mut Peekable<I>,
+    separator_next: &mut bool,
+    separator: G,
+) -> Option<I::Item>
+where
+    I: Iterator,
+    G: FnOnce() -> I::Item,
+{
+    if *separator_next && iter.peek().is_some() {
+        *separator_next = false;
+        Some(separator())
+    } else {
+        *separator_next = true;
+        iter.next()
+    }
+}
+
+/// The size hint for `intersperse_items` and `intersperse_items_with`: a separator between
+/// each two items, and one before the first when an item has already been given.
+fn intersperse_size_hint<I: Iterator>(
+    iter: &Peekable<I>,
+    separator_next: bool,
+) -> (usize, Option<usize>) {
+    let with_separators = |len: usize| {
+        if separator_next {
+            len.checked_mul(2)
+        } else {
+            len.checked_mul(2).map(|len| len.saturating_sub(1))
+        }
+    };
+    let (lower, upper) = iter.size_hint();
+    (
+        with_separators(lower).unwrap_or(usize::MAX),
+        upper.and_then(with_separators),
+    )
+}
+
+impl<I> Iterator for Intersperse<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let separator = &self.separator;
+        intersperse_next(&mut self.iter, &mut self.separator_next, || {
+            separator.clone()
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        intersperse_size_hint(&self.iter, self.separator_next)
+    }
+}
+
+impl<I, G> Iterator for IntersperseWith<I, G>
+where
+    I: Iterator,
+    G: FnMut() -> I::Item,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        intersperse_next(
+            &mut self.iter,
+            &mut self.separator_next,
+            &mut self.separator,
+        )
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        intersperse_size_hint(&self.iter, self.separator_next)
+    }
+}
+
+impl<I> FusedIterator for Intersperse<I>
+where
+    I: FusedIterator,
+    I::Item: Clone,
+{
+}
+
+impl<I, G> FusedIterator for IntersperseWith<I, G>
+where
+    I: FusedIterator,
+    G: FnMut() -> I::Item,
+{
+}
+
+impl<I, G> Clone for IntersperseWith<I, G>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+    G: Clone,
+{
+    fn clone(&self) -> Self {
+        IntersperseWith {
+            iter: self.iter.clone(),
+            separator: self.separator.clone(),
+            separator_next: self.separator_next,
+        }
+    }
+}
+
+impl<I, G> fmt::Debug for IntersperseWith<I, G>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("IntersperseWith")
+            .field("iter", &self.iter)
+            .field("separator_next", &self.separator_next)
+            .finish()
+    }
+}
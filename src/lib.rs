@@ -42,6 +42,8 @@ pub mod iter;
 pub mod sorted_vec;
 /// A string is a collection so we should have more methods for handling strings.
 pub mod string;
+// Unicode character property tables used by the string helpers.
+mod tables;
 /// Array Tool provides many useful methods for vectors
 pub mod vec;
 
@@ -5,6 +5,18 @@
 // http://opensource.org/licenses/MIT>, at your option. This file may not be
 // copied, modified, or distributed except according to those terms.
 
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+use tables;
+use tables::grapheme::GraphemeBreak;
+use tables::sentence::SentenceBreak;
+use tables::word::WordBreak;
+use vec::EditDistance;
+
 /// A grapheme iterator that produces the bytes for each grapheme.
 #[derive(Debug)]
 pub struct GraphemeBytesIter<'a> {
@@ -26,28 +38,24 @@ impl<'a> Iterator for GraphemeBytesIter<'a> {
     type Item = &'a [u8];
 
     fn next(&mut self) -> Option<&'a [u8]> {
-        let mut result: Option<&[u8]> = None;
-        let mut idx = self.offset;
-        for _ in self.offset..self.source.len() {
-            idx += 1;
-            if self.offset < self.source.len() {
-                if self.source.is_char_boundary(idx) {
-                    let slice: &[u8] = self.source[self.offset..idx].as_bytes();
-
-                    self.grapheme_count += 1;
-                    self.offset = idx;
-
-                    result = Some(slice);
-                    break;
-                }
-            }
-        }
-        result
+        let character = self.source[self.offset..].chars().next()?;
+        let idx = self.offset + character.len_utf8();
+        let slice: &[u8] = &self.source.as_bytes()[self.offset..idx];
+
+        self.grapheme_count += 1;
+        self.offset = idx;
+
+        Some(slice)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len();
+        (remaining, Some(remaining))
     }
 }
 impl<'a> ExactSizeIterator for GraphemeBytesIter<'a> {
     fn len(&self) -> usize {
-        self.source.chars().count()
+        self.source[self.offset..].chars().count()
     }
 }
 /// ToGraphemeBytesIter - create an iterator to return bytes for each grapheme in a string.
@@ -71,254 +79,3023 @@ pub trait ToGraphemeBytesIter<'a> {
 }
 impl<'a> ToGraphemeBytesIter<'a> for str {
     fn grapheme_bytes_iter(&'a self) -> GraphemeBytesIter<'a> {
-        GraphemeBytesIter::new(&self)
+        GraphemeBytesIter::new(self)
     }
 }
 
-/// Squeeze - squeezes duplicate characters down to one each
-pub trait Squeeze {
-    /// # Example
-    /// ```
-    /// use array_tool::string::Squeeze;
-    ///
-    /// "yellow moon".squeeze("");
-    /// ```
-    ///
-    /// # Output
-    /// ```text
-    /// "yelow mon"
-    /// ```
-    fn squeeze(&self, targets: &'static str) -> String;
+/// An iterator over the characters of a string, each as a string slice.  Despite the
+/// name it yields `char`s rather than extended grapheme clusters, so an accent which
+/// follows its letter comes separately.
+#[derive(Clone, Debug)]
+pub struct GraphemeIter<'a> {
+    source: &'a str,
+    front: usize,
+    back: usize,
 }
-impl Squeeze for str {
-    fn squeeze(&self, targets: &'static str) -> String {
-        let mut output = Vec::<u8>::with_capacity(self.len());
-        let everything: bool = targets.is_empty();
-        let chars = targets.grapheme_bytes_iter().collect::<Vec<&[u8]>>();
-        let mut last: &[u8] = &[0];
-        for character in self.grapheme_bytes_iter() {
-            if last != character {
-                output.extend_from_slice(character);
-            } else if !(everything || chars.contains(&character)) {
-                output.extend_from_slice(character);
-            }
-            last = character;
+impl<'a> GraphemeIter<'a> {
+    /// Creates a new iterator over the characters of a string source.
+    pub fn new(source: &'a str) -> GraphemeIter<'a> {
+        GraphemeIter {
+            source,
+            front: 0,
+            back: source.len(),
         }
-        String::from_utf8(output).expect("squeeze failed to render String!")
+    }
+
+    /// The byte offset of the next character from the front.
+    pub fn offset(&self) -> usize {
+        self.front
+    }
+
+    /// The part of the source which has not been iterated over yet.
+    pub fn as_str(&self) -> &'a str {
+        &self.source[self.front..self.back]
     }
 }
+impl<'a> Iterator for GraphemeIter<'a> {
+    type Item = &'a str;
 
-/// Justify - expand line to given width.
-pub trait Justify {
+    fn next(&mut self) -> Option<&'a str> {
+        let character = self.as_str().chars().next()?;
+        let start = self.front;
+        self.front += character.len_utf8();
+        Some(&self.source[start..self.front])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every character is between one and four bytes long.
+        let bytes = self.back - self.front;
+        (bytes.div_ceil(4), Some(bytes))
+    }
+}
+impl<'a> DoubleEndedIterator for GraphemeIter<'a> {
+    fn next_back(&mut self) -> Option<&'a str> {
+        let character = self.as_str().chars().next_back()?;
+        let end = self.back;
+        self.back -= character.len_utf8();
+        Some(&self.source[self.back..end])
+    }
+}
+impl<'a> FusedIterator for GraphemeIter<'a> {}
+
+/// An iterator over the characters of a string, each as its byte offset and a string
+/// slice, like `GraphemeIter`.
+#[derive(Clone, Debug)]
+pub struct GraphemeIndices<'a> {
+    iter: GraphemeIter<'a>,
+}
+impl<'a> GraphemeIndices<'a> {
+    /// Creates a new iterator over the characters of a string source and their offsets.
+    pub fn new(source: &'a str) -> GraphemeIndices<'a> {
+        GraphemeIndices {
+            iter: GraphemeIter::new(source),
+        }
+    }
+
+    /// The part of the source which has not been iterated over yet.
+    pub fn as_str(&self) -> &'a str {
+        self.iter.as_str()
+    }
+}
+impl<'a> Iterator for GraphemeIndices<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<(usize, &'a str)> {
+        let offset = self.iter.offset();
+        self.iter.next().map(|character| (offset, character))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+impl<'a> DoubleEndedIterator for GraphemeIndices<'a> {
+    fn next_back(&mut self) -> Option<(usize, &'a str)> {
+        self.iter
+            .next_back()
+            .map(|character| (self.iter.back, character))
+    }
+}
+impl<'a> FusedIterator for GraphemeIndices<'a> {}
+
+/// ToGraphemeIter - create an iterator to return a string slice for each character in a
+/// string.  These are `char`s, not extended grapheme clusters: `Truncate` and
+/// `Indexing::Grapheme` keep a letter together with its accents.
+pub trait ToGraphemeIter<'a> {
+    /// Returns a GraphemeIter which you may iterate over from either end.
+    ///
     /// # Example
     /// ```
-    /// use array_tool::string::Justify;
+    /// use array_tool::string::ToGraphemeIter;
     ///
-    /// "asd asdf asd".justify_line(14);
+    /// let string = "a s—d féZ";
+    /// let mut characters = string.grapheme_iter();
+    /// assert_eq!(characters.next(), Some("a"));
+    /// assert_eq!(characters.next_back(), Some("Z"));
+    /// assert_eq!(characters.nth(2), Some("—"));
+    /// assert_eq!("e\u{301}".grapheme_iter().count(), 2);
+    /// ```
+    fn grapheme_iter(&'a self) -> GraphemeIter<'a>;
+
+    /// Returns a GraphemeIndices which yields each character with its byte offset.
+    ///
+    /// # Example
     /// ```
+    /// use array_tool::string::ToGraphemeIter;
     ///
-    /// # Output
-    /// ```text
-    /// "asd  asdf  asd"
+    /// let string = "s—d";
+    /// let characters: Vec<(usize, &str)> = string.grapheme_indices().collect();
+    /// assert_eq!(characters, vec![(0, "s"), (1, "—"), (4, "d")]);
     /// ```
-    fn justify_line(&self, width: usize) -> String;
+    fn grapheme_indices(&'a self) -> GraphemeIndices<'a>;
+}
+impl<'a> ToGraphemeIter<'a> for str {
+    fn grapheme_iter(&'a self) -> GraphemeIter<'a> {
+        GraphemeIter::new(self)
+    }
+    fn grapheme_indices(&'a self) -> GraphemeIndices<'a> {
+        GraphemeIndices::new(self)
+    }
 }
 
-impl Justify for str {
-    fn justify_line(&self, width: usize) -> String {
-        if self.is_empty() {
-            return format!("{}", self);
+fn grapheme_break(character: char) -> GraphemeBreak {
+    tables::lookup(character, tables::grapheme::GRAPHEME_BREAK).unwrap_or(GraphemeBreak::Other)
+}
+
+// The end of the extended grapheme cluster which starts at `start`, following the rules
+// of UAX #29 apart from the Indic conjunct rule GB9c.
+fn grapheme_end(text: &str, start: usize) -> usize {
+    use tables::grapheme::GraphemeBreak::*;
+
+    let mut characters = text[start..].char_indices();
+    let first = match characters.next() {
+        Some((_, character)) => character,
+        None => return start,
+    };
+    let mut end = start + first.len_utf8();
+    let mut previous = grapheme_break(first);
+    // Whether the cluster so far is an emoji followed by marks, for joining emoji (GB11).
+    let mut pictographic = previous == ExtendedPictographic;
+    let mut regional = usize::from(previous == RegionalIndicator);
+    for (index, character) in characters {
+        let kind = grapheme_break(character);
+        let joins = match (previous, kind) {
+            (Cr, Lf) => true,
+            (Cr, _) | (Lf, _) | (Control, _) | (_, Cr) | (_, Lf) | (_, Control) => false,
+            (L, L) | (L, V) | (L, Lv) | (L, Lvt) => true,
+            (Lv, V) | (Lv, T) | (V, V) | (V, T) => true,
+            (Lvt, T) | (T, T) => true,
+            (_, Extend) | (_, Zwj) | (_, SpacingMark) | (Prepend, _) => true,
+            (Zwj, ExtendedPictographic) => pictographic,
+            (RegionalIndicator, RegionalIndicator) => regional % 2 == 1,
+            _ => false,
         };
-        let trimmed = self.trim();
-        let len = trimmed.chars().count();
-        if len >= width {
-            return self.to_string();
+        if !joins {
+            return start + index;
+        }
+        pictographic = match kind {
+            ExtendedPictographic => true,
+            Extend | Zwj => pictographic && previous != Zwj,
+            _ => false,
         };
-        let difference = width - len;
-        let iter = trimmed.split_whitespace();
-        let spaces = iter.count() - 1;
-        let mut iter = trimmed.split_whitespace().peekable();
-        if spaces == 0 {
-            return self.to_string();
-        }
-        let mut obj = String::with_capacity(trimmed.len() + spaces);
-
-        let div = difference / spaces;
-        let mut remainder = difference % spaces;
-
-        while let Some(x) = iter.next() {
-            obj.push_str(x);
-            let val = if remainder > 0 {
-                remainder = remainder - 1;
-                div + 1
-            } else {
-                div
-            };
-            for _ in 0..val + 1 {
-                if let Some(_) = iter.peek() {
-                    // Don't add spaces if last word
-                    obj.push_str(" ");
+        regional = if kind == RegionalIndicator {
+            regional + 1
+        } else {
+            0
+        };
+        previous = kind;
+        end = start + index + character.len_utf8();
+    }
+    end
+}
+
+fn word_break(character: char) -> WordBreak {
+    tables::lookup(character, tables::word::WORD_BREAK).unwrap_or(WordBreak::Other)
+}
+
+fn is_ahletter(kind: WordBreak) -> bool {
+    kind == WordBreak::ALetter || kind == WordBreak::HebrewLetter
+}
+
+// The Word_Break value of the first character from `offset` which rule WB4 does not skip.
+fn next_word_break(text: &str, offset: usize) -> Option<WordBreak> {
+    use tables::word::WordBreak::*;
+    text[offset..]
+        .chars()
+        .map(word_break)
+        .find(|&kind| kind != Extend && kind != Format && kind != Zwj)
+}
+
+// The end of the word segment which starts at `start`, following the rules of UAX #29.
+fn word_bound_end(text: &str, start: usize) -> usize {
+    use tables::word::WordBreak::*;
+
+    let mut characters = text[start..].char_indices();
+    let first = match characters.next() {
+        Some((_, character)) => character,
+        None => return start,
+    };
+    let mut end = start + first.len_utf8();
+    let mut previous = word_break(first);
+    match previous {
+        Cr if text[end..].starts_with('\n') => return end + 1,
+        Cr | Lf | Newline => return end,
+        _ => {}
+    }
+    // `previous` and `before` skip what WB4 ignores, `adjacent` is the character just before.
+    let mut before = Other;
+    let mut adjacent = previous;
+    let mut regional = usize::from(previous == RegionalIndicator);
+    for (index, character) in characters {
+        let offset = start + index;
+        let kind = word_break(character);
+        let after = offset + character.len_utf8();
+        let joins = match (previous, kind) {
+            (_, Cr) | (_, Lf) | (_, Newline) => false,
+            _ if adjacent == Zwj && tables::in_table(character, tables::EXTENDED_PICTOGRAPHIC) => {
+                true
+            }
+            (_, WSegSpace) if adjacent == WSegSpace => true,
+            (_, Extend) | (_, Format) | (_, Zwj) => {
+                adjacent = kind;
+                end = after;
+                continue;
+            }
+            (p, k) if is_ahletter(p) && is_ahletter(k) => true,
+            (p, MidLetter) | (p, MidNumLet) | (p, SingleQuote) if is_ahletter(p) => {
+                p == HebrewLetter && kind == SingleQuote
+                    || next_word_break(text, after).is_some_and(is_ahletter)
+            }
+            (MidLetter, k) | (MidNumLet, k) | (SingleQuote, k) if is_ahletter(k) => {
+                is_ahletter(before)
+            }
+            (HebrewLetter, DoubleQuote) => next_word_break(text, after) == Some(HebrewLetter),
+            (DoubleQuote, HebrewLetter) => before == HebrewLetter,
+            (Numeric, Numeric) => true,
+            (p, Numeric) | (Numeric, p) if is_ahletter(p) => true,
+            (MidNum, Numeric) | (MidNumLet, Numeric) | (SingleQuote, Numeric) => before == Numeric,
+            (Numeric, MidNum) | (Numeric, MidNumLet) | (Numeric, SingleQuote) => {
+                next_word_break(text, after) == Some(Numeric)
+            }
+            (Katakana, Katakana) => true,
+            (ALetter, ExtendNumLet)
+            | (HebrewLetter, ExtendNumLet)
+            | (Numeric, ExtendNumLet)
+            | (Katakana, ExtendNumLet)
+            | (ExtendNumLet, ExtendNumLet) => true,
+            (ExtendNumLet, ALetter)
+            | (ExtendNumLet, HebrewLetter)
+            | (ExtendNumLet, Numeric)
+            | (ExtendNumLet, Katakana) => true,
+            (RegionalIndicator, RegionalIndicator) => regional % 2 == 1,
+            _ => false,
+        };
+        if !joins {
+            return offset;
+        }
+        regional = if kind == RegionalIndicator {
+            regional + 1
+        } else {
+            0
+        };
+        before = previous;
+        previous = kind;
+        adjacent = kind;
+        end = after;
+    }
+    end
+}
+
+fn sentence_break(character: char) -> SentenceBreak {
+    tables::lookup(character, tables::sentence::SENTENCE_BREAK).unwrap_or(SentenceBreak::Other)
+}
+
+// Whether a lower case letter comes before any other letter, terminator or paragraph
+// separator, so that a full stop from before `offset` does not end the sentence (SB8).
+fn lower_follows(text: &str, offset: usize) -> bool {
+    use tables::sentence::SentenceBreak::*;
+    text[offset..].chars().map(sentence_break).find(|kind| {
+        matches!(
+            kind,
+            OLetter | Upper | Lower | Sep | Cr | Lf | STerm | ATerm
+        )
+    }) == Some(Lower)
+}
+
+// The end of the sentence which starts at `start`, following the rules of UAX #29.
+fn sentence_bound_end(text: &str, start: usize) -> usize {
+    use tables::sentence::SentenceBreak::*;
+
+    // How far into the terminator, closing punctuation and spaces at the end of a sentence.
+    #[derive(Clone, Copy, PartialEq)]
+    enum Phase {
+        Open,
+        Terminator,
+        Closing,
+        Spaces,
+    }
+    let mut phase = Phase::Open;
+    let mut full_stop = false;
+    let mut before_terminator = Other;
+    let mut previous = Other;
+    let mut end = start;
+    for (index, character) in text[start..].char_indices() {
+        let offset = start + index;
+        let kind = sentence_break(character);
+        let after = offset + character.len_utf8();
+        match kind {
+            Cr if text[after..].starts_with('\n') => return after + 1,
+            Cr | Lf | Sep => return after,
+            Extend | Format if offset > start => {
+                end = after;
+                continue;
+            }
+            _ => {}
+        }
+        if phase != Phase::Open {
+            let joins = match kind {
+                Close => phase != Phase::Spaces || full_stop && lower_follows(text, offset),
+                Sp | SContinue | STerm | ATerm => true,
+                Numeric if phase == Phase::Terminator && full_stop => true,
+                Upper if phase == Phase::Terminator && full_stop => {
+                    before_terminator == Upper || before_terminator == Lower
                 }
+                _ => full_stop && lower_follows(text, offset),
+            };
+            if !joins {
+                return offset;
             }
         }
-        obj
+        phase = match kind {
+            STerm | ATerm => {
+                full_stop = kind == ATerm;
+                before_terminator = previous;
+                Phase::Terminator
+            }
+            Close if phase == Phase::Terminator || phase == Phase::Closing => Phase::Closing,
+            Sp if phase != Phase::Open => Phase::Spaces,
+            _ => Phase::Open,
+        };
+        previous = kind;
+        end = after;
     }
+    end
 }
 
-/// Substitute string character for each index given.
-pub trait SubstMarks {
+/// An iterator over the pieces of a string between word boundaries, as defined by
+/// [UAX #29](https://www.unicode.org/reports/tr29/).  Words, runs of spaces and each
+/// punctuation mark are yielded separately, so joining them gives back the whole string.
+#[derive(Clone, Debug)]
+pub struct WordBounds<'a> {
+    source: &'a str,
+    front: usize,
+}
+impl<'a> WordBounds<'a> {
+    /// Creates a new word bounds iterator from a string source.
+    pub fn new(source: &'a str) -> WordBounds<'a> {
+        WordBounds { source, front: 0 }
+    }
+
+    /// The byte offset of the next piece.
+    pub fn offset(&self) -> usize {
+        self.front
+    }
+
+    /// The part of the source which has not been iterated over yet.
+    pub fn as_str(&self) -> &'a str {
+        &self.source[self.front..]
+    }
+}
+impl<'a> Iterator for WordBounds<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        if self.front == self.source.len() {
+            return None;
+        }
+        let start = self.front;
+        self.front = word_bound_end(self.source, start);
+        Some(&self.source[start..self.front])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let bytes = self.source.len() - self.front;
+        (usize::from(bytes > 0), Some(bytes))
+    }
+}
+impl<'a> FusedIterator for WordBounds<'a> {}
+
+/// An iterator over the words of a string: the pieces between word boundaries which hold
+/// a letter or a digit.
+#[derive(Clone, Debug)]
+pub struct UnicodeWords<'a> {
+    bounds: WordBounds<'a>,
+}
+impl<'a> UnicodeWords<'a> {
+    /// Creates a new word iterator from a string source.
+    pub fn new(source: &'a str) -> UnicodeWords<'a> {
+        UnicodeWords {
+            bounds: WordBounds::new(source),
+        }
+    }
+
+    /// The part of the source which has not been iterated over yet.
+    pub fn as_str(&self) -> &'a str {
+        self.bounds.as_str()
+    }
+}
+impl<'a> Iterator for UnicodeWords<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        self.bounds
+            .find(|piece| piece.chars().any(char::is_alphanumeric))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.bounds.size_hint().1)
+    }
+}
+impl<'a> FusedIterator for UnicodeWords<'a> {}
+
+/// ToWordIter - iterate over the words of a string the way
+/// [UAX #29](https://www.unicode.org/reports/tr29/) finds them, keeping contractions,
+/// decimals and emoji sequences whole and splitting apart punctuation and ideographs.
+pub trait ToWordIter<'a> {
+    /// Returns a UnicodeWords which yields every word, leaving out the spaces and
+    /// punctuation between them.
+    ///
     /// # Example
     /// ```
-    /// use array_tool::string::SubstMarks;
+    /// use array_tool::string::ToWordIter;
     ///
-    /// "asdf asdf asdf".subst_marks(vec![0,5,8], "Z");
+    /// let string = "\"Can't stop\", she said—at 3.14 p.m. 東京へ";
+    /// let words: Vec<&str> = string.unicode_words().collect();
+    /// assert_eq!(
+    ///   words,
+    ///   vec!["Can't", "stop", "she", "said", "at", "3.14", "p.m", "東", "京", "へ"]
+    /// );
     /// ```
+    fn unicode_words(&'a self) -> UnicodeWords<'a>;
+
+    /// Returns a WordBounds which yields the pieces between every word boundary.
     ///
-    /// # Output
-    /// ```text
-    /// "Zsdf ZsdZ asdf"
+    /// # Example
     /// ```
-    fn subst_marks(&self, marks: Vec<usize>, chr: &'static str) -> String;
-}
-impl SubstMarks for str {
-    fn subst_marks(&self, marks: Vec<usize>, chr: &'static str) -> String {
-        let mut output = Vec::<u8>::with_capacity(self.len());
-        let mut count = 0;
-        let mut last = 0;
-        for i in 0..self.len() {
-            let idx = i + 1;
-            if self.is_char_boundary(idx) {
-                if marks.contains(&count) {
-                    count += 1;
-                    last = idx;
-                    output.extend_from_slice(chr.as_bytes());
-                    continue;
-                }
+    /// use array_tool::string::ToWordIter;
+    ///
+    /// let string = "Hi, you  👍🏽!";
+    /// let pieces: Vec<&str> = string.word_bounds().collect();
+    /// assert_eq!(pieces, vec!["Hi", ",", " ", "you", "  ", "👍🏽", "!"]);
+    /// ```
+    fn word_bounds(&'a self) -> WordBounds<'a>;
+}
+impl<'a> ToWordIter<'a> for str {
+    fn unicode_words(&'a self) -> UnicodeWords<'a> {
+        UnicodeWords::new(self)
+    }
+    fn word_bounds(&'a self) -> WordBounds<'a> {
+        WordBounds::new(self)
+    }
+}
 
-                let slice: &[u8] = self[last..idx].as_bytes();
-                output.extend_from_slice(slice);
+/// An iterator over the sentences of a string, as defined by
+/// [UAX #29](https://www.unicode.org/reports/tr29/).  Each sentence keeps the spaces and
+/// line ending after it, so joining them gives back the whole string.
+#[derive(Clone, Debug)]
+pub struct Sentences<'a> {
+    source: &'a str,
+    front: usize,
+}
+impl<'a> Sentences<'a> {
+    /// Creates a new sentence iterator from a string source.
+    pub fn new(source: &'a str) -> Sentences<'a> {
+        Sentences { source, front: 0 }
+    }
 
-                count += 1;
-                last = idx
-            }
+    /// The byte offset of the next sentence.
+    pub fn offset(&self) -> usize {
+        self.front
+    }
+
+    /// The part of the source which has not been iterated over yet.
+    pub fn as_str(&self) -> &'a str {
+        &self.source[self.front..]
+    }
+}
+impl<'a> Iterator for Sentences<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        if self.front == self.source.len() {
+            return None;
         }
-        String::from_utf8(output).expect("subst_marks failed to render String!")
+        let start = self.front;
+        self.front = sentence_bound_end(self.source, start);
+        Some(&self.source[start..self.front])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let bytes = self.source.len() - self.front;
+        (usize::from(bytes > 0), Some(bytes))
     }
 }
+impl<'a> FusedIterator for Sentences<'a> {}
 
-/// After whitespace
-pub trait AfterWhitespace {
-    /// Given offset method will seek from there to end of string to find the first
-    /// non white space.  Resulting value is counted from offset.
+/// ToSentenceIter - iterate over the sentences of a string.
+pub trait ToSentenceIter<'a> {
+    /// Returns a Sentences which yields every sentence along with the spaces after it.  A
+    /// full stop followed by a lower case word, as in an abbreviation, does not end one.
     ///
     /// # Example
     /// ```
-    /// use array_tool::string::AfterWhitespace;
+    /// use array_tool::string::ToSentenceIter;
     ///
+    /// let string = "Mr. Smith left, e.g. at 3.30 p.m. today. \"Why?\" It was late.\nOK";
+    /// let sentences: Vec<&str> = string.sentences().collect();
     /// assert_eq!(
-    ///   "asdf           asdf asdf".seek_end_of_whitespace(6),
-    ///   Some(9)
+    ///   sentences,
+    ///   vec![
+    ///     "Mr. ",
+    ///     "Smith left, e.g. at 3.30 p.m. today. ",
+    ///     "\"Why?\" ",
+    ///     "It was late.\n",
+    ///     "OK",
+    ///   ]
     /// );
     /// ```
-    fn seek_end_of_whitespace(&self, offset: usize) -> Option<usize>;
+    fn sentences(&'a self) -> Sentences<'a>;
 }
-impl AfterWhitespace for str {
-    fn seek_end_of_whitespace(&self, offset: usize) -> Option<usize> {
-        if self.len() < offset {
-            return None;
-        };
-        let mut seeker = self[offset..self.len()].chars();
-        let mut val = None;
-        let mut indx = 0;
-        while let Some(x) = seeker.next() {
-            if x.ne(&" ".chars().next().unwrap()) {
-                val = Some(indx);
-                break;
+impl<'a> ToSentenceIter<'a> for str {
+    fn sentences(&'a self) -> Sentences<'a> {
+        Sentences::new(self)
+    }
+}
+
+/// The version of Unicode which display widths are based on.
+pub const UNICODE_VERSION: (u8, u8, u8) = tables::UNICODE_VERSION;
+
+/// DisplayWidth - the number of terminal columns text takes up.
+///
+/// Widths follow [UAX #11](https://www.unicode.org/reports/tr11/): East Asian Wide and
+/// Fullwidth characters, such as CJK ideographs and most emoji, take up two columns, while
+/// combining marks, zero width characters and control characters take up none.  Every other
+/// character takes up one column.
+pub trait DisplayWidth {
+    /// # Example
+    /// ```
+    /// use array_tool::string::DisplayWidth;
+    ///
+    /// assert_eq!("abc".display_width(), 3);
+    /// assert_eq!("漢字".display_width(), 4);
+    /// assert_eq!("e\u{301}".display_width(), 1);
+    /// ```
+    fn display_width(&self) -> usize;
+
+    /// Measures like `display_width`, except that characters of ambiguous East Asian Width
+    /// take up two columns, as they do in Chinese, Japanese and Korean terminals.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::DisplayWidth;
+    ///
+    /// assert_eq!("±1°".display_width(), 3);
+    /// assert_eq!("±1°".display_width_cjk(), 5);
+    /// ```
+    fn display_width_cjk(&self) -> usize;
+}
+impl DisplayWidth for char {
+    fn display_width(&self) -> usize {
+        let c = *self;
+        if c < '\u{7F}' {
+            if c >= ' ' {
+                1
+            } else {
+                0
             }
-            indx += 1;
+        } else if c < '\u{A0}' || tables::in_table(c, tables::ZERO_WIDTH) {
+            0
+        } else if tables::in_table(c, tables::WIDE) {
+            2
+        } else {
+            1
+        }
+    }
+
+    fn display_width_cjk(&self) -> usize {
+        if *self >= '\u{A0}' && tables::in_table(*self, tables::AMBIGUOUS) {
+            2
+        } else {
+            self.display_width()
         }
-        val
     }
 }
+impl DisplayWidth for str {
+    fn display_width(&self) -> usize {
+        self.chars().map(|c| c.display_width()).sum()
+    }
 
-/// Word wrapping
-pub trait WordWrap {
-    ///  White space is treated as valid content and new lines will only be swapped in for
-    ///  the last white space character at the end of the given width.  White space may reach beyond
-    ///  the width you've provided.  You will need to trim end of lines in your own output (e.g.
-    ///  splitting string at each new line and printing the line with trim_right).  Or just trust
-    ///  that lines that are beyond the width are just white space and only print the width -
-    ///  ignoring tailing white space.
+    fn display_width_cjk(&self) -> usize {
+        self.chars().map(|c| c.display_width_cjk()).sum()
+    }
+}
+impl DisplayWidth for String {
+    fn display_width(&self) -> usize {
+        self.as_str().display_width()
+    }
+
+    fn display_width_cjk(&self) -> usize {
+        self.as_str().display_width_cjk()
+    }
+}
+impl<'a> DisplayWidth for Cow<'a, str> {
+    fn display_width(&self) -> usize {
+        (**self).display_width()
+    }
+
+    fn display_width_cjk(&self) -> usize {
+        (**self).display_width_cjk()
+    }
+}
+
+/// Where `Truncate` cuts text out, and so where the ellipsis goes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum TruncatePosition {
+    /// Keeps the end of the text, as for paths.
+    Start,
+    /// Keeps the start and end of the text, as for file names.
+    Middle,
+    /// Keeps the start of the text.
+    #[default]
+    End,
+}
+
+/// The extended grapheme clusters of `text`, such as a letter and its accents or a
+/// sequence of joined emoji.
+fn grapheme_clusters(text: &str) -> Vec<&str> {
+    let mut clusters = vec![];
+    let mut start = 0;
+    while start < text.len() {
+        let end = grapheme_end(text, start);
+        clusters.push(&text[start..end]);
+        start = end;
+    }
+    clusters
+}
+
+/// The number of `clusters` from the front which fit in `width`, and the width they take.
+fn clusters_fitting<'a, I>(clusters: I, width: usize) -> (usize, usize)
+where
+    I: Iterator<Item = &'a &'a str>,
+{
+    let mut used = 0;
+    let mut count = 0;
+    for cluster in clusters {
+        let cluster_width = cluster.display_width();
+        if used + cluster_width > width {
+            break;
+        }
+        used += cluster_width;
+        count += 1;
+    }
+    (count, used)
+}
+
+/// Truncate - shorten text to a number of terminal columns, as given by `DisplayWidth`,
+/// marking where it was cut with an ellipsis.  Text is only cut between extended grapheme
+/// clusters, so accents stay on their letters and emoji sequences stay whole.
+pub trait Truncate {
+    /// Cuts the end off text wider than `width`, leaving room for a `…` at the end.  Text
+    /// which fits is returned as it is.
     ///
     /// # Example
     /// ```
-    /// use array_tool::string::WordWrap;
+    /// use array_tool::string::Truncate;
     ///
-    /// "asd asdf asd".word_wrap(8);
+    /// assert_eq!("Ada Lovelace".truncate_to_width(8), "Ada Lov…");
+    /// assert_eq!("Ada".truncate_to_width(8), "Ada");
+    /// assert_eq!("東京都庁".truncate_to_width(6), "東京…");
+    /// assert_eq!("Zoe\u{301} 👩‍👩‍👧".truncate_to_width(4), "Zoe\u{301}…");
     /// ```
+    fn truncate_to_width(&self, width: usize) -> String;
+
+    /// Shortens text wider than `width` like `truncate_to_width`, with a choice of
+    /// ellipsis and of where the text is cut.  The ellipsis counts towards the width, and
+    /// is itself cut short when it doesn't fit.
     ///
-    /// # Output
-    /// ```text
-    /// "asd asdf\nasd"
+    /// # Example
     /// ```
-    fn word_wrap(&self, width: usize) -> String;
+    /// use array_tool::string::{Truncate, TruncatePosition};
+    ///
+    /// let path = "/home/ada/notes/engine.txt";
+    /// assert_eq!(path.truncate_to_width_with(16, "...", TruncatePosition::Start), "...es/engine.txt");
+    /// assert_eq!(path.truncate_to_width_with(16, "~", TruncatePosition::Middle), "/home/ad~ine.txt");
+    /// assert_eq!(path.truncate_to_width_with(16, "", TruncatePosition::End), "/home/ada/notes/");
+    /// ```
+    fn truncate_to_width_with(
+        &self,
+        width: usize,
+        ellipsis: &str,
+        position: TruncatePosition,
+    ) -> String;
 }
-// No need to worry about character encoding since we're only checking for the
-// space and new line characters.
-impl WordWrap for &'static str {
-    fn word_wrap(&self, width: usize) -> String {
-        let mut markers = vec![];
-        fn wordwrap(
-            t: &'static str,
-            chunk: usize,
-            offset: usize,
-            mrkrs: &mut Vec<usize>,
-        ) -> String {
-            match t[offset..*vec![offset + chunk, t.len()].iter().min().unwrap()].rfind("\n") {
-                None => {
-                    match t[offset..*vec![offset + chunk, t.len()].iter().min().unwrap()].rfind(" ")
-                    {
-                        Some(x) => {
-                            let mut eows = x; // end of white space
-                            if offset + chunk < t.len() {
-                                // check if white space continues
-                                match t.seek_end_of_whitespace(offset + x) {
-                                    Some(a) => {
-                                        if a.ne(&0) {
-                                            eows = x + a - 1;
-                                        }
-                                    }
-                                    None => {}
-                                }
-                            }
-                            if offset + chunk < t.len() {
-                                // safe to seek ahead by 1 or not end of string
-                                if !["\n".chars().next().unwrap(), " ".chars().next().unwrap()]
-                                    .contains(
-                                        &t[offset + eows + 1..offset + eows + 2]
-                                            .chars()
-                                            .next()
-                                            .unwrap(),
-                                    )
-                                {
-                                    mrkrs.push(offset + eows)
-                                }
-                            };
-                            wordwrap(t, chunk, offset + eows + 1, mrkrs)
-                        }
-                        None => {
-                            if offset + chunk < t.len() {
-                                // String may continue
-                                wordwrap(t, chunk, offset + 1, mrkrs) // Recurse + 1 until next space
-                            } else {
-                                return t.subst_marks(mrkrs.to_vec(), "\n");
-                            }
-                        }
-                    }
-                }
-                Some(x) => wordwrap(t, chunk, offset + x + 1, mrkrs),
-            }
+
+impl<T: AsRef<str> + ?Sized> Truncate for T {
+    fn truncate_to_width(&self, width: usize) -> String {
+        self.truncate_to_width_with(width, "…", TruncatePosition::End)
+    }
+
+    fn truncate_to_width_with(
+        &self,
+        width: usize,
+        ellipsis: &str,
+        position: TruncatePosition,
+    ) -> String {
+        let text = self.as_ref();
+        if text.display_width() <= width {
+            return text.to_string();
+        }
+        let ellipsis_width = ellipsis.display_width();
+        if ellipsis_width >= width {
+            return ellipsis.truncate_to_width_with(width, "", TruncatePosition::End);
         }
-        wordwrap(self, width + 1, 0, &mut markers)
+        let room = width - ellipsis_width;
+        let clusters = grapheme_clusters(text);
+        let (front, back) = match position {
+            TruncatePosition::Start => (0, clusters_fitting(clusters.iter().rev(), room).0),
+            TruncatePosition::Middle => {
+                let (front, used) = clusters_fitting(clusters.iter(), room - room / 2);
+                (
+                    front,
+                    clusters_fitting(clusters.iter().rev(), room - used).0,
+                )
+            }
+            TruncatePosition::End => (clusters_fitting(clusters.iter(), room).0, 0),
+        };
+
+        let mut output: String = clusters[..front].concat();
+        output.push_str(ellipsis);
+        output.push_str(&clusters[clusters.len() - back..].concat());
+        output
+    }
+}
+
+/// GraphemeDistance - edit distances and similarity between strings, counted in extended
+/// grapheme clusters so that an accented letter or an emoji sequence is one edit.  These
+/// are the measures of `vec::EditDistance`, and the `_within` forms likewise make fast
+/// comparators for fuzzy matching.
+///
+/// # Example
+/// ```
+/// use array_tool::string::GraphemeDistance;
+/// use array_tool::vec::Uniq;
+///
+/// let products = vec!["iPhone 15 Pro", "iPhone 15 Pro ", "iphone 15 pro", "Pixel 8"];
+/// assert_eq!(
+///   products.unique_via(|a, b| a.to_lowercase().levenshtein_within(&b.to_lowercase(), 1).is_some()),
+///   vec!["iPhone 15 Pro", "Pixel 8"]
+/// );
+/// ```
+pub trait GraphemeDistance {
+    /// The fewest grapheme insertions, deletions and substitutions which turn one string
+    /// into the other.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::GraphemeDistance;
+    ///
+    /// assert_eq!("kitten".levenshtein("sitting"), 3);
+    /// assert_eq!("cafe".levenshtein("cafe\u{301}"), 1);
+    /// ```
+    fn levenshtein(&self, other: &str) -> usize;
+
+    /// The Levenshtein distance, or `None` when it is more than `limit`.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::GraphemeDistance;
+    ///
+    /// assert_eq!("Lovelace".levenshtein_within("Lovelaces", 1), Some(1));
+    /// assert_eq!("Lovelace".levenshtein_within("Babbage", 3), None);
+    /// ```
+    fn levenshtein_within(&self, other: &str, limit: usize) -> Option<usize>;
+
+    /// Like `levenshtein`, with swapping two neighbouring graphemes counting as one edit
+    /// (optimal string alignment).
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::GraphemeDistance;
+    ///
+    /// assert_eq!("recieve".damerau_levenshtein("receive"), 1);
+    /// assert_eq!("ca".damerau_levenshtein("abc"), 3);
+    /// ```
+    fn damerau_levenshtein(&self, other: &str) -> usize;
+
+    /// The optimal string alignment distance, or `None` when it is more than `limit`.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::GraphemeDistance;
+    ///
+    /// assert_eq!("teh cat".damerau_levenshtein_within("the cat", 1), Some(1));
+    /// ```
+    fn damerau_levenshtein_within(&self, other: &str, limit: usize) -> Option<usize>;
+
+    /// The number of graphemes which differ position by position, counting each grapheme
+    /// past the end of the shorter string as a difference.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::GraphemeDistance;
+    ///
+    /// assert_eq!("karolin".hamming("kathrin"), 3);
+    /// assert_eq!("🇫🇷🇩🇪".hamming("🇫🇷🇮🇹"), 1);
+    /// ```
+    fn hamming(&self, other: &str) -> usize;
+
+    /// The Hamming distance, or `None` when it is more than `limit`.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::GraphemeDistance;
+    ///
+    /// assert_eq!("karolin".hamming_within("kathrin", 2), None);
+    /// ```
+    fn hamming_within(&self, other: &str, limit: usize) -> Option<usize>;
+
+    /// The Jaro–Winkler similarity, from 0.0 for nothing in common to 1.0 for equal
+    /// strings.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::GraphemeDistance;
+    ///
+    /// assert!("MARTHA".jaro_winkler("MARHTA") > 0.96);
+    /// assert!("DWAYNE".jaro_winkler("DUANE") > "DWAYNE".jaro_winkler("WAYNE DOE"));
+    /// ```
+    fn jaro_winkler(&self, other: &str) -> f64;
+}
+
+impl<T: AsRef<str> + ?Sized> GraphemeDistance for T {
+    fn levenshtein(&self, other: &str) -> usize {
+        grapheme_clusters(self.as_ref()).levenshtein(&grapheme_clusters(other))
+    }
+    fn levenshtein_within(&self, other: &str, limit: usize) -> Option<usize> {
+        grapheme_clusters(self.as_ref()).levenshtein_within(&grapheme_clusters(other), limit)
+    }
+    fn damerau_levenshtein(&self, other: &str) -> usize {
+        grapheme_clusters(self.as_ref()).damerau_levenshtein(&grapheme_clusters(other))
+    }
+    fn damerau_levenshtein_within(&self, other: &str, limit: usize) -> Option<usize> {
+        grapheme_clusters(self.as_ref())
+            .damerau_levenshtein_within(&grapheme_clusters(other), limit)
+    }
+    fn hamming(&self, other: &str) -> usize {
+        grapheme_clusters(self.as_ref()).hamming(&grapheme_clusters(other))
+    }
+    fn hamming_within(&self, other: &str, limit: usize) -> Option<usize> {
+        grapheme_clusters(self.as_ref()).hamming_within(&grapheme_clusters(other), limit)
+    }
+    fn jaro_winkler(&self, other: &str) -> f64 {
+        grapheme_clusters(self.as_ref()).jaro_winkler(&grapheme_clusters(other))
+    }
+}
+
+/// A character set specification in the style of Ruby's `String#count`,
+/// `String#squeeze` and friends.
+///
+/// * `a-z` is an inclusive range of characters
+/// * a leading `^` negates the set (a lone `^` is a literal)
+/// * a backslash escapes the following character, so `\-` and `\^` are literals
+/// * a `-` at the start or end of the specification is a literal
+///
+/// A descending range such as `z-a` contains nothing.
+#[derive(Clone, Debug)]
+struct CharSpec {
+    negated: bool,
+    ranges: Vec<(char, char)>,
+}
+impl CharSpec {
+    fn parse(spec: &str) -> CharSpec {
+        if spec.starts_with('^') && spec.len() > 1 {
+            CharSpec {
+                negated: true,
+                ..CharSpec::parse_sequence(&spec[1..])
+            }
+        } else {
+            CharSpec::parse_sequence(spec)
+        }
+    }
+
+    /// Parses a specification where a leading `^` is a literal, as for the replacement
+    /// side of `tr`.
+    fn parse_sequence(spec: &str) -> CharSpec {
+        let mut chars = spec.chars().peekable();
+        let mut ranges = vec![];
+        while let Some(mut first) = chars.next() {
+            if first == '\\' {
+                if let Some(escaped) = chars.next() {
+                    first = escaped;
+                }
+            }
+            if chars.peek() == Some(&'-') {
+                let mut lookahead = chars.clone();
+                lookahead.next();
+                if let Some(last) = lookahead.next() {
+                    chars = lookahead;
+                    ranges.push((first, last));
+                    continue;
+                }
+            }
+            ranges.push((first, first));
+        }
+        CharSpec {
+            negated: false,
+            ranges,
+        }
+    }
+
+    fn contains(&self, character: char) -> bool {
+        let found = self
+            .ranges
+            .iter()
+            .any(|&(first, last)| first <= character && character <= last);
+        found != self.negated
+    }
+
+    fn range_len(&(first, last): &(char, char)) -> usize {
+        if first <= last {
+            (last as u32 - first as u32) as usize + 1
+        } else {
+            0
+        }
+    }
+
+    /// Position of the last occurrence of `character` in the expanded sequence.
+    fn position(&self, character: char) -> Option<usize> {
+        let mut start = 0;
+        let mut found = None;
+        for range in &self.ranges {
+            if range.0 <= character && character <= range.1 {
+                found = Some(start + (character as u32 - range.0 as u32) as usize);
+            }
+            start += CharSpec::range_len(range);
+        }
+        found
+    }
+
+    /// The character at `index` of the expanded sequence, or the final character when the
+    /// sequence is shorter than that.
+    fn nth_or_last(&self, mut index: usize) -> Option<char> {
+        let mut last = None;
+        for range in &self.ranges {
+            let len = CharSpec::range_len(range);
+            if len == 0 {
+                continue;
+            }
+            if index < len {
+                return ::std::char::from_u32(range.0 as u32 + index as u32).or(Some(range.1));
+            }
+            index -= len;
+            last = Some(range.1);
+        }
+        last
+    }
+}
+
+/// The intersection of one or more character set specifications.
+#[derive(Clone, Debug)]
+struct CharSpecs {
+    specs: Vec<CharSpec>,
+}
+impl CharSpecs {
+    fn parse<S: AsRef<str>>(specs: &[S]) -> CharSpecs {
+        CharSpecs {
+            specs: specs
+                .iter()
+                .map(|spec| CharSpec::parse(spec.as_ref()))
+                .collect(),
+        }
+    }
+
+    fn contains(&self, character: char) -> bool {
+        self.specs.iter().all(|spec| spec.contains(character))
+    }
+}
+
+/// Squeeze - squeezes duplicate characters down to one each
+pub trait Squeeze {
+    /// Squeezes runs of the characters in `targets` down to one each.  An empty
+    /// `targets` squeezes every character.  Otherwise `targets` is a character set
+    /// specification as in Ruby's `String#squeeze`, where `a-z` is a range, a leading `^`
+    /// negates the set and a backslash escapes a `-` or `^`.  Characters are `char`s, so
+    /// an accent which follows its letter is a character of its own.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::Squeeze;
+    ///
+    /// "yellow moon".squeeze("");
+    /// ```
+    ///
+    /// # Output
+    /// ```text
+    /// "yelow mon"
+    /// ```
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::Squeeze;
+    ///
+    /// assert_eq!("putters shoot balls".squeeze("m-z"), "puters shot balls");
+    /// assert_eq!("aaabbbccc".squeeze("^b"), "abbbc");
+    /// ```
+    fn squeeze(&self, targets: &str) -> String;
+
+    /// Squeezes runs of the characters found in every one of the given character set
+    /// specifications, like Ruby's `String#squeeze` given several arguments.  No
+    /// specifications at all squeezes every character.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::Squeeze;
+    ///
+    /// assert_eq!("hello  wwoorld".squeeze_sets(&["a-z", "^l"]), "hello  world");
+    /// assert_eq!("hello  wwoorld".squeeze_sets(&[]), "helo world");
+    /// ```
+    fn squeeze_sets(&self, sets: &[&str]) -> String;
+}
+impl<T: AsRef<str> + ?Sized> Squeeze for T {
+    fn squeeze(&self, targets: &str) -> String {
+        if targets.is_empty() {
+            self.squeeze_sets(&[])
+        } else {
+            self.squeeze_sets(&[targets])
+        }
+    }
+
+    fn squeeze_sets(&self, sets: &[&str]) -> String {
+        let text = self.as_ref();
+        let specs = CharSpecs::parse(sets);
+        let mut output = String::with_capacity(text.len());
+        let mut last: Option<char> = None;
+        for character in text.chars() {
+            if last != Some(character) || !specs.contains(character) {
+                output.push(character);
+            }
+            last = Some(character);
+        }
+        output
+    }
+}
+
+/// Translation table for `tr` built from a source and a replacement specification.
+#[derive(Clone, Debug)]
+struct Translation {
+    from: CharSpec,
+    to: CharSpec,
+}
+impl Translation {
+    fn new(from: &str, to: &str) -> Translation {
+        Translation {
+            from: CharSpec::parse(from),
+            to: CharSpec::parse_sequence(to),
+        }
+    }
+
+    /// Returns `None` for characters which are not translated, and `Some(None)` for
+    /// characters which are deleted because the replacement is empty.
+    fn translate(&self, character: char) -> Option<Option<char>> {
+        let index = if self.from.negated {
+            if self.from.contains(character) {
+                usize::MAX
+            } else {
+                return None;
+            }
+        } else {
+            self.from.position(character)?
+        };
+        Some(self.to.nth_or_last(index))
+    }
+}
+
+/// Tr - translate characters, like Ruby's `String#tr` and `String#tr_s`.
+pub trait Tr {
+    /// Replaces each character found in `from` with the character at the same position
+    /// in `to`.  Both use the character set specification of `Squeeze::squeeze`, although
+    /// a leading `^` in `to` is a literal.  When `to` is shorter than `from` it is padded
+    /// with its last character, when `from` is negated every character outside of it
+    /// becomes the last character of `to`, and when `to` is empty the characters are
+    /// deleted.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::Tr;
+    ///
+    /// "hello".tr("el", "ip");
+    /// ```
+    ///
+    /// # Output
+    /// ```text
+    /// "hippo"
+    /// ```
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::Tr;
+    ///
+    /// assert_eq!("hello".tr("a-y", "b-z"), "ifmmp");
+    /// assert_eq!("hello".tr("^l", "*"), "**ll*");
+    /// assert_eq!("hello".tr("el", ""), "ho");
+    /// ```
+    fn tr(&self, from: &str, to: &str) -> String;
+
+    /// Translates like `tr` and then squeezes runs of characters which were translated into
+    /// the same character.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::Tr;
+    ///
+    /// "hello".tr_s("l", "r");
+    /// ```
+    ///
+    /// # Output
+    /// ```text
+    /// "hero"
+    /// ```
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::Tr;
+    ///
+    /// assert_eq!("hello".tr_s("el", "-"), "h-o");
+    /// assert_eq!("aabbcc".tr_s("a-c", "x"), "x");
+    /// ```
+    fn tr_s(&self, from: &str, to: &str) -> String;
+}
+impl<T: AsRef<str> + ?Sized> Tr for T {
+    fn tr(&self, from: &str, to: &str) -> String {
+        let text = self.as_ref();
+        let translation = Translation::new(from, to);
+        let mut output = String::with_capacity(text.len());
+        for character in text.chars() {
+            match translation.translate(character) {
+                Some(Some(replacement)) => output.push(replacement),
+                Some(None) => {}
+                None => output.push(character),
+            }
+        }
+        output
+    }
+
+    fn tr_s(&self, from: &str, to: &str) -> String {
+        let text = self.as_ref();
+        let translation = Translation::new(from, to);
+        let mut output = String::with_capacity(text.len());
+        let mut last: Option<char> = None;
+        for character in text.chars() {
+            match translation.translate(character) {
+                Some(Some(replacement)) => {
+                    if last != Some(replacement) {
+                        output.push(replacement);
+                    }
+                    last = Some(replacement);
+                }
+                Some(None) => {}
+                None => {
+                    output.push(character);
+                    last = None;
+                }
+            }
+        }
+        output
+    }
+}
+
+/// Delete - remove characters, like Ruby's `String#delete`.
+pub trait Delete {
+    /// Removes every character in the character set specification `targets`, which follows
+    /// the same rules as `Squeeze::squeeze`.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::Delete;
+    ///
+    /// "hello".delete("l-o");
+    /// ```
+    ///
+    /// # Output
+    /// ```text
+    /// "he"
+    /// ```
+    fn delete(&self, targets: &str) -> String;
+
+    /// Removes every character found in all of the given character set specifications.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::Delete;
+    ///
+    /// assert_eq!("hello".delete_sets(&["a-z", "^l"]), "ll");
+    /// ```
+    fn delete_sets(&self, sets: &[&str]) -> String;
+}
+impl<T: AsRef<str> + ?Sized> Delete for T {
+    fn delete(&self, targets: &str) -> String {
+        self.delete_sets(&[targets])
+    }
+
+    fn delete_sets(&self, sets: &[&str]) -> String {
+        let specs = CharSpecs::parse(sets);
+        self.as_ref()
+            .chars()
+            .filter(|&character| !specs.contains(character))
+            .collect()
+    }
+}
+
+/// Count - count characters, like Ruby's `String#count`.
+pub trait Count {
+    /// Counts the characters in the character set specification `targets`, which follows
+    /// the same rules as `Squeeze::squeeze`.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::Count;
+    ///
+    /// "hello world".count("lo");
+    /// ```
+    ///
+    /// # Output
+    /// ```text
+    /// 5
+    /// ```
+    fn count(&self, targets: &str) -> usize;
+
+    /// Counts the characters found in all of the given character set specifications.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::Count;
+    ///
+    /// assert_eq!("hello world".count_sets(&["lo", "o"]), 2);
+    /// assert_eq!("hello world".count_sets(&["a-y", "^l"]), 7);
+    /// ```
+    fn count_sets(&self, sets: &[&str]) -> usize;
+}
+impl<T: AsRef<str> + ?Sized> Count for T {
+    fn count(&self, targets: &str) -> usize {
+        self.count_sets(&[targets])
+    }
+
+    fn count_sets(&self, sets: &[&str]) -> usize {
+        let specs = CharSpecs::parse(sets);
+        self.as_ref()
+            .chars()
+            .filter(|&character| specs.contains(character))
+            .count()
+    }
+}
+
+/// How `Justify` hands out the spaces which don't divide evenly between the gaps of a line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum SpaceDistribution {
+    /// Extra spaces go to the leftmost gaps.
+    #[default]
+    Left,
+    /// Extra spaces go to the rightmost gaps.
+    Right,
+    /// Extra spaces go to the leftmost gaps on the first line of a paragraph, the
+    /// rightmost gaps on the next line and so on.
+    Alternating,
+}
+impl SpaceDistribution {
+    fn favors_left(self, line: usize) -> bool {
+        match self {
+            SpaceDistribution::Left => true,
+            SpaceDistribution::Right => false,
+            SpaceDistribution::Alternating => line & 1 == 0,
+        }
+    }
+}
+
+/// Joins `words` with enough spaces between them to fill `width`.  Returns `None` when
+/// there is no gap to fill or the words already fill the width.
+fn justify_words(words: &[&str], width: usize, favor_left: bool) -> Option<String> {
+    let gaps = words.len().checked_sub(1)?;
+    let content: usize = words.iter().map(|word| word.display_width()).sum();
+    if gaps == 0 || content + gaps >= width {
+        return None;
+    }
+    let difference = width - content;
+    let div = difference / gaps;
+    let remainder = difference % gaps;
+
+    let mut obj = String::with_capacity(width + words.iter().map(|w| w.len()).sum::<usize>());
+    for (index, word) in words.iter().enumerate() {
+        obj.push_str(word);
+        if index == gaps {
+            // Don't add spaces if last word
+            break;
+        }
+        let extra = if favor_left {
+            index < remainder
+        } else {
+            index >= gaps - remainder
+        };
+        let spaces = if extra { div + 1 } else { div };
+        for _ in 0..spaces {
+            obj.push(' ');
+        }
+    }
+    Some(obj)
+}
+
+/// Justify - expand line to given width.  Widths are measured in terminal columns, as
+/// given by `DisplayWidth`.
+pub trait Justify {
+    /// # Example
+    /// ```
+    /// use array_tool::string::Justify;
+    ///
+    /// "asd asdf asd".justify_line(14);
+    /// ```
+    ///
+    /// # Output
+    /// ```text
+    /// "asd  asdf  asd"
+    /// ```
+    fn justify_line(&self, width: usize) -> String;
+
+    /// Justifies one line like `justify_line`, with a choice of where the extra spaces go.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::{Justify, SpaceDistribution};
+    ///
+    /// assert_eq!(
+    ///   "asd as df asd".justify_line_with(18, SpaceDistribution::Right),
+    ///   "asd  as   df   asd"
+    /// );
+    /// ```
+    fn justify_line_with(&self, width: usize, distribution: SpaceDistribution) -> String;
+
+    /// Wraps text to `width` and fully justifies every line except for the last line of
+    /// each paragraph, which stays left aligned.  Lines within a paragraph are reflowed,
+    /// while blank lines separate paragraphs and are kept.  A word longer than `width`
+    /// gets a line of its own.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::Justify;
+    ///
+    /// "The quick brown fox jumps over the lazy dog.".justify(16);
+    /// ```
+    ///
+    /// # Output
+    /// ```text
+    /// "The  quick brown\nfox  jumps  over\nthe lazy dog."
+    /// ```
+    fn justify(&self, width: usize) -> String;
+
+    /// Justifies paragraphs like `justify`, with a choice of where the extra spaces go.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::{Justify, SpaceDistribution};
+    ///
+    /// assert_eq!(
+    ///   "The quick brown fox jumps over the lazy dog.".justify_with(16, SpaceDistribution::Right),
+    ///   "The quick  brown\nfox  jumps  over\nthe lazy dog."
+    /// );
+    /// ```
+    fn justify_with(&self, width: usize, distribution: SpaceDistribution) -> String;
+
+    /// Justifies paragraphs like `justify_with`, wrapping them as configured by
+    /// `WrapOptions`.  This allows optimal fit line breaking, indents, breaking long words
+    /// and soft hyphens.  Each line is justified to the width left over after its indent.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::{Justify, Penalties, SpaceDistribution, WrapAlgorithm, WrapOptions};
+    ///
+    /// let options = WrapOptions::new(6).algorithm(WrapAlgorithm::OptimalFit(Penalties::new()));
+    /// assert_eq!(
+    ///   "aaa bb cc ddddd".justify_with_options(&options, SpaceDistribution::Left),
+    ///   "aaa\nbb  cc\nddddd"
+    /// );
+    /// ```
+    fn justify_with_options(
+        &self,
+        options: &WrapOptions,
+        distribution: SpaceDistribution,
+    ) -> String;
+}
+
+impl<T: AsRef<str> + ?Sized> Justify for T {
+    fn justify_line(&self, width: usize) -> String {
+        self.justify_line_with(width, SpaceDistribution::Left)
+    }
+
+    fn justify_line_with(&self, width: usize, distribution: SpaceDistribution) -> String {
+        let text = self.as_ref();
+        let words: Vec<&str> = glue_separated(text).collect();
+        justify_words(&words, width, distribution.favors_left(0))
+            .unwrap_or_else(|| text.to_string())
+    }
+
+    fn justify(&self, width: usize) -> String {
+        self.justify_with(width, SpaceDistribution::Left)
+    }
+
+    fn justify_with(&self, width: usize, distribution: SpaceDistribution) -> String {
+        let options = WrapOptions::new(width).soft_hyphens(false);
+        self.justify_with_options(&options, distribution)
+    }
+
+    fn justify_with_options(
+        &self,
+        options: &WrapOptions,
+        distribution: SpaceDistribution,
+    ) -> String {
+        let text = tabs_expanded(self.as_ref(), options);
+        let mut lines = vec![];
+        let mut paragraph = vec![];
+        for line in text.lines() {
+            if line.trim().is_empty() {
+                justify_paragraph(&paragraph.join(" "), options, distribution, &mut lines);
+                paragraph.clear();
+                lines.push(options.indent_for(lines.len()).trim_end().to_string());
+            } else {
+                paragraph.extend(glue_separated(line));
+            }
+        }
+        justify_paragraph(&paragraph.join(" "), options, distribution, &mut lines);
+
+        let mut output = lines.join("\n");
+        if text.ends_with('\n') {
+            output.push('\n');
+        }
+        output
+    }
+}
+
+/// Wraps the words of a paragraph and justifies all but its last line, adding them to
+/// `lines` with their indents.
+fn justify_paragraph(
+    paragraph: &str,
+    options: &WrapOptions,
+    distribution: SpaceDistribution,
+    lines: &mut Vec<String>,
+) {
+    if paragraph.is_empty() {
+        return;
+    }
+    // Only the very first line of the text takes the initial indent.
+    let options = if lines.is_empty() {
+        Cow::Borrowed(options)
+    } else {
+        Cow::Owned(
+            options
+                .clone()
+                .initial_indent(options.subsequent_indent.clone()),
+        )
+    };
+    let wrapped = wrap(paragraph, &options);
+    for (index, line) in wrapped.iter().enumerate() {
+        let mut content: String = paragraph[line.start..line.end]
+            .chars()
+            .filter(|&c| !(options.soft_hyphens && c == SOFT_HYPHEN))
+            .collect();
+        if line.hyphen {
+            content.push('-');
+        }
+        let words: Vec<&str> = glue_separated(&content).collect();
+        let indent = options.indent_for(index);
+        let width = options.width.saturating_sub(indent.display_width());
+        let justified = if index + 1 < wrapped.len() {
+            justify_words(&words, width, distribution.favors_left(index))
+        } else {
+            None
+        };
+        lines.push(format!(
+            "{}{}",
+            indent,
+            justified.unwrap_or_else(|| words.join(" "))
+        ));
+    }
+}
+
+/// How positions in a string are counted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Indexing {
+    /// Byte offsets, which must fall on character boundaries.
+    #[default]
+    Byte,
+    /// Characters, as given by `str::chars`.
+    Char,
+    /// Extended grapheme clusters, such as a letter and its accents, as `Truncate` counts
+    /// them.
+    Grapheme,
+}
+
+/// Why `SubstMarks::subst_ranges` couldn't apply its edits.  Each variant holds the
+/// position of the offending edit in the list of edits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SubstError {
+    /// The edit's range starts after it ends.
+    InvalidRange(usize),
+    /// The edit's range starts before the end of the previous edit and overlaps it.
+    Overlap(usize),
+    /// The edit comes entirely before the previous edit.  Edits must be sorted.
+    Unsorted(usize),
+    /// The edit's range reaches beyond the end of the string.
+    OutOfBounds(usize),
+    /// The edit's byte range doesn't start or end on a character boundary.
+    NotCharBoundary(usize),
+}
+impl fmt::Display for SubstError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SubstError::InvalidRange(edit) => write!(f, "edit {} starts after it ends", edit),
+            SubstError::Overlap(edit) => write!(f, "edit {} overlaps the edit before it", edit),
+            SubstError::Unsorted(edit) => write!(f, "edit {} is out of order", edit),
+            SubstError::OutOfBounds(edit) => write!(f, "edit {} reaches past the end", edit),
+            SubstError::NotCharBoundary(edit) => {
+                write!(f, "edit {} is not on a character boundary", edit)
+            }
+        }
+    }
+}
+impl Error for SubstError {}
+
+/// Substitute string character for each index given.
+pub trait SubstMarks {
+    /// Marks are character indexes, in any order.  Marks past the end are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::SubstMarks;
+    ///
+    /// "asdf asdf asdf".subst_marks(vec![0,5,8], "Z");
+    /// ```
+    ///
+    /// # Output
+    /// ```text
+    /// "Zsdf ZsdZ asdf"
+    /// ```
+    fn subst_marks(&self, marks: Vec<usize>, chr: &str) -> String;
+
+    /// Replaces each range with its replacement, counting positions in bytes, characters or
+    /// graphemes.  Edits must be sorted and must not overlap, though empty ranges insert
+    /// their replacement and may share a position with their neighbors.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::{Indexing, SubstError, SubstMarks};
+    ///
+    /// let redactions = [(8..12, "[name]"), (18..22, "[city]")];
+    /// assert_eq!(
+    ///   "Call me Ülla from Köln.".subst_ranges(&redactions, Indexing::Char),
+    ///   Ok("Call me [name] from [city].".to_string())
+    /// );
+    /// assert_eq!(
+    ///   "Call me Ülla.".subst_ranges(&[(8..12, "A"), (10..11, "B")], Indexing::Char),
+    ///   Err(SubstError::Overlap(1))
+    /// );
+    /// ```
+    fn subst_ranges<S: AsRef<str>>(
+        &self,
+        edits: &[(Range<usize>, S)],
+        indexing: Indexing,
+    ) -> Result<String, SubstError>;
+}
+impl<T: AsRef<str> + ?Sized> SubstMarks for T {
+    fn subst_marks(&self, marks: Vec<usize>, chr: &str) -> String {
+        let text = self.as_ref();
+        let mut marks = marks;
+        marks.sort_unstable();
+        marks.dedup();
+        let mut marks = marks.into_iter().peekable();
+        let mut output = String::with_capacity(text.len());
+        for (count, character) in text.chars().enumerate() {
+            if marks.next_if_eq(&count).is_some() {
+                output.push_str(chr);
+            } else {
+                output.push(character);
+            }
+        }
+        output
+    }
+
+    fn subst_ranges<S: AsRef<str>>(
+        &self,
+        edits: &[(Range<usize>, S)],
+        indexing: Indexing,
+    ) -> Result<String, SubstError> {
+        let text = self.as_ref();
+        for (edit, (range, _)) in edits.iter().enumerate() {
+            if range.start > range.end {
+                return Err(SubstError::InvalidRange(edit));
+            }
+            if let Some((previous, _)) = edit.checked_sub(1).map(|previous| &edits[previous]) {
+                if range.start < previous.end {
+                    return Err(if range.end <= previous.start {
+                        SubstError::Unsorted(edit)
+                    } else {
+                        SubstError::Overlap(edit)
+                    });
+                }
+            }
+        }
+
+        // The byte offset of each position in turn, ending with the length of the text.
+        let mut offsets: Box<dyn Iterator<Item = usize>> = match indexing {
+            Indexing::Byte => Box::new(0..text.len() + 1),
+            Indexing::Char => Box::new(
+                text.char_indices()
+                    .map(|(index, _)| index)
+                    .chain(Some(text.len())),
+            ),
+            Indexing::Grapheme => Box::new(grapheme_offsets(text)),
+        };
+        let mut position = 0;
+        let mut offset = offsets.next();
+        let mut byte_offset = |target: usize| -> Option<usize> {
+            while position < target {
+                position += 1;
+                offset = offsets.next();
+            }
+            offset
+        };
+
+        let replaced: usize = edits
+            .iter()
+            .map(|(_, replacement)| replacement.as_ref().len())
+            .sum();
+        let mut output = String::with_capacity(text.len() + replaced);
+        let mut copied = 0;
+        for (edit, (range, replacement)) in edits.iter().enumerate() {
+            let start = byte_offset(range.start).ok_or(SubstError::OutOfBounds(edit))?;
+            let end = byte_offset(range.end).ok_or(SubstError::OutOfBounds(edit))?;
+            if !text.is_char_boundary(start) || !text.is_char_boundary(end) {
+                return Err(SubstError::NotCharBoundary(edit));
+            }
+            output.push_str(&text[copied..start]);
+            output.push_str(replacement.as_ref());
+            copied = end;
+        }
+        output.push_str(&text[copied..]);
+        Ok(output)
+    }
+}
+
+/// The byte offset of `position`, counted as `indexing` says, if it is within `text`.
+fn byte_offset(text: &str, position: usize, indexing: Indexing) -> Option<usize> {
+    match indexing {
+        Indexing::Byte => Some(position).filter(|&position| text.is_char_boundary(position)),
+        Indexing::Char => text
+            .char_indices()
+            .map(|(index, _)| index)
+            .chain(Some(text.len()))
+            .nth(position),
+        Indexing::Grapheme => grapheme_offsets(text).nth(position),
+    }
+}
+
+/// The byte offset of each extended grapheme cluster in `text`, ending with the length of
+/// the text.
+fn grapheme_offsets(text: &str) -> impl Iterator<Item = usize> + '_ {
+    std::iter::successors(Some(0), move |&start| {
+        if start < text.len() {
+            Some(grapheme_end(text, start))
+        } else {
+            None
+        }
+    })
+}
+
+/// The length of `text`, counted as `indexing` says.
+fn position_count(text: &str, indexing: Indexing) -> usize {
+    match indexing {
+        Indexing::Byte => text.len(),
+        Indexing::Char => text.chars().count(),
+        Indexing::Grapheme => grapheme_offsets(text).count() - 1,
+    }
+}
+
+/// Whether `character` is a combining mark or other zero width character which belongs
+/// with the character before it.
+fn attaches(character: char) -> bool {
+    character.display_width() == 0 && !character.is_control()
+}
+
+/// The byte offsets of the boundaries between words and everything else, including the
+/// start and end of the text when it starts or ends with a word.  Words are made of
+/// alphanumeric characters and underscores, along with any marks attached to them.
+fn word_boundaries<'a>(text: &'a str) -> Box<dyn Iterator<Item = usize> + 'a> {
+    let is_word = |character: char| character.is_alphanumeric() || character == '_';
+    let ends_with_word = text
+        .chars()
+        .rev()
+        .find(|&c| !attaches(c))
+        .is_some_and(is_word);
+    let mut previous = false;
+    let boundaries = text.char_indices().filter_map(move |(index, character)| {
+        if attaches(character) || is_word(character) == previous {
+            return None;
+        }
+        previous = !previous;
+        Some(index)
+    });
+    Box::new(boundaries.chain(Some(text.len()).filter(|_| ends_with_word)))
+}
+
+/// Seek past or back over white space, or to the edge of a word.  White space is anything
+/// Unicode counts as white space, including tabs and new lines.
+pub trait AfterWhitespace {
+    /// Given offset method will seek from there to end of string to find the first
+    /// non white space.  Resulting value is counted from offset.  Both are counted in
+    /// bytes, and an offset which isn't on a character boundary finds nothing.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::AfterWhitespace;
+    ///
+    /// assert_eq!(
+    ///   "asdf           asdf asdf".seek_end_of_whitespace(6),
+    ///   Some(9)
+    /// );
+    /// ```
+    fn seek_end_of_whitespace(&self, offset: usize) -> Option<usize>;
+
+    /// Seeks forward like `seek_end_of_whitespace`, counting the offset and result in
+    /// bytes, characters or graphemes.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::{AfterWhitespace, Indexing};
+    ///
+    /// assert_eq!("né\t\u{3000} x".seek_end_of_whitespace_with(2, Indexing::Char), Some(3));
+    /// assert_eq!("né\t\u{3000} x".seek_end_of_whitespace_with(3, Indexing::Byte), Some(5));
+    /// ```
+    fn seek_end_of_whitespace_with(&self, offset: usize, indexing: Indexing) -> Option<usize>;
+
+    /// Given offset method will seek from there back to start of string to find the last
+    /// non white space.  Resulting value is counted back from offset, so the white space
+    /// before offset starts at offset minus the result.  Both are counted in bytes.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::AfterWhitespace;
+    ///
+    /// assert_eq!(
+    ///   "asdf           asdf asdf".seek_start_of_whitespace(13),
+    ///   Some(9)
+    /// );
+    /// ```
+    fn seek_start_of_whitespace(&self, offset: usize) -> Option<usize>;
+
+    /// Seeks back like `seek_start_of_whitespace`, counting the offset and result in
+    /// bytes, characters or graphemes.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::{AfterWhitespace, Indexing};
+    ///
+    /// assert_eq!("né \n x".seek_start_of_whitespace_with(4, Indexing::Char), Some(2));
+    /// ```
+    fn seek_start_of_whitespace_with(&self, offset: usize, indexing: Indexing) -> Option<usize>;
+
+    /// Seeks forward from offset to the next start or end of a word, as with `\b` in a
+    /// regular expression.  Resulting value is counted from offset, which is never a
+    /// result itself.  Words are made of alphanumeric characters and underscores.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::{AfterWhitespace, Indexing};
+    ///
+    /// let text = "Grüße, world!";
+    /// assert_eq!(text.seek_next_word_boundary(0, Indexing::Char), Some(5));
+    /// assert_eq!(text.seek_next_word_boundary(5, Indexing::Char), Some(2));
+    /// assert_eq!(text.seek_next_word_boundary(12, Indexing::Char), None);
+    /// ```
+    fn seek_next_word_boundary(&self, offset: usize, indexing: Indexing) -> Option<usize>;
+
+    /// Seeks back from offset to the previous start or end of a word.  Resulting value is
+    /// counted back from offset.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::{AfterWhitespace, Indexing};
+    ///
+    /// let text = "Grüße, world!";
+    /// assert_eq!(text.seek_previous_word_boundary(10, Indexing::Char), Some(3));
+    /// assert_eq!(text.seek_previous_word_boundary(7, Indexing::Char), Some(2));
+    /// assert_eq!(text.seek_previous_word_boundary(0, Indexing::Char), None);
+    /// ```
+    fn seek_previous_word_boundary(&self, offset: usize, indexing: Indexing) -> Option<usize>;
+}
+impl<T: AsRef<str> + ?Sized> AfterWhitespace for T {
+    fn seek_end_of_whitespace(&self, offset: usize) -> Option<usize> {
+        self.seek_end_of_whitespace_with(offset, Indexing::Byte)
+    }
+
+    fn seek_end_of_whitespace_with(&self, offset: usize, indexing: Indexing) -> Option<usize> {
+        let text = self.as_ref();
+        let rest = &text[byte_offset(text, offset, indexing)?..];
+        let end = rest.find(|c: char| !c.is_whitespace())?;
+        Some(position_count(&rest[..end], indexing))
+    }
+
+    fn seek_start_of_whitespace(&self, offset: usize) -> Option<usize> {
+        self.seek_start_of_whitespace_with(offset, Indexing::Byte)
+    }
+
+    fn seek_start_of_whitespace_with(&self, offset: usize, indexing: Indexing) -> Option<usize> {
+        let text = self.as_ref();
+        let before = &text[..byte_offset(text, offset, indexing)?];
+        let (start, character) = before
+            .char_indices()
+            .rev()
+            .find(|&(_, c)| !c.is_whitespace())?;
+        Some(position_count(
+            &before[start + character.len_utf8()..],
+            indexing,
+        ))
+    }
+
+    fn seek_next_word_boundary(&self, offset: usize, indexing: Indexing) -> Option<usize> {
+        let text = self.as_ref();
+        let start = byte_offset(text, offset, indexing)?;
+        let boundary = word_boundaries(text).find(|&boundary| boundary > start)?;
+        Some(position_count(&text[start..boundary], indexing))
+    }
+
+    fn seek_previous_word_boundary(&self, offset: usize, indexing: Indexing) -> Option<usize> {
+        let text = self.as_ref();
+        let end = byte_offset(text, offset, indexing)?;
+        let boundary = word_boundaries(text)
+            .take_while(|&boundary| boundary < end)
+            .last()?;
+        Some(position_count(&text[boundary..end], indexing))
+    }
+}
+
+/// Where tabs move text to, measured in columns from the start of a line.
+///
+/// # Example
+/// ```
+/// use array_tool::string::TabStops;
+///
+/// let stops = TabStops::at(vec![4, 10]).then_every(8);
+/// assert_eq!(stops.next_stop(0), Some(4));
+/// assert_eq!(stops.next_stop(4), Some(10));
+/// assert_eq!(stops.next_stop(11), Some(18));
+/// assert_eq!(TabStops::at(vec![4]).next_stop(4), None);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TabStops {
+    stops: Vec<usize>,
+    every: Option<usize>,
+}
+impl TabStops {
+    /// Tab stops every `width` columns.  With a width of 0 tabs take up no columns.
+    pub fn every(width: usize) -> TabStops {
+        TabStops {
+            stops: vec![],
+            every: Some(width),
+        }
+    }
+
+    /// Tab stops at the given columns.  A tab past the last stop takes up one column.
+    pub fn at(mut columns: Vec<usize>) -> TabStops {
+        columns.sort_unstable();
+        columns.dedup();
+        TabStops {
+            stops: columns,
+            every: None,
+        }
+    }
+
+    /// Continues the tab stops every `width` columns after the last one.
+    pub fn then_every(mut self, width: usize) -> TabStops {
+        self.every = Some(width);
+        self
+    }
+
+    /// The column a tab at `column` moves to, or `None` when there are no more stops.
+    pub fn next_stop(&self, column: usize) -> Option<usize> {
+        if let Some(&stop) = self.stops.iter().find(|&&stop| stop > column) {
+            return Some(stop);
+        }
+        let last = self.stops.last().cloned().unwrap_or(0);
+        match self.every {
+            Some(0) => Some(column),
+            Some(width) => Some(last + ((column - last) / width + 1) * width),
+            None => None,
+        }
+    }
+
+    // The columns taken up by a tab at `column`.
+    fn tab_width(&self, column: usize) -> usize {
+        self.next_stop(column).map_or(1, |stop| stop - column)
+    }
+}
+impl Default for TabStops {
+    /// Tab stops every 8 columns.
+    fn default() -> TabStops {
+        TabStops::every(8)
+    }
+}
+
+/// ExpandTabs - swap tabs for spaces and back, keeping text lined up.  Columns are
+/// counted as given by `DisplayWidth` and start over after every line break.
+pub trait ExpandTabs {
+    /// Replaces every tab with the spaces up to the next tab stop, which are every
+    /// `tab_width` columns.  A tab width of 0 removes tabs.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::ExpandTabs;
+    ///
+    /// assert_eq!("a\tbc\td\n\tx".expand_tabs(4), "a   bc  d\n    x");
+    /// assert_eq!("漢字\tx".expand_tabs(8), "漢字    x");
+    /// ```
+    fn expand_tabs(&self, tab_width: usize) -> String;
+
+    /// Replaces every tab with the spaces up to the next of the given `TabStops`.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::{ExpandTabs, TabStops};
+    ///
+    /// let stops = TabStops::at(vec![6, 12]);
+    /// assert_eq!("id\tname\tage\tcity".expand_tabs_with(&stops), "id    name  age city");
+    /// ```
+    fn expand_tabs_with(&self, stops: &TabStops) -> String;
+
+    /// Replaces the spaces and tabs indenting each line with as many tabs as fit, every
+    /// `tab_width` columns, followed by spaces.  White space after the indent is kept.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::ExpandTabs;
+    ///
+    /// assert_eq!("          x = 1;  y\n  \t z".unexpand_tabs(4), "\t\t  x = 1;  y\n\t z");
+    /// ```
+    fn unexpand_tabs(&self, tab_width: usize) -> String;
+
+    /// Replaces the spaces and tabs indenting each line like `unexpand_tabs`, with tabs
+    /// up to each of the given `TabStops`.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::{ExpandTabs, TabStops};
+    ///
+    /// let stops = TabStops::at(vec![2, 6]);
+    /// assert_eq!("         x".unexpand_tabs_with(&stops), "\t\t   x");
+    /// ```
+    fn unexpand_tabs_with(&self, stops: &TabStops) -> String;
+}
+
+impl<T: AsRef<str> + ?Sized> ExpandTabs for T {
+    fn expand_tabs(&self, tab_width: usize) -> String {
+        self.expand_tabs_with(&TabStops::every(tab_width))
+    }
+
+    fn expand_tabs_with(&self, stops: &TabStops) -> String {
+        let text = self.as_ref();
+        let mut output = String::with_capacity(text.len());
+        let mut column = 0;
+        for character in text.chars() {
+            match character {
+                '\t' => {
+                    let spaces = stops.tab_width(column);
+                    output.extend((0..spaces).map(|_| ' '));
+                    column += spaces;
+                }
+                '\n' | '\r' => {
+                    output.push(character);
+                    column = 0;
+                }
+                _ => {
+                    output.push(character);
+                    column += character.display_width();
+                }
+            }
+        }
+        output
+    }
+
+    fn unexpand_tabs(&self, tab_width: usize) -> String {
+        self.unexpand_tabs_with(&TabStops::every(tab_width))
+    }
+
+    fn unexpand_tabs_with(&self, stops: &TabStops) -> String {
+        let text = self.as_ref();
+        let mut output = String::with_capacity(text.len());
+        for line in text.split_inclusive('\n') {
+            let indent_len = line.len() - line.trim_start_matches([' ', '\t']).len();
+            let mut end = 0;
+            for character in line[..indent_len].chars() {
+                end += if character == '\t' {
+                    stops.tab_width(end)
+                } else {
+                    1
+                };
+            }
+            let mut column = 0;
+            while let Some(stop) = stops.next_stop(column).filter(|&stop| stop > column) {
+                if stop > end {
+                    break;
+                }
+                output.push('\t');
+                column = stop;
+            }
+            output.extend((column..end).map(|_| ' '));
+            output.push_str(&line[indent_len..]);
+        }
+        output
+    }
+}
+
+/// How `WordWrap` chooses where to break lines.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum WrapAlgorithm {
+    /// Fill each line with as many words as fit before moving on to the next.  Fast, but
+    /// may leave a very ragged right edge.
+    #[default]
+    FirstFit,
+    /// Choose the breaks for each paragraph as a whole, in the style of Knuth and Plass,
+    /// keeping the total of the squared gaps at the end of the lines to a minimum.  The
+    /// last line of a paragraph may be as short as it likes.
+    OptimalFit(Penalties),
+}
+
+/// The costs `WrapAlgorithm::OptimalFit` weighs against the squared gap at the end of
+/// each line.
+///
+/// # Example
+/// ```
+/// use array_tool::string::{Penalties, WordWrap, WrapAlgorithm, WrapOptions};
+///
+/// let options = WrapOptions::new(6).algorithm(WrapAlgorithm::OptimalFit(Penalties::new()));
+/// assert_eq!("aaa bb cc ddddd".word_wrap_with(&options), "aaa\nbb cc\nddddd");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Penalties {
+    line: u64,
+    overflow: u64,
+    hyphen: u64,
+}
+impl Penalties {
+    /// A cost of 1000 per line, 2500 per column past the width and 25 per soft hyphen
+    /// break.
+    pub fn new() -> Penalties {
+        Penalties {
+            line: 1000,
+            overflow: 2500,
+            hyphen: 25,
+        }
+    }
+
+    /// The cost of every line.  Higher costs favor fewer, fuller lines.
+    pub fn line(mut self, line: u64) -> Penalties {
+        self.line = line;
+        self
+    }
+
+    /// The cost of each column a line runs past the width.  Only words too long for any
+    /// line do so, and this decides whether they follow other words or start a line.
+    pub fn overflow(mut self, overflow: u64) -> Penalties {
+        self.overflow = overflow;
+        self
+    }
+
+    /// The cost of breaking a line at a soft hyphen.
+    pub fn hyphen(mut self, hyphen: u64) -> Penalties {
+        self.hyphen = hyphen;
+        self
+    }
+}
+impl Default for Penalties {
+    fn default() -> Penalties {
+        Penalties::new()
+    }
+}
+
+/// Options for `WordWrap::word_wrap_with`.
+///
+/// # Example
+/// ```
+/// use array_tool::string::{WordWrap, WrapOptions};
+///
+/// let options = WrapOptions::new(20).indent("// ").break_words(true);
+/// assert_eq!(
+///   "Checksum 0123456789abcdef0123456789abcdef".word_wrap_with(&options),
+///   "// Checksum 01234567\n// 89abcdef012345678\n// 9abcdef"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WrapOptions {
+    width: usize,
+    initial_indent: String,
+    subsequent_indent: String,
+    break_words: bool,
+    soft_hyphens: bool,
+    trim: bool,
+    tab_stops: TabStops,
+    expand_tabs: bool,
+    algorithm: WrapAlgorithm,
+}
+impl WrapOptions {
+    /// Options for wrapping to `width` columns, counting the indent.  Long words are kept
+    /// whole, soft hyphens are break opportunities, trailing white space is trimmed and
+    /// tab stops are every 8 columns.  Lines are filled first fit.
+    pub fn new(width: usize) -> WrapOptions {
+        WrapOptions {
+            width,
+            initial_indent: String::new(),
+            subsequent_indent: String::new(),
+            break_words: false,
+            soft_hyphens: true,
+            trim: true,
+            tab_stops: TabStops::default(),
+            expand_tabs: false,
+            algorithm: WrapAlgorithm::FirstFit,
+        }
+    }
+
+    /// The number of columns to wrap to, counting the indent.
+    pub fn width(mut self, width: usize) -> WrapOptions {
+        self.width = width;
+        self
+    }
+
+    /// Text to start the first line with, such as a bullet.
+    pub fn initial_indent<S: Into<String>>(mut self, indent: S) -> WrapOptions {
+        self.initial_indent = indent.into();
+        self
+    }
+
+    /// Text to start every line after the first with.
+    pub fn subsequent_indent<S: Into<String>>(mut self, indent: S) -> WrapOptions {
+        self.subsequent_indent = indent.into();
+        self
+    }
+
+    /// Text to start every line with, such as `"// "` for code comments.
+    pub fn indent<S: Into<String>>(self, indent: S) -> WrapOptions {
+        let indent = indent.into();
+        self.initial_indent(indent.clone())
+            .subsequent_indent(indent)
+    }
+
+    /// Whether words longer than the width are broken across lines.  Otherwise they
+    /// overflow the width on a line of their own.
+    pub fn break_words(mut self, break_words: bool) -> WrapOptions {
+        self.break_words = break_words;
+        self
+    }
+
+    /// Whether lines may break at a soft hyphen (U+00AD), which is then shown as a `-`.
+    /// Soft hyphens which don't end a line are removed.
+    pub fn soft_hyphens(mut self, soft_hyphens: bool) -> WrapOptions {
+        self.soft_hyphens = soft_hyphens;
+        self
+    }
+
+    /// Whether white space at the end of each line is removed.
+    pub fn trim(mut self, trim: bool) -> WrapOptions {
+        self.trim = trim;
+        self
+    }
+
+    /// The number of columns between tab stops.  Tabs are expanded to spaces up to the
+    /// next tab stop, counting the indent.
+    pub fn tab_width(self, tab_width: usize) -> WrapOptions {
+        self.tab_stops(TabStops::every(tab_width))
+    }
+
+    /// Where tabs expand to, like `tab_width` with a list of stops.
+    pub fn tab_stops(mut self, tab_stops: TabStops) -> WrapOptions {
+        self.tab_stops = tab_stops;
+        self
+    }
+
+    /// Whether tabs are expanded against the columns of the original text before it is
+    /// wrapped, keeping tab-aligned text lined up under an indent.  Otherwise tabs are
+    /// break opportunities which expand from the column they end up at.  `wrap_lines`
+    /// yields slices of the original text, so it always does the latter.
+    pub fn expand_tabs(mut self, expand_tabs: bool) -> WrapOptions {
+        self.expand_tabs = expand_tabs;
+        self
+    }
+
+    /// How to choose where lines break.
+    pub fn algorithm(mut self, algorithm: WrapAlgorithm) -> WrapOptions {
+        self.algorithm = algorithm;
+        self
+    }
+
+    fn indent_for(&self, line: usize) -> &str {
+        if line == 0 {
+            &self.initial_indent
+        } else {
+            &self.subsequent_indent
+        }
+    }
+
+    fn is_glue(&self, character: char) -> bool {
+        character == ' ' || character == '\t' || (self.soft_hyphens && character == SOFT_HYPHEN)
+    }
+}
+
+const SOFT_HYPHEN: char = '\u{AD}';
+const ZERO_WIDTH_SPACE: char = '\u{200B}';
+
+/// The words of `text` between its spaces and tabs, so that non-breaking spaces stay
+/// inside words.
+fn glue_separated(text: &str) -> impl Iterator<Item = &str> {
+    text.split([' ', '\t']).filter(|word| !word.is_empty())
+}
+
+/// Whether a line may break between two characters with no glue between them.  That is
+/// after a zero width space or a wide space such as the ideographic space, and before or
+/// after the characters of Chinese, Japanese and Korean text unless that would start a
+/// line with closing punctuation or end one with opening punctuation.  Non-breaking
+/// spaces never allow a break.
+fn breaks_between(before: char, after: char) -> bool {
+    if before.is_ascii() && after.is_ascii() {
+        return false;
+    }
+    if after.is_whitespace()
+        || after.display_width() == 0
+        || tables::in_table(before, tables::NON_BREAKING)
+        || tables::in_table(after, tables::NON_BREAKING)
+    {
+        return false;
+    }
+    if before == ZERO_WIDTH_SPACE || before.is_whitespace() {
+        // Wrapping already breaks at ASCII white space.
+        return !before.is_ascii();
+    }
+    (tables::in_table(before, tables::IDEOGRAPHIC) || tables::in_table(after, tables::IDEOGRAPHIC))
+        && !tables::in_table(before, tables::NO_BREAK_AFTER)
+        && !tables::in_table(after, tables::NO_BREAK_BEFORE)
+}
+
+/// The text to wrap, with its tabs expanded first if the options ask for it.
+fn tabs_expanded<'a>(text: &'a str, options: &WrapOptions) -> Cow<'a, str> {
+    if options.expand_tabs && text.contains('\t') {
+        Cow::Owned(text.expand_tabs_with(&options.tab_stops))
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+/// The columns taken up by white space and soft hyphens starting at column `col`.
+fn glue_width(glue: &str, mut col: usize, options: &WrapOptions) -> usize {
+    let start = col;
+    for character in glue.chars() {
+        col += match character {
+            '\t' => options.tab_stops.tab_width(col),
+            SOFT_HYPHEN if options.soft_hyphens => 0,
+            _ => character.display_width(),
+        };
+    }
+    col - start
+}
+
+/// The length in bytes of the longest start of `text` which fits in `columns`, without
+/// separating a character from the zero width characters which follow it.
+fn prefix_len(text: &str, columns: usize) -> usize {
+    let mut used = 0;
+    for (index, character) in text.char_indices() {
+        let width = character.display_width();
+        if width > 0 && used + width > columns {
+            return index;
+        }
+        used += width;
+    }
+    text.len()
+}
+
+/// A word with the white space before and after it, as byte offsets into the source.
+/// Only the first fragment of a line has white space before its word.
+#[derive(Clone, Copy, Debug)]
+struct Fragment {
+    start: usize,
+    word_start: usize,
+    word_end: usize,
+    end: usize,
+    width: usize,
+    hyphen: bool,
+}
+
+/// Splits the source line `text[start..end]` into fragments at its break opportunities.
+fn fragments(text: &str, start: usize, end: usize, options: &WrapOptions) -> Vec<Fragment> {
+    let line = &text[start..end];
+    let lead = line.len() - line.trim_start_matches([' ', '\t']).len();
+    let mut fragments = vec![];
+    let mut fragment_start = start;
+    let mut index = start + lead;
+    loop {
+        let word_start = index;
+        index += text[index..end]
+            .find(|c| options.is_glue(c))
+            .unwrap_or(end - index);
+        let word_end = index;
+        // Ideographic text may also break between characters, with no glue.
+        let mut piece_start = word_start;
+        let mut previous = None;
+        for (offset, character) in text[word_start..word_end].char_indices() {
+            let split = word_start + offset;
+            if previous.is_some_and(|before| breaks_between(before, character)) {
+                fragments.push(Fragment {
+                    start: fragment_start,
+                    word_start: piece_start,
+                    word_end: split,
+                    end: split,
+                    width: text[piece_start..split].display_width(),
+                    hyphen: false,
+                });
+                fragment_start = split;
+                piece_start = split;
+            }
+            previous = Some(character);
+        }
+        index += text[index..end]
+            .find(|c| !options.is_glue(c))
+            .unwrap_or(end - index);
+        let glue = &text[word_end..index];
+        fragments.push(Fragment {
+            start: fragment_start,
+            word_start: piece_start,
+            word_end,
+            end: index,
+            width: text[piece_start..word_end].display_width(),
+            hyphen: !glue.is_empty() && glue.chars().all(|c| c == SOFT_HYPHEN),
+        });
+        if index == end {
+            return fragments;
+        }
+        fragment_start = index;
+    }
+}
+
+/// One wrapped line as byte offsets into the source.  `end` excludes the white space at
+/// the break, which runs on to `glue_end`.
+#[derive(Clone, Copy, Debug)]
+struct WrapLine {
+    start: usize,
+    end: usize,
+    glue_end: usize,
+    hyphen: bool,
+}
+
+/// The byte ranges of every source line, without their line endings.
+fn source_lines(text: &str) -> Vec<(usize, usize)> {
+    let mut lines = vec![];
+    let mut start = 0;
+    for (index, _) in text.match_indices('\n') {
+        lines.push((start, index));
+        start = index + 1;
+    }
+    lines.push((start, text.len()));
+    lines
+}
+
+/// Fills each line with as many words as fit before moving on to the next.
+fn wrap_greedy(text: &str, options: &WrapOptions) -> Vec<WrapLine> {
+    let width = options.width;
+    let subsequent = options.subsequent_indent.display_width();
+    let mut lines = vec![];
+    for (source_start, source_end) in source_lines(text) {
+        let mut line_start = source_start;
+        let mut col = options.indent_for(lines.len()).display_width();
+        // (word_end, glue_end, hyphen) of the last word on the line
+        let mut placed: Option<(usize, usize, bool)> = None;
+        for fragment in fragments(text, source_start, source_end, options) {
+            let lead = glue_width(&text[fragment.start..fragment.word_start], col, options);
+            let penalty = if fragment.hyphen { 1 } else { 0 };
+            // Too long to fit even at the start of a line of its own
+            let too_long = options.break_words
+                && match placed {
+                    Some(_) => subsequent + fragment.width > width,
+                    None => col + lead + fragment.width > width,
+                };
+            if let Some((word_end, glue_end, hyphen)) = placed {
+                let glue = glue_width(&text[word_end..glue_end], col, options);
+                let fits = col + glue + fragment.width + penalty <= width;
+                if fits || (too_long && col + glue < width) {
+                    col += glue;
+                } else {
+                    lines.push(WrapLine {
+                        start: line_start,
+                        end: word_end,
+                        glue_end,
+                        hyphen,
+                    });
+                    line_start = fragment.word_start;
+                    col = subsequent;
+                    placed = None;
+                }
+            } else {
+                col += lead;
+            }
+
+            let mut word_start = fragment.word_start;
+            if too_long {
+                loop {
+                    let rest = &text[word_start..fragment.word_end];
+                    let mut split = word_start + prefix_len(rest, width.saturating_sub(col));
+                    if split == fragment.word_end {
+                        break;
+                    }
+                    if split == word_start {
+                        if let Some((word_end, glue_end, hyphen)) = placed {
+                            lines.push(WrapLine {
+                                start: line_start,
+                                end: word_end,
+                                glue_end,
+                                hyphen,
+                            });
+                            line_start = word_start;
+                            col = subsequent;
+                            placed = None;
+                            continue;
+                        }
+                        // Nothing fits on an empty line, so overflow by one character.
+                        let first = rest.chars().next().map_or(0, char::len_utf8);
+                        split = word_start + first + prefix_len(&rest[first..], 0);
+                        if split == fragment.word_end {
+                            break;
+                        }
+                    }
+                    lines.push(WrapLine {
+                        start: line_start,
+                        end: split,
+                        glue_end: split,
+                        hyphen: false,
+                    });
+                    line_start = split;
+                    word_start = split;
+                    col = subsequent;
+                    placed = None;
+                }
+            }
+            col += text[word_start..fragment.word_end].display_width();
+            placed = Some((fragment.word_end, fragment.end, fragment.hyphen));
+        }
+        let (word_end, glue_end, _) = placed.unwrap_or((line_start, line_start, false));
+        lines.push(WrapLine {
+            start: line_start,
+            end: word_end,
+            glue_end,
+            hyphen: false,
+        });
+    }
+    lines
+}
+
+/// Splits the words of `fragments` which are too wide for a line starting at `col`
+/// into pieces which fit, with no glue between them.
+fn split_long_words(
+    text: &str,
+    fragments: Vec<Fragment>,
+    col: usize,
+    options: &WrapOptions,
+) -> Vec<Fragment> {
+    let available = options.width.saturating_sub(col);
+    let mut pieces = Vec::with_capacity(fragments.len());
+    for fragment in fragments {
+        let mut start = fragment.start;
+        let mut word_start = fragment.word_start;
+        loop {
+            let rest = &text[word_start..fragment.word_end];
+            let mut split = word_start + prefix_len(rest, available);
+            if split == word_start {
+                let first = rest.chars().next().map_or(0, char::len_utf8);
+                split = word_start + first + prefix_len(&rest[first..], 0);
+            }
+            if split == fragment.word_end {
+                pieces.push(Fragment {
+                    start,
+                    word_start,
+                    width: rest.display_width(),
+                    ..fragment
+                });
+                break;
+            }
+            pieces.push(Fragment {
+                start,
+                word_start,
+                word_end: split,
+                end: split,
+                width: text[word_start..split].display_width(),
+                hyphen: false,
+            });
+            start = split;
+            word_start = split;
+        }
+    }
+    pieces
+}
+
+/// Chooses the breaks for each source line at once, with the lowest total cost.
+fn wrap_optimal(text: &str, options: &WrapOptions, penalties: &Penalties) -> Vec<WrapLine> {
+    let width = options.width;
+    let subsequent = options.subsequent_indent.display_width();
+    let mut lines = vec![];
+    for (source_start, source_end) in source_lines(text) {
+        let initial = options.indent_for(lines.len()).display_width();
+        let mut fragments = fragments(text, source_start, source_end, options);
+        if options.break_words {
+            fragments = split_long_words(text, fragments, initial.max(subsequent), options);
+        }
+
+        // cost[i] is the least cost of the lines holding the first i fragments, and
+        // the last of those lines starts with fragment from[i].
+        let count = fragments.len();
+        let mut cost = vec![u64::MAX; count + 1];
+        let mut from = vec![0; count + 1];
+        cost[0] = 0;
+        for first in 0..count {
+            let mut col = if first == 0 {
+                let lead = &text[fragments[0].start..fragments[0].word_start];
+                initial + glue_width(lead, initial, options)
+            } else {
+                subsequent
+            };
+            for last in first..count {
+                if last > first {
+                    let glue = &text[fragments[last - 1].word_end..fragments[last - 1].end];
+                    col += glue_width(glue, col, options);
+                }
+                col += fragments[last].width;
+                let fits = col <= width;
+                // Only a word too long for any line may run past the width after others.
+                if last > first && !fits && subsequent + fragments[last].width <= width {
+                    break;
+                }
+                let final_line = last + 1 == count;
+                let hyphen = !final_line && fragments[last].hyphen;
+                let line_width = col + if hyphen { 1 } else { 0 };
+                if last > first && fits && line_width > width {
+                    continue;
+                }
+                let mut line_cost = penalties.line;
+                line_cost = line_cost.saturating_add(
+                    penalties
+                        .overflow
+                        .saturating_mul(line_width.saturating_sub(width) as u64),
+                );
+                if !final_line {
+                    let slack = width.saturating_sub(line_width) as u64;
+                    line_cost = line_cost.saturating_add(slack.saturating_mul(slack));
+                }
+                if hyphen {
+                    line_cost = line_cost.saturating_add(penalties.hyphen);
+                }
+                let total = cost[first].saturating_add(line_cost);
+                if total < cost[last + 1] {
+                    cost[last + 1] = total;
+                    from[last + 1] = first;
+                }
+                if !fits {
+                    break;
+                }
+            }
+        }
+
+        let mut breaks = vec![count];
+        while let Some(&end) = breaks.last().filter(|&&end| end > 0) {
+            breaks.push(from[end]);
+        }
+        breaks.reverse();
+        for pair in breaks.windows(2) {
+            let (first, last) = (pair[0], pair[1] - 1);
+            lines.push(WrapLine {
+                start: if first == 0 {
+                    source_start
+                } else {
+                    fragments[first].word_start
+                },
+                end: fragments[last].word_end,
+                glue_end: fragments[last].end,
+                hyphen: last + 1 < count && fragments[last].hyphen,
+            });
+        }
+    }
+    lines
+}
+
+/// Breaks `text` into lines with the algorithm chosen in `options`.
+fn wrap(text: &str, options: &WrapOptions) -> Vec<WrapLine> {
+    match options.algorithm {
+        WrapAlgorithm::FirstFit => wrap_greedy(text, options),
+        WrapAlgorithm::OptimalFit(ref penalties) => wrap_optimal(text, options, penalties),
+    }
+}
+
+/// Renders wrapped lines with their indents, expanded tabs and hyphens.
+fn render_lines(text: &str, lines: &[WrapLine], options: &WrapOptions) -> String {
+    let mut output = String::with_capacity(text.len() + lines.len());
+    for (index, line) in lines.iter().enumerate() {
+        if index > 0 {
+            output.push('\n');
+        }
+        let line_start = output.len();
+        let indent = options.indent_for(index);
+        output.push_str(indent);
+        let mut col = indent.display_width();
+        let end = if options.trim {
+            line.end
+        } else {
+            line.glue_end
+        };
+        for character in text[line.start..end].chars() {
+            match character {
+                '\t' => {
+                    let spaces = glue_width("\t", col, options);
+                    for _ in 0..spaces {
+                        output.push(' ');
+                    }
+                    col += spaces;
+                }
+                SOFT_HYPHEN if options.soft_hyphens => {}
+                _ => {
+                    output.push(character);
+                    col += character.display_width();
+                }
+            }
+        }
+        if line.hyphen {
+            output.push('-');
+        }
+        if options.trim {
+            let trimmed = line_start + output[line_start..].trim_end().len();
+            output.truncate(trimmed);
+        }
+    }
+    output
+}
+
+/// Wrapped lines as slices of the source with their byte ranges.  Created by
+/// `WordWrap::wrap_lines`.
+#[derive(Clone, Debug)]
+pub struct WrapLines<'a> {
+    source: &'a str,
+    lines: std::vec::IntoIter<WrapLine>,
+    trim: bool,
+}
+impl<'a> WrapLines<'a> {
+    fn range(&self, line: WrapLine) -> (Range<usize>, &'a str) {
+        let mut end = line.glue_end;
+        if self.trim {
+            end = line.start + self.source[line.start..line.end].trim_end().len();
+        }
+        (line.start..end, &self.source[line.start..end])
+    }
+}
+impl<'a> Iterator for WrapLines<'a> {
+    type Item = (Range<usize>, &'a str);
+
+    fn next(&mut self) -> Option<(Range<usize>, &'a str)> {
+        self.lines.next().map(|line| self.range(line))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.lines.size_hint()
+    }
+}
+impl<'a> DoubleEndedIterator for WrapLines<'a> {
+    fn next_back(&mut self) -> Option<(Range<usize>, &'a str)> {
+        self.lines.next_back().map(|line| self.range(line))
+    }
+}
+impl<'a> ExactSizeIterator for WrapLines<'a> {}
+impl<'a> FusedIterator for WrapLines<'a> {}
+
+/// Word wrapping.  Widths are measured in terminal columns, as given by `DisplayWidth`.
+pub trait WordWrap {
+    ///  White space is treated as valid content and new lines will only be swapped in for
+    ///  the last white space character at the end of the given width.  White space may reach beyond
+    ///  the width you've provided.  You will need to trim end of lines in your own output (e.g.
+    ///  splitting string at each new line and printing the line with trim_right).  Or just trust
+    ///  that lines that are beyond the width are just white space and only print the width -
+    ///  ignoring tailing white space.  `word_wrap_with` can trim it for you.
+    ///
+    ///  Chinese, Japanese and Korean text may also break between characters, where a new
+    ///  line is inserted.  Non-breaking spaces never break.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::WordWrap;
+    ///
+    /// "asd asdf asd".word_wrap(8);
+    /// ```
+    ///
+    /// # Output
+    /// ```text
+    /// "asd asdf\nasd"
+    /// ```
+    fn word_wrap(&self, width: usize) -> String;
+
+    /// Wraps text as configured by `WrapOptions`.  Lines are broken at spaces and tabs, at
+    /// soft hyphens which then show as a `-`, and between ideographic characters as
+    /// `word_wrap` does.  Each new line in the text is kept and starts a new line in the
+    /// output.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::{WordWrap, WrapOptions};
+    ///
+    /// let options = WrapOptions::new(16).initial_indent("- ").subsequent_indent("  ");
+    /// "Wrap long bullet points neatly".word_wrap_with(&options);
+    /// ```
+    ///
+    /// # Output
+    /// ```text
+    /// "- Wrap long\n  bullet points\n  neatly"
+    /// ```
+    fn word_wrap_with(&self, options: &WrapOptions) -> String;
+
+    /// Wraps text as `word_wrap_with` does, but yields each line as a slice of the
+    /// original text along with its byte range.  The slices don't include indents, tabs
+    /// are left unexpanded, and soft hyphens are neither removed nor shown.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::{WordWrap, WrapOptions};
+    ///
+    /// "asd asdf asd".wrap_lines(&WrapOptions::new(8)).collect::<Vec<_>>();
+    /// ```
+    ///
+    /// # Output
+    /// ```text
+    /// vec![(0..8, "asd asdf"), (9..12, "asd")]
+    /// ```
+    fn wrap_lines<'a>(&'a self, options: &WrapOptions) -> WrapLines<'a>;
+}
+/// The columns `character` takes in the `word_wrap` window.  Every ASCII character, new
+/// lines and tabs included, takes one as it did when the window was counted in bytes, so
+/// the window ends at a new line rather than running on past the line break.
+fn window_width(character: char) -> usize {
+    if character.is_ascii() {
+        1
+    } else {
+        character.display_width()
+    }
+}
+
+// The wrapping window is measured in columns.  Lines are split by swapping a space for a
+// new line, or by inserting a new line between ideographic characters.
+impl<T: AsRef<str> + ?Sized> WordWrap for T {
+    fn word_wrap(&self, width: usize) -> String {
+        let text = self.as_ref();
+        let bytes = text.as_bytes();
+        let chunk = width + 1;
+        let mut output = bytes.to_vec();
+        let mut inserts = vec![];
+
+        // The window is `text[offset..end]`, the most text from `offset` which fits in
+        // `chunk` columns, ending early after a new line.  Columns are counted from the
+        // start of the text, so the window is `column - start` wide.  Neither end ever
+        // moves backwards, so every character enters and leaves the window once.
+        let mut offset = 0;
+        let mut end = 0;
+        let mut start = 0;
+        let mut column = 0;
+        let mut newline = None;
+        let mut space = None;
+        // Where the line may break between ideographic characters, and at which column.
+        let mut breaks: VecDeque<(usize, usize)> = VecDeque::new();
+        loop {
+            while newline.is_none() {
+                let character = match text[end..].chars().next() {
+                    Some(character) => character,
+                    None => break,
+                };
+                if end > offset && breaks.back().is_none_or(|&(x, _)| x < end) {
+                    if let Some(before) = text[..end].chars().next_back() {
+                        if breaks_between(before, character) {
+                            breaks.push_back((end, column));
+                        }
+                    }
+                }
+                let columns = window_width(character);
+                if column - start + columns > chunk {
+                    break;
+                }
+                match character {
+                    '\n' => newline = Some(end),
+                    ' ' => space = Some(end),
+                    _ => {}
+                }
+                column += columns;
+                end += character.len_utf8();
+            }
+            // The rest of the text fits, so there is nothing more to wrap.
+            if end == text.len() {
+                break;
+            }
+
+            let split = breaks
+                .iter()
+                .rev()
+                .find(|&&(_, at)| at - start <= width)
+                .map(|&(x, _)| x);
+            let next = if let Some(x) = newline {
+                x + 1
+            } else if let Some(x) = split.filter(|&x| space.is_none_or(|space| x > space)) {
+                inserts.push(x);
+                x
+            } else if let Some(x) = space {
+                // end of white space, which may continue beyond the window
+                let eows = x + bytes[x..].iter().take_while(|&&b| b == b' ').count() - 1;
+                if let Some(&b) = bytes.get(eows + 1) {
+                    if b != b'\n' {
+                        output[eows] = b'\n';
+                    }
+                }
+                eows + 1
+            } else {
+                // String may continue; slide on until a space or new line fits
+                offset + text[offset..].chars().next().map_or(1, char::len_utf8)
+            };
+
+            if next >= end {
+                column += text[end..next].chars().map(window_width).sum::<usize>();
+                start = column;
+                offset = next;
+                end = next;
+                newline = None;
+                space = None;
+                breaks.clear();
+            } else {
+                start += text[offset..next].chars().map(window_width).sum::<usize>();
+                offset = next;
+                space = space.filter(|&x| x >= offset);
+                while let Some(&(x, _)) = breaks.front() {
+                    if x > offset {
+                        break;
+                    }
+                    breaks.pop_front();
+                }
+            }
+        }
+
+        if !inserts.is_empty() {
+            let mut spliced = Vec::with_capacity(output.len() + inserts.len());
+            let mut copied = 0;
+            for index in inserts {
+                spliced.extend_from_slice(&output[copied..index]);
+                spliced.push(b'\n');
+                copied = index;
+            }
+            spliced.extend_from_slice(&output[copied..]);
+            output = spliced;
+        }
+        String::from_utf8(output).expect("word_wrap failed to render String!")
+    }
+
+    fn word_wrap_with(&self, options: &WrapOptions) -> String {
+        let text = tabs_expanded(self.as_ref(), options);
+        render_lines(&text, &wrap(&text, options), options)
+    }
+
+    fn wrap_lines<'a>(&'a self, options: &WrapOptions) -> WrapLines<'a> {
+        let text = self.as_ref();
+        WrapLines {
+            source: text,
+            lines: wrap(text, options).into_iter(),
+            trim: options.trim,
+        }
+    }
+}
+
+/// The spaces and tabs at the start of `line`.
+fn leading_whitespace(line: &str) -> &str {
+    &line[..line.len() - line.trim_start_matches([' ', '\t']).len()]
+}
+
+/// The `\n` or `\r\n` which ends `line`, if any.
+fn line_ending(line: &str) -> &str {
+    let content = line.trim_end_matches('\n').trim_end_matches('\r');
+    &line[content.len()..]
+}
+
+/// Indent - add and remove indentation for blocks of text, such as generated code.  Line
+/// endings are kept as they are, `\n` or `\r\n`.
+pub trait Indent {
+    /// Removes the leading white space which every line has in common.  Lines holding
+    /// nothing but white space don't count towards it and are emptied.  Tabs and spaces
+    /// are never taken as the same, so a line indented by a tab and one indented by spaces
+    /// only lose the white space they begin with in common.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::Indent;
+    ///
+    /// assert_eq!("    fn a() {\n        b();\n    }\n".dedent(), "fn a() {\n    b();\n}\n");
+    /// assert_eq!("  \tx\n  \n  \t\ty".dedent(), "x\n\n\ty");
+    /// assert_eq!("  a\n\tb".dedent(), "  a\n\tb");
+    /// ```
+    fn dedent(&self) -> String;
+
+    /// Adds `prefix` to the start of every line which is not blank.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::Indent;
+    ///
+    /// assert_eq!("a\n\n  b\n".indent("> "), "> a\n\n>   b\n");
+    /// ```
+    fn indent(&self, prefix: &str) -> String;
+
+    /// Adds `prefix` to the start of every line for which `predicate` is true.  The
+    /// predicate is given each line without its line ending.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::Indent;
+    ///
+    /// assert_eq!("a\n\nb".indent_if("# ", |_| true), "# a\n# \n# b");
+    /// assert_eq!(
+    ///   "let x = 1;\nprintln!(x);".indent_if("// ", |line| line.starts_with("println")),
+    ///   "let x = 1;\n// println!(x);"
+    /// );
+    /// ```
+    fn indent_if<F: Fn(&str) -> bool>(&self, prefix: &str, predicate: F) -> String;
+
+    /// Replaces the common leading white space, as removed by `dedent`, with `prefix`.
+    /// Blank lines are kept, but emptied.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::string::Indent;
+    ///
+    /// assert_eq!(
+    ///   "\t\tif x {\n\t\t\ty();\n   \n\t\t}".reindent("    "),
+    ///   "    if x {\n    \ty();\n\n    }"
+    /// );
+    /// ```
+    fn reindent(&self, prefix: &str) -> String;
+}
+
+impl<T: AsRef<str> + ?Sized> Indent for T {
+    fn dedent(&self) -> String {
+        let text = self.as_ref();
+        let mut margin: Option<&str> = None;
+        for line in text.lines().filter(|line| !line.trim().is_empty()) {
+            let indent = leading_whitespace(line);
+            margin = Some(match margin {
+                Some(margin) => {
+                    let common = margin
+                        .bytes()
+                        .zip(indent.bytes())
+                        .take_while(|(a, b)| a == b)
+                        .count();
+                    &margin[..common]
+                }
+                None => indent,
+            });
+        }
+        let margin = margin.map_or(0, str::len);
+
+        let mut output = String::with_capacity(text.len());
+        for line in text.split_inclusive('\n') {
+            if line.trim().is_empty() {
+                output.push_str(line_ending(line));
+            } else {
+                output.push_str(&line[margin..]);
+            }
+        }
+        output
+    }
+
+    fn indent(&self, prefix: &str) -> String {
+        self.indent_if(prefix, |line| !line.trim().is_empty())
+    }
+
+    fn indent_if<F: Fn(&str) -> bool>(&self, prefix: &str, predicate: F) -> String {
+        let text = self.as_ref();
+        let mut output = String::with_capacity(text.len());
+        for line in text.split_inclusive('\n') {
+            if predicate(&line[..line.len() - line_ending(line).len()]) {
+                output.push_str(prefix);
+            }
+            output.push_str(line);
+        }
+        output
+    }
+
+    fn reindent(&self, prefix: &str) -> String {
+        self.dedent().indent(prefix)
     }
 }
@@ -0,0 +1,1900 @@
+// Copyright 2015-2017 Daniel P. Clark & array_tool Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+// Unicode character property tables.  Each table is a sorted list of inclusive code
+// point ranges, generated from the Unicode Character Database.
+
+use std::cmp::Ordering;
+
+/// The version of Unicode the tables are generated from.
+pub const UNICODE_VERSION: (u8, u8, u8) = (15, 1, 0);
+
+/// Finds whether `c` falls within one of the sorted, inclusive ranges of `table`.
+pub fn in_table(c: char, table: &[(u32, u32)]) -> bool {
+    let c = c as u32;
+    table
+        .binary_search_by(|&(first, last)| {
+            if c < first {
+                Ordering::Greater
+            } else if c > last {
+                Ordering::Less
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+/// Code points which take up no columns: combining marks, format characters, Hangul
+/// medial vowels and final consonants, variation selectors and the like.  Control
+/// characters are not listed.
+#[rustfmt::skip]
+pub const ZERO_WIDTH: &[(u32, u32)] = &[
+    (0x300, 0x36F), (0x483, 0x489), (0x591, 0x5BD), (0x5BF, 0x5BF), (0x5C1, 0x5C2), (0x5C4, 0x5C5),
+    (0x5C7, 0x5C7), (0x600, 0x605), (0x610, 0x61A), (0x61C, 0x61C), (0x64B, 0x65F), (0x670, 0x670),
+    (0x6D6, 0x6DD), (0x6DF, 0x6E4), (0x6E7, 0x6E8), (0x6EA, 0x6ED), (0x70F, 0x70F), (0x711, 0x711),
+    (0x730, 0x74A), (0x7A6, 0x7B0), (0x7EB, 0x7F3), (0x7FD, 0x7FD), (0x816, 0x819), (0x81B, 0x823),
+    (0x825, 0x827), (0x829, 0x82D), (0x859, 0x85B), (0x890, 0x891), (0x898, 0x89F), (0x8CA, 0x902),
+    (0x93A, 0x93A), (0x93C, 0x93C), (0x941, 0x948), (0x94D, 0x94D), (0x951, 0x957), (0x962, 0x963),
+    (0x981, 0x981), (0x9BC, 0x9BC), (0x9C1, 0x9C4), (0x9CD, 0x9CD), (0x9E2, 0x9E3), (0x9FE, 0x9FE),
+    (0xA01, 0xA02), (0xA3C, 0xA3C), (0xA41, 0xA42), (0xA47, 0xA48), (0xA4B, 0xA4D), (0xA51, 0xA51),
+    (0xA70, 0xA71), (0xA75, 0xA75), (0xA81, 0xA82), (0xABC, 0xABC), (0xAC1, 0xAC5), (0xAC7, 0xAC8),
+    (0xACD, 0xACD), (0xAE2, 0xAE3), (0xAFA, 0xAFF), (0xB01, 0xB01), (0xB3C, 0xB3C), (0xB3F, 0xB3F),
+    (0xB41, 0xB44), (0xB4D, 0xB4D), (0xB55, 0xB56), (0xB62, 0xB63), (0xB82, 0xB82), (0xBC0, 0xBC0),
+    (0xBCD, 0xBCD), (0xC00, 0xC00), (0xC04, 0xC04), (0xC3C, 0xC3C), (0xC3E, 0xC40), (0xC46, 0xC48),
+    (0xC4A, 0xC4D), (0xC55, 0xC56), (0xC62, 0xC63), (0xC81, 0xC81), (0xCBC, 0xCBC), (0xCBF, 0xCBF),
+    (0xCC6, 0xCC6), (0xCCC, 0xCCD), (0xCE2, 0xCE3), (0xD00, 0xD01), (0xD3B, 0xD3C), (0xD41, 0xD44),
+    (0xD4D, 0xD4D), (0xD62, 0xD63), (0xD81, 0xD81), (0xDCA, 0xDCA), (0xDD2, 0xDD4), (0xDD6, 0xDD6),
+    (0xE31, 0xE31), (0xE34, 0xE3A), (0xE47, 0xE4E), (0xEB1, 0xEB1), (0xEB4, 0xEBC), (0xEC8, 0xECE),
+    (0xF18, 0xF19), (0xF35, 0xF35), (0xF37, 0xF37), (0xF39, 0xF39), (0xF71, 0xF7E), (0xF80, 0xF84),
+    (0xF86, 0xF87), (0xF8D, 0xF97), (0xF99, 0xFBC), (0xFC6, 0xFC6), (0x102D, 0x1030),
+    (0x1032, 0x1037), (0x1039, 0x103A), (0x103D, 0x103E), (0x1058, 0x1059), (0x105E, 0x1060),
+    (0x1071, 0x1074), (0x1082, 0x1082), (0x1085, 0x1086), (0x108D, 0x108D), (0x109D, 0x109D),
+    (0x1160, 0x11FF), (0x135D, 0x135F), (0x1712, 0x1714), (0x1732, 0x1733), (0x1752, 0x1753),
+    (0x1772, 0x1773), (0x17B4, 0x17B5), (0x17B7, 0x17BD), (0x17C6, 0x17C6), (0x17C9, 0x17D3),
+    (0x17DD, 0x17DD), (0x180B, 0x180F), (0x1885, 0x1886), (0x18A9, 0x18A9), (0x1920, 0x1922),
+    (0x1927, 0x1928), (0x1932, 0x1932), (0x1939, 0x193B), (0x1A17, 0x1A18), (0x1A1B, 0x1A1B),
+    (0x1A56, 0x1A56), (0x1A58, 0x1A5E), (0x1A60, 0x1A60), (0x1A62, 0x1A62), (0x1A65, 0x1A6C),
+    (0x1A73, 0x1A7C), (0x1A7F, 0x1A7F), (0x1AB0, 0x1ACE), (0x1B00, 0x1B03), (0x1B34, 0x1B34),
+    (0x1B36, 0x1B3A), (0x1B3C, 0x1B3C), (0x1B42, 0x1B42), (0x1B6B, 0x1B73), (0x1B80, 0x1B81),
+    (0x1BA2, 0x1BA5), (0x1BA8, 0x1BA9), (0x1BAB, 0x1BAD), (0x1BE6, 0x1BE6), (0x1BE8, 0x1BE9),
+    (0x1BED, 0x1BED), (0x1BEF, 0x1BF1), (0x1C2C, 0x1C33), (0x1C36, 0x1C37), (0x1CD0, 0x1CD2),
+    (0x1CD4, 0x1CE0), (0x1CE2, 0x1CE8), (0x1CED, 0x1CED), (0x1CF4, 0x1CF4), (0x1CF8, 0x1CF9),
+    (0x1DC0, 0x1DFF), (0x200B, 0x200F), (0x202A, 0x202E), (0x2060, 0x2064), (0x2066, 0x206F),
+    (0x20D0, 0x20F0), (0x2CEF, 0x2CF1), (0x2D7F, 0x2D7F), (0x2DE0, 0x2DFF), (0x302A, 0x302D),
+    (0x3099, 0x309A), (0xA66F, 0xA672), (0xA674, 0xA67D), (0xA69E, 0xA69F), (0xA6F0, 0xA6F1),
+    (0xA802, 0xA802), (0xA806, 0xA806), (0xA80B, 0xA80B), (0xA825, 0xA826), (0xA82C, 0xA82C),
+    (0xA8C4, 0xA8C5), (0xA8E0, 0xA8F1), (0xA8FF, 0xA8FF), (0xA926, 0xA92D), (0xA947, 0xA951),
+    (0xA980, 0xA982), (0xA9B3, 0xA9B3), (0xA9B6, 0xA9B9), (0xA9BC, 0xA9BD), (0xA9E5, 0xA9E5),
+    (0xAA29, 0xAA2E), (0xAA31, 0xAA32), (0xAA35, 0xAA36), (0xAA43, 0xAA43), (0xAA4C, 0xAA4C),
+    (0xAA7C, 0xAA7C), (0xAAB0, 0xAAB0), (0xAAB2, 0xAAB4), (0xAAB7, 0xAAB8), (0xAABE, 0xAABF),
+    (0xAAC1, 0xAAC1), (0xAAEC, 0xAAED), (0xAAF6, 0xAAF6), (0xABE5, 0xABE5), (0xABE8, 0xABE8),
+    (0xABED, 0xABED), (0xFB1E, 0xFB1E), (0xFE00, 0xFE0F), (0xFE20, 0xFE2F), (0xFEFF, 0xFEFF),
+    (0xFFF9, 0xFFFB), (0x101FD, 0x101FD), (0x102E0, 0x102E0), (0x10376, 0x1037A),
+    (0x10A01, 0x10A03), (0x10A05, 0x10A06), (0x10A0C, 0x10A0F), (0x10A38, 0x10A3A),
+    (0x10A3F, 0x10A3F), (0x10AE5, 0x10AE6), (0x10D24, 0x10D27), (0x10EAB, 0x10EAC),
+    (0x10EFD, 0x10EFF), (0x10F46, 0x10F50), (0x10F82, 0x10F85), (0x11001, 0x11001),
+    (0x11038, 0x11046), (0x11070, 0x11070), (0x11073, 0x11074), (0x1107F, 0x11081),
+    (0x110B3, 0x110B6), (0x110B9, 0x110BA), (0x110BD, 0x110BD), (0x110C2, 0x110C2),
+    (0x110CD, 0x110CD), (0x11100, 0x11102), (0x11127, 0x1112B), (0x1112D, 0x11134),
+    (0x11173, 0x11173), (0x11180, 0x11181), (0x111B6, 0x111BE), (0x111C9, 0x111CC),
+    (0x111CF, 0x111CF), (0x1122F, 0x11231), (0x11234, 0x11234), (0x11236, 0x11237),
+    (0x1123E, 0x1123E), (0x11241, 0x11241), (0x112DF, 0x112DF), (0x112E3, 0x112EA),
+    (0x11300, 0x11301), (0x1133B, 0x1133C), (0x11340, 0x11340), (0x11366, 0x1136C),
+    (0x11370, 0x11374), (0x11438, 0x1143F), (0x11442, 0x11444), (0x11446, 0x11446),
+    (0x1145E, 0x1145E), (0x114B3, 0x114B8), (0x114BA, 0x114BA), (0x114BF, 0x114C0),
+    (0x114C2, 0x114C3), (0x115B2, 0x115B5), (0x115BC, 0x115BD), (0x115BF, 0x115C0),
+    (0x115DC, 0x115DD), (0x11633, 0x1163A), (0x1163D, 0x1163D), (0x1163F, 0x11640),
+    (0x116AB, 0x116AB), (0x116AD, 0x116AD), (0x116B0, 0x116B5), (0x116B7, 0x116B7),
+    (0x1171D, 0x1171F), (0x11722, 0x11725), (0x11727, 0x1172B), (0x1182F, 0x11837),
+    (0x11839, 0x1183A), (0x1193B, 0x1193C), (0x1193E, 0x1193E), (0x11943, 0x11943),
+    (0x119D4, 0x119D7), (0x119DA, 0x119DB), (0x119E0, 0x119E0), (0x11A01, 0x11A0A),
+    (0x11A33, 0x11A38), (0x11A3B, 0x11A3E), (0x11A47, 0x11A47), (0x11A51, 0x11A56),
+    (0x11A59, 0x11A5B), (0x11A8A, 0x11A96), (0x11A98, 0x11A99), (0x11C30, 0x11C36),
+    (0x11C38, 0x11C3D), (0x11C3F, 0x11C3F), (0x11C92, 0x11CA7), (0x11CAA, 0x11CB0),
+    (0x11CB2, 0x11CB3), (0x11CB5, 0x11CB6), (0x11D31, 0x11D36), (0x11D3A, 0x11D3A),
+    (0x11D3C, 0x11D3D), (0x11D3F, 0x11D45), (0x11D47, 0x11D47), (0x11D90, 0x11D91),
+    (0x11D95, 0x11D95), (0x11D97, 0x11D97), (0x11EF3, 0x11EF4), (0x11F00, 0x11F01),
+    (0x11F36, 0x11F3A), (0x11F40, 0x11F40), (0x11F42, 0x11F42), (0x13430, 0x13440),
+    (0x13447, 0x13455), (0x16AF0, 0x16AF4), (0x16B30, 0x16B36), (0x16F4F, 0x16F4F),
+    (0x16F8F, 0x16F92), (0x16FE4, 0x16FE4), (0x1BC9D, 0x1BC9E), (0x1BCA0, 0x1BCA3),
+    (0x1CF00, 0x1CF2D), (0x1CF30, 0x1CF46), (0x1D167, 0x1D169), (0x1D173, 0x1D182),
+    (0x1D185, 0x1D18B), (0x1D1AA, 0x1D1AD), (0x1D242, 0x1D244), (0x1DA00, 0x1DA36),
+    (0x1DA3B, 0x1DA6C), (0x1DA75, 0x1DA75), (0x1DA84, 0x1DA84), (0x1DA9B, 0x1DA9F),
+    (0x1DAA1, 0x1DAAF), (0x1E000, 0x1E006), (0x1E008, 0x1E018), (0x1E01B, 0x1E021),
+    (0x1E023, 0x1E024), (0x1E026, 0x1E02A), (0x1E08F, 0x1E08F), (0x1E130, 0x1E136),
+    (0x1E2AE, 0x1E2AE), (0x1E2EC, 0x1E2EF), (0x1E4EC, 0x1E4EF), (0x1E8D0, 0x1E8D6),
+    (0x1E944, 0x1E94A), (0xE0001, 0xE0001), (0xE0020, 0xE007F), (0xE0100, 0xE01EF),
+];
+
+/// Code points with an East Asian Width of Wide or Fullwidth, which take up two columns.
+#[rustfmt::skip]
+pub const WIDE: &[(u32, u32)] = &[
+    (0x1100, 0x115F), (0x231A, 0x231B), (0x2329, 0x232A), (0x23E9, 0x23EC), (0x23F0, 0x23F0),
+    (0x23F3, 0x23F3), (0x25FD, 0x25FE), (0x2614, 0x2615), (0x2648, 0x2653), (0x267F, 0x267F),
+    (0x2693, 0x2693), (0x26A1, 0x26A1), (0x26AA, 0x26AB), (0x26BD, 0x26BE), (0x26C4, 0x26C5),
+    (0x26CE, 0x26CE), (0x26D4, 0x26D4), (0x26EA, 0x26EA), (0x26F2, 0x26F3), (0x26F5, 0x26F5),
+    (0x26FA, 0x26FA), (0x26FD, 0x26FD), (0x2705, 0x2705), (0x270A, 0x270B), (0x2728, 0x2728),
+    (0x274C, 0x274C), (0x274E, 0x274E), (0x2753, 0x2755), (0x2757, 0x2757), (0x2795, 0x2797),
+    (0x27B0, 0x27B0), (0x27BF, 0x27BF), (0x2B1B, 0x2B1C), (0x2B50, 0x2B50), (0x2B55, 0x2B55),
+    (0x2E80, 0x2E99), (0x2E9B, 0x2EF3), (0x2F00, 0x2FD5), (0x2FF0, 0x3029), (0x302E, 0x303E),
+    (0x3041, 0x3096), (0x309B, 0x30FF), (0x3105, 0x312F), (0x3131, 0x318E), (0x3190, 0x31E3),
+    (0x31EF, 0x321E), (0x3220, 0x3247), (0x3250, 0x4DBF), (0x4E00, 0xA48C), (0xA490, 0xA4C6),
+    (0xA960, 0xA97C), (0xAC00, 0xD7A3), (0xF900, 0xFAFF), (0xFE10, 0xFE19), (0xFE30, 0xFE52),
+    (0xFE54, 0xFE66), (0xFE68, 0xFE6B), (0xFF01, 0xFF60), (0xFFE0, 0xFFE6), (0x16FE0, 0x16FE3),
+    (0x16FF0, 0x16FF1), (0x17000, 0x187F7), (0x18800, 0x18CD5), (0x18D00, 0x18D08),
+    (0x1AFF0, 0x1AFF3), (0x1AFF5, 0x1AFFB), (0x1AFFD, 0x1AFFE), (0x1B000, 0x1B122),
+    (0x1B132, 0x1B132), (0x1B150, 0x1B152), (0x1B155, 0x1B155), (0x1B164, 0x1B167),
+    (0x1B170, 0x1B2FB), (0x1F004, 0x1F004), (0x1F0CF, 0x1F0CF), (0x1F18E, 0x1F18E),
+    (0x1F191, 0x1F19A), (0x1F200, 0x1F202), (0x1F210, 0x1F23B), (0x1F240, 0x1F248),
+    (0x1F250, 0x1F251), (0x1F260, 0x1F265), (0x1F300, 0x1F320), (0x1F32D, 0x1F335),
+    (0x1F337, 0x1F37C), (0x1F37E, 0x1F393), (0x1F3A0, 0x1F3CA), (0x1F3CF, 0x1F3D3),
+    (0x1F3E0, 0x1F3F0), (0x1F3F4, 0x1F3F4), (0x1F3F8, 0x1F43E), (0x1F440, 0x1F440),
+    (0x1F442, 0x1F4FC), (0x1F4FF, 0x1F53D), (0x1F54B, 0x1F54E), (0x1F550, 0x1F567),
+    (0x1F57A, 0x1F57A), (0x1F595, 0x1F596), (0x1F5A4, 0x1F5A4), (0x1F5FB, 0x1F64F),
+    (0x1F680, 0x1F6C5), (0x1F6CC, 0x1F6CC), (0x1F6D0, 0x1F6D2), (0x1F6D5, 0x1F6D7),
+    (0x1F6DC, 0x1F6DF), (0x1F6EB, 0x1F6EC), (0x1F6F4, 0x1F6FC), (0x1F7E0, 0x1F7EB),
+    (0x1F7F0, 0x1F7F0), (0x1F90C, 0x1F93A), (0x1F93C, 0x1F945), (0x1F947, 0x1F9FF),
+    (0x1FA70, 0x1FA7C), (0x1FA80, 0x1FA88), (0x1FA90, 0x1FABD), (0x1FABF, 0x1FAC5),
+    (0x1FACE, 0x1FADB), (0x1FAE0, 0x1FAE8), (0x1FAF0, 0x1FAF8), (0x20000, 0x2FFFD),
+    (0x30000, 0x3FFFD),
+];
+
+/// Code points with an East Asian Width of Ambiguous, which take up two columns in East
+/// Asian contexts and one column otherwise.
+#[rustfmt::skip]
+pub const AMBIGUOUS: &[(u32, u32)] = &[
+    (0xA1, 0xA1), (0xA4, 0xA4), (0xA7, 0xA8), (0xAA, 0xAA), (0xAE, 0xAE), (0xB0, 0xB4),
+    (0xB6, 0xBA), (0xBC, 0xBF), (0xC6, 0xC6), (0xD0, 0xD0), (0xD7, 0xD8), (0xDE, 0xE1),
+    (0xE6, 0xE6), (0xE8, 0xEA), (0xEC, 0xED), (0xF0, 0xF0), (0xF2, 0xF3), (0xF7, 0xFA),
+    (0xFC, 0xFC), (0xFE, 0xFE), (0x101, 0x101), (0x111, 0x111), (0x113, 0x113), (0x11B, 0x11B),
+    (0x126, 0x127), (0x12B, 0x12B), (0x131, 0x133), (0x138, 0x138), (0x13F, 0x142), (0x144, 0x144),
+    (0x148, 0x14B), (0x14D, 0x14D), (0x152, 0x153), (0x166, 0x167), (0x16B, 0x16B), (0x1CE, 0x1CE),
+    (0x1D0, 0x1D0), (0x1D2, 0x1D2), (0x1D4, 0x1D4), (0x1D6, 0x1D6), (0x1D8, 0x1D8), (0x1DA, 0x1DA),
+    (0x1DC, 0x1DC), (0x251, 0x251), (0x261, 0x261), (0x2C4, 0x2C4), (0x2C7, 0x2C7), (0x2C9, 0x2CB),
+    (0x2CD, 0x2CD), (0x2D0, 0x2D0), (0x2D8, 0x2DB), (0x2DD, 0x2DD), (0x2DF, 0x2DF), (0x391, 0x3A1),
+    (0x3A3, 0x3A9), (0x3B1, 0x3C1), (0x3C3, 0x3C9), (0x401, 0x401), (0x410, 0x44F), (0x451, 0x451),
+    (0x2010, 0x2010), (0x2013, 0x2016), (0x2018, 0x2019), (0x201C, 0x201D), (0x2020, 0x2022),
+    (0x2024, 0x2027), (0x2030, 0x2030), (0x2032, 0x2033), (0x2035, 0x2035), (0x203B, 0x203B),
+    (0x203E, 0x203E), (0x2074, 0x2074), (0x207F, 0x207F), (0x2081, 0x2084), (0x20AC, 0x20AC),
+    (0x2103, 0x2103), (0x2105, 0x2105), (0x2109, 0x2109), (0x2113, 0x2113), (0x2116, 0x2116),
+    (0x2121, 0x2122), (0x2126, 0x2126), (0x212B, 0x212B), (0x2153, 0x2154), (0x215B, 0x215E),
+    (0x2160, 0x216B), (0x2170, 0x2179), (0x2189, 0x2189), (0x2190, 0x2199), (0x21B8, 0x21B9),
+    (0x21D2, 0x21D2), (0x21D4, 0x21D4), (0x21E7, 0x21E7), (0x2200, 0x2200), (0x2202, 0x2203),
+    (0x2207, 0x2208), (0x220B, 0x220B), (0x220F, 0x220F), (0x2211, 0x2211), (0x2215, 0x2215),
+    (0x221A, 0x221A), (0x221D, 0x2220), (0x2223, 0x2223), (0x2225, 0x2225), (0x2227, 0x222C),
+    (0x222E, 0x222E), (0x2234, 0x2237), (0x223C, 0x223D), (0x2248, 0x2248), (0x224C, 0x224C),
+    (0x2252, 0x2252), (0x2260, 0x2261), (0x2264, 0x2267), (0x226A, 0x226B), (0x226E, 0x226F),
+    (0x2282, 0x2283), (0x2286, 0x2287), (0x2295, 0x2295), (0x2299, 0x2299), (0x22A5, 0x22A5),
+    (0x22BF, 0x22BF), (0x2312, 0x2312), (0x2460, 0x24E9), (0x24EB, 0x254B), (0x2550, 0x2573),
+    (0x2580, 0x258F), (0x2592, 0x2595), (0x25A0, 0x25A1), (0x25A3, 0x25A9), (0x25B2, 0x25B3),
+    (0x25B6, 0x25B7), (0x25BC, 0x25BD), (0x25C0, 0x25C1), (0x25C6, 0x25C8), (0x25CB, 0x25CB),
+    (0x25CE, 0x25D1), (0x25E2, 0x25E5), (0x25EF, 0x25EF), (0x2605, 0x2606), (0x2609, 0x2609),
+    (0x260E, 0x260F), (0x261C, 0x261C), (0x261E, 0x261E), (0x2640, 0x2640), (0x2642, 0x2642),
+    (0x2660, 0x2661), (0x2663, 0x2665), (0x2667, 0x266A), (0x266C, 0x266D), (0x266F, 0x266F),
+    (0x269E, 0x269F), (0x26BF, 0x26BF), (0x26C6, 0x26CD), (0x26CF, 0x26D3), (0x26D5, 0x26E1),
+    (0x26E3, 0x26E3), (0x26E8, 0x26E9), (0x26EB, 0x26F1), (0x26F4, 0x26F4), (0x26F6, 0x26F9),
+    (0x26FB, 0x26FC), (0x26FE, 0x26FF), (0x273D, 0x273D), (0x2776, 0x277F), (0x2B56, 0x2B59),
+    (0x3248, 0x324F), (0xE000, 0xF8FF), (0xFFFD, 0xFFFD), (0x1F100, 0x1F10A), (0x1F110, 0x1F12D),
+    (0x1F130, 0x1F169), (0x1F170, 0x1F18D), (0x1F18F, 0x1F190), (0x1F19B, 0x1F1AC),
+    (0xF0000, 0xFFFFD), (0x100000, 0x10FFFD),
+];
+
+// Line breaking, after the ID, OP, CL, NS and GL classes of UAX #14.  These are a
+// conservative subset for wrapping text without white space, not the full algorithm.
+
+/// Ideographs and the scripts written with them, between whose characters a line may
+/// break: CJK ideographs, radicals and symbols, kana, bopomofo, Hangul syllables, Yi
+/// and full width forms.
+#[rustfmt::skip]
+pub const IDEOGRAPHIC: &[(u32, u32)] = &[
+    (0x2E80, 0x2FFF), (0x3000, 0x303F), (0x3040, 0x33FF), (0x3400, 0x4DBF), (0x4E00, 0x9FFF),
+    (0xA000, 0xA4CF), (0xAC00, 0xD7A3), (0xF900, 0xFAFF), (0xFE30, 0xFE4F), (0xFF01, 0xFF60),
+    (0xFFE0, 0xFFE6), (0x1B000, 0x1B2FF), (0x20000, 0x2FFFD), (0x30000, 0x3FFFD),
+];
+
+/// Opening brackets and quotes, which a line must not end with.
+#[rustfmt::skip]
+pub const NO_BREAK_AFTER: &[(u32, u32)] = &[
+    (0x28, 0x28), (0x5B, 0x5B), (0x7B, 0x7B), (0xAB, 0xAB), (0x2018, 0x2018), (0x201C, 0x201C),
+    (0x2039, 0x2039), (0x3008, 0x3008), (0x300A, 0x300A), (0x300C, 0x300C), (0x300E, 0x300E),
+    (0x3010, 0x3010), (0x3014, 0x3014), (0x3016, 0x3016), (0x3018, 0x3018), (0x301A, 0x301A),
+    (0x301D, 0x301D), (0xFF08, 0xFF08), (0xFF3B, 0xFF3B), (0xFF5B, 0xFF5B), (0xFF5F, 0xFF5F),
+    (0xFF62, 0xFF62),
+];
+
+/// Closing brackets and quotes, punctuation, iteration marks, small kana and the
+/// prolonged sound mark, which a line must not start with.
+#[rustfmt::skip]
+pub const NO_BREAK_BEFORE: &[(u32, u32)] = &[
+    (0x21, 0x21), (0x25, 0x25), (0x29, 0x29), (0x2C, 0x2C), (0x2E, 0x2E), (0x3A, 0x3B),
+    (0x3F, 0x3F), (0x5D, 0x5D), (0x7D, 0x7D), (0xBB, 0xBB), (0x2019, 0x2019), (0x201D, 0x201D),
+    (0x2025, 0x2026), (0x203A, 0x203A), (0x203C, 0x203C), (0x2047, 0x2049), (0x3001, 0x3002),
+    (0x3005, 0x3005), (0x3009, 0x3009), (0x300B, 0x300B), (0x300D, 0x300D), (0x300F, 0x300F),
+    (0x3011, 0x3011), (0x3015, 0x3015), (0x3017, 0x3017), (0x3019, 0x3019), (0x301B, 0x301C),
+    (0x301E, 0x301F), (0x303B, 0x303C), (0x3041, 0x3041), (0x3043, 0x3043), (0x3045, 0x3045),
+    (0x3047, 0x3047), (0x3049, 0x3049), (0x3063, 0x3063), (0x3083, 0x3083), (0x3085, 0x3085),
+    (0x3087, 0x3087), (0x308E, 0x308E), (0x3095, 0x3096), (0x309B, 0x309E), (0x30A0, 0x30A1),
+    (0x30A3, 0x30A3), (0x30A5, 0x30A5), (0x30A7, 0x30A7), (0x30A9, 0x30A9), (0x30C3, 0x30C3),
+    (0x30E3, 0x30E3), (0x30E5, 0x30E5), (0x30E7, 0x30E7), (0x30EE, 0x30EE), (0x30F5, 0x30F6),
+    (0x30FB, 0x30FE), (0x31F0, 0x31FF), (0xFF01, 0xFF01), (0xFF05, 0xFF05), (0xFF09, 0xFF09),
+    (0xFF0C, 0xFF0C), (0xFF0E, 0xFF0E), (0xFF1A, 0xFF1B), (0xFF1F, 0xFF1F), (0xFF3D, 0xFF3D),
+    (0xFF5D, 0xFF5D), (0xFF60, 0xFF61), (0xFF63, 0xFF65),
+];
+
+/// Characters which glue their neighbors together: the no-break, figure and narrow
+/// no-break spaces, the non-breaking hyphen, word joiner and zero width no-break space.
+#[rustfmt::skip]
+pub const NON_BREAKING: &[(u32, u32)] = &[
+    (0xA0, 0xA0), (0x2007, 0x2007), (0x2011, 0x2011), (0x202F, 0x202F), (0x2060, 0x2060),
+    (0xFEFF, 0xFEFF),
+];
+
+/// Finds the value `c` has in a sorted table of inclusive ranges and their values.
+pub fn lookup<T: Copy>(c: char, table: &[(u32, u32, T)]) -> Option<T> {
+    let c = c as u32;
+    table
+        .binary_search_by(|&(first, last, _)| {
+            if c < first {
+                Ordering::Greater
+            } else if c > last {
+                Ordering::Less
+            } else {
+                Ordering::Equal
+            }
+        })
+        .ok()
+        .map(|index| table[index].2)
+}
+
+/// Emoji and the symbols likely to become emoji, which stay joined to a zero width joiner
+/// before them.
+#[rustfmt::skip]
+pub const EXTENDED_PICTOGRAPHIC: &[(u32, u32)] = &[
+    (0xA9, 0xA9), (0xAE, 0xAE), (0x203C, 0x203C), (0x2049, 0x2049), (0x2122, 0x2122),
+    (0x2139, 0x2139), (0x2194, 0x2199), (0x21A9, 0x21AA), (0x231A, 0x231B), (0x2328, 0x2328),
+    (0x2388, 0x2388), (0x23CF, 0x23CF), (0x23E9, 0x23F3), (0x23F8, 0x23FA), (0x24C2, 0x24C2),
+    (0x25AA, 0x25AB), (0x25B6, 0x25B6), (0x25C0, 0x25C0), (0x25FB, 0x25FE), (0x2600, 0x2605),
+    (0x2607, 0x2612), (0x2614, 0x2685), (0x2690, 0x2705), (0x2708, 0x2712), (0x2714, 0x2714),
+    (0x2716, 0x2716), (0x271D, 0x271D), (0x2721, 0x2721), (0x2728, 0x2728), (0x2733, 0x2734),
+    (0x2744, 0x2744), (0x2747, 0x2747), (0x274C, 0x274C), (0x274E, 0x274E), (0x2753, 0x2755),
+    (0x2757, 0x2757), (0x2763, 0x2767), (0x2795, 0x2797), (0x27A1, 0x27A1), (0x27B0, 0x27B0),
+    (0x27BF, 0x27BF), (0x2934, 0x2935), (0x2B05, 0x2B07), (0x2B1B, 0x2B1C), (0x2B50, 0x2B50),
+    (0x2B55, 0x2B55), (0x3030, 0x3030), (0x303D, 0x303D), (0x3297, 0x3297), (0x3299, 0x3299),
+    (0x1F000, 0x1F0FF), (0x1F10D, 0x1F10F), (0x1F12F, 0x1F12F), (0x1F16C, 0x1F171),
+    (0x1F17E, 0x1F17F), (0x1F18E, 0x1F18E), (0x1F191, 0x1F19A), (0x1F1AD, 0x1F1E5),
+    (0x1F201, 0x1F20F), (0x1F21A, 0x1F21A), (0x1F22F, 0x1F22F), (0x1F232, 0x1F23A),
+    (0x1F23C, 0x1F23F), (0x1F249, 0x1F3FA), (0x1F400, 0x1F53D), (0x1F546, 0x1F64F),
+    (0x1F680, 0x1F6FF), (0x1F774, 0x1F77F), (0x1F7D5, 0x1F7FF), (0x1F80C, 0x1F80F),
+    (0x1F848, 0x1F84F), (0x1F85A, 0x1F85F), (0x1F888, 0x1F88F), (0x1F8AE, 0x1F8FF),
+    (0x1F90C, 0x1F93A), (0x1F93C, 0x1F945), (0x1F947, 0x1FAFF), (0x1FC00, 0x1FFFD),
+];
+
+/// Grapheme_Cluster_Break property values of UAX #29, with Extended_Pictographic folded in.
+pub mod grapheme {
+    /// A Grapheme_Cluster_Break property value, or `ExtendedPictographic` for emoji which
+    /// have none.  Characters not in the table are `Other`.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum GraphemeBreak {
+        Cr,
+        Lf,
+        Control,
+        Extend,
+        Zwj,
+        RegionalIndicator,
+        Prepend,
+        SpacingMark,
+        L,
+        V,
+        T,
+        Lv,
+        Lvt,
+        ExtendedPictographic,
+        Other,
+    }
+    use self::GraphemeBreak::*;
+
+    /// The Grapheme_Cluster_Break value of every code point which has one.
+    #[rustfmt::skip]
+    pub const GRAPHEME_BREAK: &[(u32, u32, GraphemeBreak)] = &[
+        (0x0, 0x9, Control), (0xA, 0xA, Lf), (0xB, 0xC, Control), (0xD, 0xD, Cr), (0xE, 0x1F, Control),
+        (0x7F, 0x9F, Control), (0xA9, 0xA9, ExtendedPictographic), (0xAD, 0xAD, Control),
+        (0xAE, 0xAE, ExtendedPictographic), (0x300, 0x36F, Extend), (0x483, 0x489, Extend),
+        (0x591, 0x5BD, Extend), (0x5BF, 0x5BF, Extend), (0x5C1, 0x5C2, Extend), (0x5C4, 0x5C5, Extend),
+        (0x5C7, 0x5C7, Extend), (0x600, 0x605, Prepend), (0x610, 0x61A, Extend),
+        (0x61C, 0x61C, Control), (0x64B, 0x65F, Extend), (0x670, 0x670, Extend),
+        (0x6D6, 0x6DC, Extend), (0x6DD, 0x6DD, Prepend), (0x6DF, 0x6E4, Extend),
+        (0x6E7, 0x6E8, Extend), (0x6EA, 0x6ED, Extend), (0x70F, 0x70F, Prepend),
+        (0x711, 0x711, Extend), (0x730, 0x74A, Extend), (0x7A6, 0x7B0, Extend), (0x7EB, 0x7F3, Extend),
+        (0x7FD, 0x7FD, Extend), (0x816, 0x819, Extend), (0x81B, 0x823, Extend), (0x825, 0x827, Extend),
+        (0x829, 0x82D, Extend), (0x859, 0x85B, Extend), (0x890, 0x891, Prepend),
+        (0x898, 0x89F, Extend), (0x8CA, 0x8E1, Extend), (0x8E2, 0x8E2, Prepend),
+        (0x8E3, 0x902, Extend), (0x903, 0x903, SpacingMark), (0x93A, 0x93A, Extend),
+        (0x93B, 0x93B, SpacingMark), (0x93C, 0x93C, Extend), (0x93E, 0x940, SpacingMark),
+        (0x941, 0x948, Extend), (0x949, 0x94C, SpacingMark), (0x94D, 0x94D, Extend),
+        (0x94E, 0x94F, SpacingMark), (0x951, 0x957, Extend), (0x962, 0x963, Extend),
+        (0x981, 0x981, Extend), (0x982, 0x983, SpacingMark), (0x9BC, 0x9BC, Extend),
+        (0x9BE, 0x9BE, Extend), (0x9BF, 0x9C0, SpacingMark), (0x9C1, 0x9C4, Extend),
+        (0x9C7, 0x9C8, SpacingMark), (0x9CB, 0x9CC, SpacingMark), (0x9CD, 0x9CD, Extend),
+        (0x9D7, 0x9D7, Extend), (0x9E2, 0x9E3, Extend), (0x9FE, 0x9FE, Extend), (0xA01, 0xA02, Extend),
+        (0xA03, 0xA03, SpacingMark), (0xA3C, 0xA3C, Extend), (0xA3E, 0xA40, SpacingMark),
+        (0xA41, 0xA42, Extend), (0xA47, 0xA48, Extend), (0xA4B, 0xA4D, Extend), (0xA51, 0xA51, Extend),
+        (0xA70, 0xA71, Extend), (0xA75, 0xA75, Extend), (0xA81, 0xA82, Extend),
+        (0xA83, 0xA83, SpacingMark), (0xABC, 0xABC, Extend), (0xABE, 0xAC0, SpacingMark),
+        (0xAC1, 0xAC5, Extend), (0xAC7, 0xAC8, Extend), (0xAC9, 0xAC9, SpacingMark),
+        (0xACB, 0xACC, SpacingMark), (0xACD, 0xACD, Extend), (0xAE2, 0xAE3, Extend),
+        (0xAFA, 0xAFF, Extend), (0xB01, 0xB01, Extend), (0xB02, 0xB03, SpacingMark),
+        (0xB3C, 0xB3C, Extend), (0xB3E, 0xB3F, Extend), (0xB40, 0xB40, SpacingMark),
+        (0xB41, 0xB44, Extend), (0xB47, 0xB48, SpacingMark), (0xB4B, 0xB4C, SpacingMark),
+        (0xB4D, 0xB4D, Extend), (0xB55, 0xB57, Extend), (0xB62, 0xB63, Extend), (0xB82, 0xB82, Extend),
+        (0xBBE, 0xBBE, Extend), (0xBBF, 0xBBF, SpacingMark), (0xBC0, 0xBC0, Extend),
+        (0xBC1, 0xBC2, SpacingMark), (0xBC6, 0xBC8, SpacingMark), (0xBCA, 0xBCC, SpacingMark),
+        (0xBCD, 0xBCD, Extend), (0xBD7, 0xBD7, Extend), (0xC00, 0xC00, Extend),
+        (0xC01, 0xC03, SpacingMark), (0xC04, 0xC04, Extend), (0xC3C, 0xC3C, Extend),
+        (0xC3E, 0xC40, Extend), (0xC41, 0xC44, SpacingMark), (0xC46, 0xC48, Extend),
+        (0xC4A, 0xC4D, Extend), (0xC55, 0xC56, Extend), (0xC62, 0xC63, Extend), (0xC81, 0xC81, Extend),
+        (0xC82, 0xC83, SpacingMark), (0xCBC, 0xCBC, Extend), (0xCBE, 0xCBE, SpacingMark),
+        (0xCBF, 0xCBF, Extend), (0xCC0, 0xCC1, SpacingMark), (0xCC2, 0xCC2, Extend),
+        (0xCC3, 0xCC4, SpacingMark), (0xCC6, 0xCC6, Extend), (0xCC7, 0xCC8, SpacingMark),
+        (0xCCA, 0xCCB, SpacingMark), (0xCCC, 0xCCD, Extend), (0xCD5, 0xCD6, Extend),
+        (0xCE2, 0xCE3, Extend), (0xCF3, 0xCF3, SpacingMark), (0xD00, 0xD01, Extend),
+        (0xD02, 0xD03, SpacingMark), (0xD3B, 0xD3C, Extend), (0xD3E, 0xD3E, Extend),
+        (0xD3F, 0xD40, SpacingMark), (0xD41, 0xD44, Extend), (0xD46, 0xD48, SpacingMark),
+        (0xD4A, 0xD4C, SpacingMark), (0xD4D, 0xD4D, Extend), (0xD4E, 0xD4E, Prepend),
+        (0xD57, 0xD57, Extend), (0xD62, 0xD63, Extend), (0xD81, 0xD81, Extend),
+        (0xD82, 0xD83, SpacingMark), (0xDCA, 0xDCA, Extend), (0xDCF, 0xDCF, Extend),
+        (0xDD0, 0xDD1, SpacingMark), (0xDD2, 0xDD4, Extend), (0xDD6, 0xDD6, Extend),
+        (0xDD8, 0xDDE, SpacingMark), (0xDDF, 0xDDF, Extend), (0xDF2, 0xDF3, SpacingMark),
+        (0xE31, 0xE31, Extend), (0xE33, 0xE33, SpacingMark), (0xE34, 0xE3A, Extend),
+        (0xE47, 0xE4E, Extend), (0xEB1, 0xEB1, Extend), (0xEB3, 0xEB3, SpacingMark),
+        (0xEB4, 0xEBC, Extend), (0xEC8, 0xECE, Extend), (0xF18, 0xF19, Extend), (0xF35, 0xF35, Extend),
+        (0xF37, 0xF37, Extend), (0xF39, 0xF39, Extend), (0xF3E, 0xF3F, SpacingMark),
+        (0xF71, 0xF7E, Extend), (0xF7F, 0xF7F, SpacingMark), (0xF80, 0xF84, Extend),
+        (0xF86, 0xF87, Extend), (0xF8D, 0xF97, Extend), (0xF99, 0xFBC, Extend), (0xFC6, 0xFC6, Extend),
+        (0x102D, 0x1030, Extend), (0x1031, 0x1031, SpacingMark), (0x1032, 0x1037, Extend),
+        (0x1039, 0x103A, Extend), (0x103B, 0x103C, SpacingMark), (0x103D, 0x103E, Extend),
+        (0x1056, 0x1057, SpacingMark), (0x1058, 0x1059, Extend), (0x105E, 0x1060, Extend),
+        (0x1071, 0x1074, Extend), (0x1082, 0x1082, Extend), (0x1084, 0x1084, SpacingMark),
+        (0x1085, 0x1086, Extend), (0x108D, 0x108D, Extend), (0x109D, 0x109D, Extend),
+        (0x1100, 0x115F, L), (0x1160, 0x11A7, V), (0x11A8, 0x11FF, T), (0x135D, 0x135F, Extend),
+        (0x1712, 0x1714, Extend), (0x1715, 0x1715, SpacingMark), (0x1732, 0x1733, Extend),
+        (0x1734, 0x1734, SpacingMark), (0x1752, 0x1753, Extend), (0x1772, 0x1773, Extend),
+        (0x17B4, 0x17B5, Extend), (0x17B6, 0x17B6, SpacingMark), (0x17B7, 0x17BD, Extend),
+        (0x17BE, 0x17C5, SpacingMark), (0x17C6, 0x17C6, Extend), (0x17C7, 0x17C8, SpacingMark),
+        (0x17C9, 0x17D3, Extend), (0x17DD, 0x17DD, Extend), (0x180B, 0x180D, Extend),
+        (0x180E, 0x180E, Control), (0x180F, 0x180F, Extend), (0x1885, 0x1886, Extend),
+        (0x18A9, 0x18A9, Extend), (0x1920, 0x1922, Extend), (0x1923, 0x1926, SpacingMark),
+        (0x1927, 0x1928, Extend), (0x1929, 0x192B, SpacingMark), (0x1930, 0x1931, SpacingMark),
+        (0x1932, 0x1932, Extend), (0x1933, 0x1938, SpacingMark), (0x1939, 0x193B, Extend),
+        (0x1A17, 0x1A18, Extend), (0x1A19, 0x1A1A, SpacingMark), (0x1A1B, 0x1A1B, Extend),
+        (0x1A55, 0x1A55, SpacingMark), (0x1A56, 0x1A56, Extend), (0x1A57, 0x1A57, SpacingMark),
+        (0x1A58, 0x1A5E, Extend), (0x1A60, 0x1A60, Extend), (0x1A62, 0x1A62, Extend),
+        (0x1A65, 0x1A6C, Extend), (0x1A6D, 0x1A72, SpacingMark), (0x1A73, 0x1A7C, Extend),
+        (0x1A7F, 0x1A7F, Extend), (0x1AB0, 0x1ACE, Extend), (0x1B00, 0x1B03, Extend),
+        (0x1B04, 0x1B04, SpacingMark), (0x1B34, 0x1B3A, Extend), (0x1B3B, 0x1B3B, SpacingMark),
+        (0x1B3C, 0x1B3C, Extend), (0x1B3D, 0x1B41, SpacingMark), (0x1B42, 0x1B42, Extend),
+        (0x1B43, 0x1B44, SpacingMark), (0x1B6B, 0x1B73, Extend), (0x1B80, 0x1B81, Extend),
+        (0x1B82, 0x1B82, SpacingMark), (0x1BA1, 0x1BA1, SpacingMark), (0x1BA2, 0x1BA5, Extend),
+        (0x1BA6, 0x1BA7, SpacingMark), (0x1BA8, 0x1BA9, Extend), (0x1BAA, 0x1BAA, SpacingMark),
+        (0x1BAB, 0x1BAD, Extend), (0x1BE6, 0x1BE6, Extend), (0x1BE7, 0x1BE7, SpacingMark),
+        (0x1BE8, 0x1BE9, Extend), (0x1BEA, 0x1BEC, SpacingMark), (0x1BED, 0x1BED, Extend),
+        (0x1BEE, 0x1BEE, SpacingMark), (0x1BEF, 0x1BF1, Extend), (0x1BF2, 0x1BF3, SpacingMark),
+        (0x1C24, 0x1C2B, SpacingMark), (0x1C2C, 0x1C33, Extend), (0x1C34, 0x1C35, SpacingMark),
+        (0x1C36, 0x1C37, Extend), (0x1CD0, 0x1CD2, Extend), (0x1CD4, 0x1CE0, Extend),
+        (0x1CE1, 0x1CE1, SpacingMark), (0x1CE2, 0x1CE8, Extend), (0x1CED, 0x1CED, Extend),
+        (0x1CF4, 0x1CF4, Extend), (0x1CF7, 0x1CF7, SpacingMark), (0x1CF8, 0x1CF9, Extend),
+        (0x1DC0, 0x1DFF, Extend), (0x200B, 0x200B, Control), (0x200C, 0x200C, Extend),
+        (0x200D, 0x200D, Zwj), (0x200E, 0x200F, Control), (0x2028, 0x202E, Control),
+        (0x203C, 0x203C, ExtendedPictographic), (0x2049, 0x2049, ExtendedPictographic),
+        (0x2060, 0x206F, Control), (0x20D0, 0x20F0, Extend), (0x2122, 0x2122, ExtendedPictographic),
+        (0x2139, 0x2139, ExtendedPictographic), (0x2194, 0x2199, ExtendedPictographic),
+        (0x21A9, 0x21AA, ExtendedPictographic), (0x231A, 0x231B, ExtendedPictographic),
+        (0x2328, 0x2328, ExtendedPictographic), (0x2388, 0x2388, ExtendedPictographic),
+        (0x23CF, 0x23CF, ExtendedPictographic), (0x23E9, 0x23F3, ExtendedPictographic),
+        (0x23F8, 0x23FA, ExtendedPictographic), (0x24C2, 0x24C2, ExtendedPictographic),
+        (0x25AA, 0x25AB, ExtendedPictographic), (0x25B6, 0x25B6, ExtendedPictographic),
+        (0x25C0, 0x25C0, ExtendedPictographic), (0x25FB, 0x25FE, ExtendedPictographic),
+        (0x2600, 0x2605, ExtendedPictographic), (0x2607, 0x2612, ExtendedPictographic),
+        (0x2614, 0x2685, ExtendedPictographic), (0x2690, 0x2705, ExtendedPictographic),
+        (0x2708, 0x2712, ExtendedPictographic), (0x2714, 0x2714, ExtendedPictographic),
+        (0x2716, 0x2716, ExtendedPictographic), (0x271D, 0x271D, ExtendedPictographic),
+        (0x2721, 0x2721, ExtendedPictographic), (0x2728, 0x2728, ExtendedPictographic),
+        (0x2733, 0x2734, ExtendedPictographic), (0x2744, 0x2744, ExtendedPictographic),
+        (0x2747, 0x2747, ExtendedPictographic), (0x274C, 0x274C, ExtendedPictographic),
+        (0x274E, 0x274E, ExtendedPictographic), (0x2753, 0x2755, ExtendedPictographic),
+        (0x2757, 0x2757, ExtendedPictographic), (0x2763, 0x2767, ExtendedPictographic),
+        (0x2795, 0x2797, ExtendedPictographic), (0x27A1, 0x27A1, ExtendedPictographic),
+        (0x27B0, 0x27B0, ExtendedPictographic), (0x27BF, 0x27BF, ExtendedPictographic),
+        (0x2934, 0x2935, ExtendedPictographic), (0x2B05, 0x2B07, ExtendedPictographic),
+        (0x2B1B, 0x2B1C, ExtendedPictographic), (0x2B50, 0x2B50, ExtendedPictographic),
+        (0x2B55, 0x2B55, ExtendedPictographic), (0x2CEF, 0x2CF1, Extend), (0x2D7F, 0x2D7F, Extend),
+        (0x2DE0, 0x2DFF, Extend), (0x302A, 0x302F, Extend), (0x3030, 0x3030, ExtendedPictographic),
+        (0x303D, 0x303D, ExtendedPictographic), (0x3099, 0x309A, Extend),
+        (0x3297, 0x3297, ExtendedPictographic), (0x3299, 0x3299, ExtendedPictographic),
+        (0xA66F, 0xA672, Extend), (0xA674, 0xA67D, Extend), (0xA69E, 0xA69F, Extend),
+        (0xA6F0, 0xA6F1, Extend), (0xA802, 0xA802, Extend), (0xA806, 0xA806, Extend),
+        (0xA80B, 0xA80B, Extend), (0xA823, 0xA824, SpacingMark), (0xA825, 0xA826, Extend),
+        (0xA827, 0xA827, SpacingMark), (0xA82C, 0xA82C, Extend), (0xA880, 0xA881, SpacingMark),
+        (0xA8B4, 0xA8C3, SpacingMark), (0xA8C4, 0xA8C5, Extend), (0xA8E0, 0xA8F1, Extend),
+        (0xA8FF, 0xA8FF, Extend), (0xA926, 0xA92D, Extend), (0xA947, 0xA951, Extend),
+        (0xA952, 0xA953, SpacingMark), (0xA960, 0xA97C, L), (0xA980, 0xA982, Extend),
+        (0xA983, 0xA983, SpacingMark), (0xA9B3, 0xA9B3, Extend), (0xA9B4, 0xA9B5, SpacingMark),
+        (0xA9B6, 0xA9B9, Extend), (0xA9BA, 0xA9BB, SpacingMark), (0xA9BC, 0xA9BD, Extend),
+        (0xA9BE, 0xA9C0, SpacingMark), (0xA9E5, 0xA9E5, Extend), (0xAA29, 0xAA2E, Extend),
+        (0xAA2F, 0xAA30, SpacingMark), (0xAA31, 0xAA32, Extend), (0xAA33, 0xAA34, SpacingMark),
+        (0xAA35, 0xAA36, Extend), (0xAA43, 0xAA43, Extend), (0xAA4C, 0xAA4C, Extend),
+        (0xAA4D, 0xAA4D, SpacingMark), (0xAA7C, 0xAA7C, Extend), (0xAAB0, 0xAAB0, Extend),
+        (0xAAB2, 0xAAB4, Extend), (0xAAB7, 0xAAB8, Extend), (0xAABE, 0xAABF, Extend),
+        (0xAAC1, 0xAAC1, Extend), (0xAAEB, 0xAAEB, SpacingMark), (0xAAEC, 0xAAED, Extend),
+        (0xAAEE, 0xAAEF, SpacingMark), (0xAAF5, 0xAAF5, SpacingMark), (0xAAF6, 0xAAF6, Extend),
+        (0xABE3, 0xABE4, SpacingMark), (0xABE5, 0xABE5, Extend), (0xABE6, 0xABE7, SpacingMark),
+        (0xABE8, 0xABE8, Extend), (0xABE9, 0xABEA, SpacingMark), (0xABEC, 0xABEC, SpacingMark),
+        (0xABED, 0xABED, Extend), (0xAC00, 0xAC00, Lv), (0xAC01, 0xAC1B, Lvt), (0xAC1C, 0xAC1C, Lv),
+        (0xAC1D, 0xAC37, Lvt), (0xAC38, 0xAC38, Lv), (0xAC39, 0xAC53, Lvt), (0xAC54, 0xAC54, Lv),
+        (0xAC55, 0xAC6F, Lvt), (0xAC70, 0xAC70, Lv), (0xAC71, 0xAC8B, Lvt), (0xAC8C, 0xAC8C, Lv),
+        (0xAC8D, 0xACA7, Lvt), (0xACA8, 0xACA8, Lv), (0xACA9, 0xACC3, Lvt), (0xACC4, 0xACC4, Lv),
+        (0xACC5, 0xACDF, Lvt), (0xACE0, 0xACE0, Lv), (0xACE1, 0xACFB, Lvt), (0xACFC, 0xACFC, Lv),
+        (0xACFD, 0xAD17, Lvt), (0xAD18, 0xAD18, Lv), (0xAD19, 0xAD33, Lvt), (0xAD34, 0xAD34, Lv),
+        (0xAD35, 0xAD4F, Lvt), (0xAD50, 0xAD50, Lv), (0xAD51, 0xAD6B, Lvt), (0xAD6C, 0xAD6C, Lv),
+        (0xAD6D, 0xAD87, Lvt), (0xAD88, 0xAD88, Lv), (0xAD89, 0xADA3, Lvt), (0xADA4, 0xADA4, Lv),
+        (0xADA5, 0xADBF, Lvt), (0xADC0, 0xADC0, Lv), (0xADC1, 0xADDB, Lvt), (0xADDC, 0xADDC, Lv),
+        (0xADDD, 0xADF7, Lvt), (0xADF8, 0xADF8, Lv), (0xADF9, 0xAE13, Lvt), (0xAE14, 0xAE14, Lv),
+        (0xAE15, 0xAE2F, Lvt), (0xAE30, 0xAE30, Lv), (0xAE31, 0xAE4B, Lvt), (0xAE4C, 0xAE4C, Lv),
+        (0xAE4D, 0xAE67, Lvt), (0xAE68, 0xAE68, Lv), (0xAE69, 0xAE83, Lvt), (0xAE84, 0xAE84, Lv),
+        (0xAE85, 0xAE9F, Lvt), (0xAEA0, 0xAEA0, Lv), (0xAEA1, 0xAEBB, Lvt), (0xAEBC, 0xAEBC, Lv),
+        (0xAEBD, 0xAED7, Lvt), (0xAED8, 0xAED8, Lv), (0xAED9, 0xAEF3, Lvt), (0xAEF4, 0xAEF4, Lv),
+        (0xAEF5, 0xAF0F, Lvt), (0xAF10, 0xAF10, Lv), (0xAF11, 0xAF2B, Lvt), (0xAF2C, 0xAF2C, Lv),
+        (0xAF2D, 0xAF47, Lvt), (0xAF48, 0xAF48, Lv), (0xAF49, 0xAF63, Lvt), (0xAF64, 0xAF64, Lv),
+        (0xAF65, 0xAF7F, Lvt), (0xAF80, 0xAF80, Lv), (0xAF81, 0xAF9B, Lvt), (0xAF9C, 0xAF9C, Lv),
+        (0xAF9D, 0xAFB7, Lvt), (0xAFB8, 0xAFB8, Lv), (0xAFB9, 0xAFD3, Lvt), (0xAFD4, 0xAFD4, Lv),
+        (0xAFD5, 0xAFEF, Lvt), (0xAFF0, 0xAFF0, Lv), (0xAFF1, 0xB00B, Lvt), (0xB00C, 0xB00C, Lv),
+        (0xB00D, 0xB027, Lvt), (0xB028, 0xB028, Lv), (0xB029, 0xB043, Lvt), (0xB044, 0xB044, Lv),
+        (0xB045, 0xB05F, Lvt), (0xB060, 0xB060, Lv), (0xB061, 0xB07B, Lvt), (0xB07C, 0xB07C, Lv),
+        (0xB07D, 0xB097, Lvt), (0xB098, 0xB098, Lv), (0xB099, 0xB0B3, Lvt), (0xB0B4, 0xB0B4, Lv),
+        (0xB0B5, 0xB0CF, Lvt), (0xB0D0, 0xB0D0, Lv), (0xB0D1, 0xB0EB, Lvt), (0xB0EC, 0xB0EC, Lv),
+        (0xB0ED, 0xB107, Lvt), (0xB108, 0xB108, Lv), (0xB109, 0xB123, Lvt), (0xB124, 0xB124, Lv),
+        (0xB125, 0xB13F, Lvt), (0xB140, 0xB140, Lv), (0xB141, 0xB15B, Lvt), (0xB15C, 0xB15C, Lv),
+        (0xB15D, 0xB177, Lvt), (0xB178, 0xB178, Lv), (0xB179, 0xB193, Lvt), (0xB194, 0xB194, Lv),
+        (0xB195, 0xB1AF, Lvt), (0xB1B0, 0xB1B0, Lv), (0xB1B1, 0xB1CB, Lvt), (0xB1CC, 0xB1CC, Lv),
+        (0xB1CD, 0xB1E7, Lvt), (0xB1E8, 0xB1E8, Lv), (0xB1E9, 0xB203, Lvt), (0xB204, 0xB204, Lv),
+        (0xB205, 0xB21F, Lvt), (0xB220, 0xB220, Lv), (0xB221, 0xB23B, Lvt), (0xB23C, 0xB23C, Lv),
+        (0xB23D, 0xB257, Lvt), (0xB258, 0xB258, Lv), (0xB259, 0xB273, Lvt), (0xB274, 0xB274, Lv),
+        (0xB275, 0xB28F, Lvt), (0xB290, 0xB290, Lv), (0xB291, 0xB2AB, Lvt), (0xB2AC, 0xB2AC, Lv),
+        (0xB2AD, 0xB2C7, Lvt), (0xB2C8, 0xB2C8, Lv), (0xB2C9, 0xB2E3, Lvt), (0xB2E4, 0xB2E4, Lv),
+        (0xB2E5, 0xB2FF, Lvt), (0xB300, 0xB300, Lv), (0xB301, 0xB31B, Lvt), (0xB31C, 0xB31C, Lv),
+        (0xB31D, 0xB337, Lvt), (0xB338, 0xB338, Lv), (0xB339, 0xB353, Lvt), (0xB354, 0xB354, Lv),
+        (0xB355, 0xB36F, Lvt), (0xB370, 0xB370, Lv), (0xB371, 0xB38B, Lvt), (0xB38C, 0xB38C, Lv),
+        (0xB38D, 0xB3A7, Lvt), (0xB3A8, 0xB3A8, Lv), (0xB3A9, 0xB3C3, Lvt), (0xB3C4, 0xB3C4, Lv),
+        (0xB3C5, 0xB3DF, Lvt), (0xB3E0, 0xB3E0, Lv), (0xB3E1, 0xB3FB, Lvt), (0xB3FC, 0xB3FC, Lv),
+        (0xB3FD, 0xB417, Lvt), (0xB418, 0xB418, Lv), (0xB419, 0xB433, Lvt), (0xB434, 0xB434, Lv),
+        (0xB435, 0xB44F, Lvt), (0xB450, 0xB450, Lv), (0xB451, 0xB46B, Lvt), (0xB46C, 0xB46C, Lv),
+        (0xB46D, 0xB487, Lvt), (0xB488, 0xB488, Lv), (0xB489, 0xB4A3, Lvt), (0xB4A4, 0xB4A4, Lv),
+        (0xB4A5, 0xB4BF, Lvt), (0xB4C0, 0xB4C0, Lv), (0xB4C1, 0xB4DB, Lvt), (0xB4DC, 0xB4DC, Lv),
+        (0xB4DD, 0xB4F7, Lvt), (0xB4F8, 0xB4F8, Lv), (0xB4F9, 0xB513, Lvt), (0xB514, 0xB514, Lv),
+        (0xB515, 0xB52F, Lvt), (0xB530, 0xB530, Lv), (0xB531, 0xB54B, Lvt), (0xB54C, 0xB54C, Lv),
+        (0xB54D, 0xB567, Lvt), (0xB568, 0xB568, Lv), (0xB569, 0xB583, Lvt), (0xB584, 0xB584, Lv),
+        (0xB585, 0xB59F, Lvt), (0xB5A0, 0xB5A0, Lv), (0xB5A1, 0xB5BB, Lvt), (0xB5BC, 0xB5BC, Lv),
+        (0xB5BD, 0xB5D7, Lvt), (0xB5D8, 0xB5D8, Lv), (0xB5D9, 0xB5F3, Lvt), (0xB5F4, 0xB5F4, Lv),
+        (0xB5F5, 0xB60F, Lvt), (0xB610, 0xB610, Lv), (0xB611, 0xB62B, Lvt), (0xB62C, 0xB62C, Lv),
+        (0xB62D, 0xB647, Lvt), (0xB648, 0xB648, Lv), (0xB649, 0xB663, Lvt), (0xB664, 0xB664, Lv),
+        (0xB665, 0xB67F, Lvt), (0xB680, 0xB680, Lv), (0xB681, 0xB69B, Lvt), (0xB69C, 0xB69C, Lv),
+        (0xB69D, 0xB6B7, Lvt), (0xB6B8, 0xB6B8, Lv), (0xB6B9, 0xB6D3, Lvt), (0xB6D4, 0xB6D4, Lv),
+        (0xB6D5, 0xB6EF, Lvt), (0xB6F0, 0xB6F0, Lv), (0xB6F1, 0xB70B, Lvt), (0xB70C, 0xB70C, Lv),
+        (0xB70D, 0xB727, Lvt), (0xB728, 0xB728, Lv), (0xB729, 0xB743, Lvt), (0xB744, 0xB744, Lv),
+        (0xB745, 0xB75F, Lvt), (0xB760, 0xB760, Lv), (0xB761, 0xB77B, Lvt), (0xB77C, 0xB77C, Lv),
+        (0xB77D, 0xB797, Lvt), (0xB798, 0xB798, Lv), (0xB799, 0xB7B3, Lvt), (0xB7B4, 0xB7B4, Lv),
+        (0xB7B5, 0xB7CF, Lvt), (0xB7D0, 0xB7D0, Lv), (0xB7D1, 0xB7EB, Lvt), (0xB7EC, 0xB7EC, Lv),
+        (0xB7ED, 0xB807, Lvt), (0xB808, 0xB808, Lv), (0xB809, 0xB823, Lvt), (0xB824, 0xB824, Lv),
+        (0xB825, 0xB83F, Lvt), (0xB840, 0xB840, Lv), (0xB841, 0xB85B, Lvt), (0xB85C, 0xB85C, Lv),
+        (0xB85D, 0xB877, Lvt), (0xB878, 0xB878, Lv), (0xB879, 0xB893, Lvt), (0xB894, 0xB894, Lv),
+        (0xB895, 0xB8AF, Lvt), (0xB8B0, 0xB8B0, Lv), (0xB8B1, 0xB8CB, Lvt), (0xB8CC, 0xB8CC, Lv),
+        (0xB8CD, 0xB8E7, Lvt), (0xB8E8, 0xB8E8, Lv), (0xB8E9, 0xB903, Lvt), (0xB904, 0xB904, Lv),
+        (0xB905, 0xB91F, Lvt), (0xB920, 0xB920, Lv), (0xB921, 0xB93B, Lvt), (0xB93C, 0xB93C, Lv),
+        (0xB93D, 0xB957, Lvt), (0xB958, 0xB958, Lv), (0xB959, 0xB973, Lvt), (0xB974, 0xB974, Lv),
+        (0xB975, 0xB98F, Lvt), (0xB990, 0xB990, Lv), (0xB991, 0xB9AB, Lvt), (0xB9AC, 0xB9AC, Lv),
+        (0xB9AD, 0xB9C7, Lvt), (0xB9C8, 0xB9C8, Lv), (0xB9C9, 0xB9E3, Lvt), (0xB9E4, 0xB9E4, Lv),
+        (0xB9E5, 0xB9FF, Lvt), (0xBA00, 0xBA00, Lv), (0xBA01, 0xBA1B, Lvt), (0xBA1C, 0xBA1C, Lv),
+        (0xBA1D, 0xBA37, Lvt), (0xBA38, 0xBA38, Lv), (0xBA39, 0xBA53, Lvt), (0xBA54, 0xBA54, Lv),
+        (0xBA55, 0xBA6F, Lvt), (0xBA70, 0xBA70, Lv), (0xBA71, 0xBA8B, Lvt), (0xBA8C, 0xBA8C, Lv),
+        (0xBA8D, 0xBAA7, Lvt), (0xBAA8, 0xBAA8, Lv), (0xBAA9, 0xBAC3, Lvt), (0xBAC4, 0xBAC4, Lv),
+        (0xBAC5, 0xBADF, Lvt), (0xBAE0, 0xBAE0, Lv), (0xBAE1, 0xBAFB, Lvt), (0xBAFC, 0xBAFC, Lv),
+        (0xBAFD, 0xBB17, Lvt), (0xBB18, 0xBB18, Lv), (0xBB19, 0xBB33, Lvt), (0xBB34, 0xBB34, Lv),
+        (0xBB35, 0xBB4F, Lvt), (0xBB50, 0xBB50, Lv), (0xBB51, 0xBB6B, Lvt), (0xBB6C, 0xBB6C, Lv),
+        (0xBB6D, 0xBB87, Lvt), (0xBB88, 0xBB88, Lv), (0xBB89, 0xBBA3, Lvt), (0xBBA4, 0xBBA4, Lv),
+        (0xBBA5, 0xBBBF, Lvt), (0xBBC0, 0xBBC0, Lv), (0xBBC1, 0xBBDB, Lvt), (0xBBDC, 0xBBDC, Lv),
+        (0xBBDD, 0xBBF7, Lvt), (0xBBF8, 0xBBF8, Lv), (0xBBF9, 0xBC13, Lvt), (0xBC14, 0xBC14, Lv),
+        (0xBC15, 0xBC2F, Lvt), (0xBC30, 0xBC30, Lv), (0xBC31, 0xBC4B, Lvt), (0xBC4C, 0xBC4C, Lv),
+        (0xBC4D, 0xBC67, Lvt), (0xBC68, 0xBC68, Lv), (0xBC69, 0xBC83, Lvt), (0xBC84, 0xBC84, Lv),
+        (0xBC85, 0xBC9F, Lvt), (0xBCA0, 0xBCA0, Lv), (0xBCA1, 0xBCBB, Lvt), (0xBCBC, 0xBCBC, Lv),
+        (0xBCBD, 0xBCD7, Lvt), (0xBCD8, 0xBCD8, Lv), (0xBCD9, 0xBCF3, Lvt), (0xBCF4, 0xBCF4, Lv),
+        (0xBCF5, 0xBD0F, Lvt), (0xBD10, 0xBD10, Lv), (0xBD11, 0xBD2B, Lvt), (0xBD2C, 0xBD2C, Lv),
+        (0xBD2D, 0xBD47, Lvt), (0xBD48, 0xBD48, Lv), (0xBD49, 0xBD63, Lvt), (0xBD64, 0xBD64, Lv),
+        (0xBD65, 0xBD7F, Lvt), (0xBD80, 0xBD80, Lv), (0xBD81, 0xBD9B, Lvt), (0xBD9C, 0xBD9C, Lv),
+        (0xBD9D, 0xBDB7, Lvt), (0xBDB8, 0xBDB8, Lv), (0xBDB9, 0xBDD3, Lvt), (0xBDD4, 0xBDD4, Lv),
+        (0xBDD5, 0xBDEF, Lvt), (0xBDF0, 0xBDF0, Lv), (0xBDF1, 0xBE0B, Lvt), (0xBE0C, 0xBE0C, Lv),
+        (0xBE0D, 0xBE27, Lvt), (0xBE28, 0xBE28, Lv), (0xBE29, 0xBE43, Lvt), (0xBE44, 0xBE44, Lv),
+        (0xBE45, 0xBE5F, Lvt), (0xBE60, 0xBE60, Lv), (0xBE61, 0xBE7B, Lvt), (0xBE7C, 0xBE7C, Lv),
+        (0xBE7D, 0xBE97, Lvt), (0xBE98, 0xBE98, Lv), (0xBE99, 0xBEB3, Lvt), (0xBEB4, 0xBEB4, Lv),
+        (0xBEB5, 0xBECF, Lvt), (0xBED0, 0xBED0, Lv), (0xBED1, 0xBEEB, Lvt), (0xBEEC, 0xBEEC, Lv),
+        (0xBEED, 0xBF07, Lvt), (0xBF08, 0xBF08, Lv), (0xBF09, 0xBF23, Lvt), (0xBF24, 0xBF24, Lv),
+        (0xBF25, 0xBF3F, Lvt), (0xBF40, 0xBF40, Lv), (0xBF41, 0xBF5B, Lvt), (0xBF5C, 0xBF5C, Lv),
+        (0xBF5D, 0xBF77, Lvt), (0xBF78, 0xBF78, Lv), (0xBF79, 0xBF93, Lvt), (0xBF94, 0xBF94, Lv),
+        (0xBF95, 0xBFAF, Lvt), (0xBFB0, 0xBFB0, Lv), (0xBFB1, 0xBFCB, Lvt), (0xBFCC, 0xBFCC, Lv),
+        (0xBFCD, 0xBFE7, Lvt), (0xBFE8, 0xBFE8, Lv), (0xBFE9, 0xC003, Lvt), (0xC004, 0xC004, Lv),
+        (0xC005, 0xC01F, Lvt), (0xC020, 0xC020, Lv), (0xC021, 0xC03B, Lvt), (0xC03C, 0xC03C, Lv),
+        (0xC03D, 0xC057, Lvt), (0xC058, 0xC058, Lv), (0xC059, 0xC073, Lvt), (0xC074, 0xC074, Lv),
+        (0xC075, 0xC08F, Lvt), (0xC090, 0xC090, Lv), (0xC091, 0xC0AB, Lvt), (0xC0AC, 0xC0AC, Lv),
+        (0xC0AD, 0xC0C7, Lvt), (0xC0C8, 0xC0C8, Lv), (0xC0C9, 0xC0E3, Lvt), (0xC0E4, 0xC0E4, Lv),
+        (0xC0E5, 0xC0FF, Lvt), (0xC100, 0xC100, Lv), (0xC101, 0xC11B, Lvt), (0xC11C, 0xC11C, Lv),
+        (0xC11D, 0xC137, Lvt), (0xC138, 0xC138, Lv), (0xC139, 0xC153, Lvt), (0xC154, 0xC154, Lv),
+        (0xC155, 0xC16F, Lvt), (0xC170, 0xC170, Lv), (0xC171, 0xC18B, Lvt), (0xC18C, 0xC18C, Lv),
+        (0xC18D, 0xC1A7, Lvt), (0xC1A8, 0xC1A8, Lv), (0xC1A9, 0xC1C3, Lvt), (0xC1C4, 0xC1C4, Lv),
+        (0xC1C5, 0xC1DF, Lvt), (0xC1E0, 0xC1E0, Lv), (0xC1E1, 0xC1FB, Lvt), (0xC1FC, 0xC1FC, Lv),
+        (0xC1FD, 0xC217, Lvt), (0xC218, 0xC218, Lv), (0xC219, 0xC233, Lvt), (0xC234, 0xC234, Lv),
+        (0xC235, 0xC24F, Lvt), (0xC250, 0xC250, Lv), (0xC251, 0xC26B, Lvt), (0xC26C, 0xC26C, Lv),
+        (0xC26D, 0xC287, Lvt), (0xC288, 0xC288, Lv), (0xC289, 0xC2A3, Lvt), (0xC2A4, 0xC2A4, Lv),
+        (0xC2A5, 0xC2BF, Lvt), (0xC2C0, 0xC2C0, Lv), (0xC2C1, 0xC2DB, Lvt), (0xC2DC, 0xC2DC, Lv),
+        (0xC2DD, 0xC2F7, Lvt), (0xC2F8, 0xC2F8, Lv), (0xC2F9, 0xC313, Lvt), (0xC314, 0xC314, Lv),
+        (0xC315, 0xC32F, Lvt), (0xC330, 0xC330, Lv), (0xC331, 0xC34B, Lvt), (0xC34C, 0xC34C, Lv),
+        (0xC34D, 0xC367, Lvt), (0xC368, 0xC368, Lv), (0xC369, 0xC383, Lvt), (0xC384, 0xC384, Lv),
+        (0xC385, 0xC39F, Lvt), (0xC3A0, 0xC3A0, Lv), (0xC3A1, 0xC3BB, Lvt), (0xC3BC, 0xC3BC, Lv),
+        (0xC3BD, 0xC3D7, Lvt), (0xC3D8, 0xC3D8, Lv), (0xC3D9, 0xC3F3, Lvt), (0xC3F4, 0xC3F4, Lv),
+        (0xC3F5, 0xC40F, Lvt), (0xC410, 0xC410, Lv), (0xC411, 0xC42B, Lvt), (0xC42C, 0xC42C, Lv),
+        (0xC42D, 0xC447, Lvt), (0xC448, 0xC448, Lv), (0xC449, 0xC463, Lvt), (0xC464, 0xC464, Lv),
+        (0xC465, 0xC47F, Lvt), (0xC480, 0xC480, Lv), (0xC481, 0xC49B, Lvt), (0xC49C, 0xC49C, Lv),
+        (0xC49D, 0xC4B7, Lvt), (0xC4B8, 0xC4B8, Lv), (0xC4B9, 0xC4D3, Lvt), (0xC4D4, 0xC4D4, Lv),
+        (0xC4D5, 0xC4EF, Lvt), (0xC4F0, 0xC4F0, Lv), (0xC4F1, 0xC50B, Lvt), (0xC50C, 0xC50C, Lv),
+        (0xC50D, 0xC527, Lvt), (0xC528, 0xC528, Lv), (0xC529, 0xC543, Lvt), (0xC544, 0xC544, Lv),
+        (0xC545, 0xC55F, Lvt), (0xC560, 0xC560, Lv), (0xC561, 0xC57B, Lvt), (0xC57C, 0xC57C, Lv),
+        (0xC57D, 0xC597, Lvt), (0xC598, 0xC598, Lv), (0xC599, 0xC5B3, Lvt), (0xC5B4, 0xC5B4, Lv),
+        (0xC5B5, 0xC5CF, Lvt), (0xC5D0, 0xC5D0, Lv), (0xC5D1, 0xC5EB, Lvt), (0xC5EC, 0xC5EC, Lv),
+        (0xC5ED, 0xC607, Lvt), (0xC608, 0xC608, Lv), (0xC609, 0xC623, Lvt), (0xC624, 0xC624, Lv),
+        (0xC625, 0xC63F, Lvt), (0xC640, 0xC640, Lv), (0xC641, 0xC65B, Lvt), (0xC65C, 0xC65C, Lv),
+        (0xC65D, 0xC677, Lvt), (0xC678, 0xC678, Lv), (0xC679, 0xC693, Lvt), (0xC694, 0xC694, Lv),
+        (0xC695, 0xC6AF, Lvt), (0xC6B0, 0xC6B0, Lv), (0xC6B1, 0xC6CB, Lvt), (0xC6CC, 0xC6CC, Lv),
+        (0xC6CD, 0xC6E7, Lvt), (0xC6E8, 0xC6E8, Lv), (0xC6E9, 0xC703, Lvt), (0xC704, 0xC704, Lv),
+        (0xC705, 0xC71F, Lvt), (0xC720, 0xC720, Lv), (0xC721, 0xC73B, Lvt), (0xC73C, 0xC73C, Lv),
+        (0xC73D, 0xC757, Lvt), (0xC758, 0xC758, Lv), (0xC759, 0xC773, Lvt), (0xC774, 0xC774, Lv),
+        (0xC775, 0xC78F, Lvt), (0xC790, 0xC790, Lv), (0xC791, 0xC7AB, Lvt), (0xC7AC, 0xC7AC, Lv),
+        (0xC7AD, 0xC7C7, Lvt), (0xC7C8, 0xC7C8, Lv), (0xC7C9, 0xC7E3, Lvt), (0xC7E4, 0xC7E4, Lv),
+        (0xC7E5, 0xC7FF, Lvt), (0xC800, 0xC800, Lv), (0xC801, 0xC81B, Lvt), (0xC81C, 0xC81C, Lv),
+        (0xC81D, 0xC837, Lvt), (0xC838, 0xC838, Lv), (0xC839, 0xC853, Lvt), (0xC854, 0xC854, Lv),
+        (0xC855, 0xC86F, Lvt), (0xC870, 0xC870, Lv), (0xC871, 0xC88B, Lvt), (0xC88C, 0xC88C, Lv),
+        (0xC88D, 0xC8A7, Lvt), (0xC8A8, 0xC8A8, Lv), (0xC8A9, 0xC8C3, Lvt), (0xC8C4, 0xC8C4, Lv),
+        (0xC8C5, 0xC8DF, Lvt), (0xC8E0, 0xC8E0, Lv), (0xC8E1, 0xC8FB, Lvt), (0xC8FC, 0xC8FC, Lv),
+        (0xC8FD, 0xC917, Lvt), (0xC918, 0xC918, Lv), (0xC919, 0xC933, Lvt), (0xC934, 0xC934, Lv),
+        (0xC935, 0xC94F, Lvt), (0xC950, 0xC950, Lv), (0xC951, 0xC96B, Lvt), (0xC96C, 0xC96C, Lv),
+        (0xC96D, 0xC987, Lvt), (0xC988, 0xC988, Lv), (0xC989, 0xC9A3, Lvt), (0xC9A4, 0xC9A4, Lv),
+        (0xC9A5, 0xC9BF, Lvt), (0xC9C0, 0xC9C0, Lv), (0xC9C1, 0xC9DB, Lvt), (0xC9DC, 0xC9DC, Lv),
+        (0xC9DD, 0xC9F7, Lvt), (0xC9F8, 0xC9F8, Lv), (0xC9F9, 0xCA13, Lvt), (0xCA14, 0xCA14, Lv),
+        (0xCA15, 0xCA2F, Lvt), (0xCA30, 0xCA30, Lv), (0xCA31, 0xCA4B, Lvt), (0xCA4C, 0xCA4C, Lv),
+        (0xCA4D, 0xCA67, Lvt), (0xCA68, 0xCA68, Lv), (0xCA69, 0xCA83, Lvt), (0xCA84, 0xCA84, Lv),
+        (0xCA85, 0xCA9F, Lvt), (0xCAA0, 0xCAA0, Lv), (0xCAA1, 0xCABB, Lvt), (0xCABC, 0xCABC, Lv),
+        (0xCABD, 0xCAD7, Lvt), (0xCAD8, 0xCAD8, Lv), (0xCAD9, 0xCAF3, Lvt), (0xCAF4, 0xCAF4, Lv),
+        (0xCAF5, 0xCB0F, Lvt), (0xCB10, 0xCB10, Lv), (0xCB11, 0xCB2B, Lvt), (0xCB2C, 0xCB2C, Lv),
+        (0xCB2D, 0xCB47, Lvt), (0xCB48, 0xCB48, Lv), (0xCB49, 0xCB63, Lvt), (0xCB64, 0xCB64, Lv),
+        (0xCB65, 0xCB7F, Lvt), (0xCB80, 0xCB80, Lv), (0xCB81, 0xCB9B, Lvt), (0xCB9C, 0xCB9C, Lv),
+        (0xCB9D, 0xCBB7, Lvt), (0xCBB8, 0xCBB8, Lv), (0xCBB9, 0xCBD3, Lvt), (0xCBD4, 0xCBD4, Lv),
+        (0xCBD5, 0xCBEF, Lvt), (0xCBF0, 0xCBF0, Lv), (0xCBF1, 0xCC0B, Lvt), (0xCC0C, 0xCC0C, Lv),
+        (0xCC0D, 0xCC27, Lvt), (0xCC28, 0xCC28, Lv), (0xCC29, 0xCC43, Lvt), (0xCC44, 0xCC44, Lv),
+        (0xCC45, 0xCC5F, Lvt), (0xCC60, 0xCC60, Lv), (0xCC61, 0xCC7B, Lvt), (0xCC7C, 0xCC7C, Lv),
+        (0xCC7D, 0xCC97, Lvt), (0xCC98, 0xCC98, Lv), (0xCC99, 0xCCB3, Lvt), (0xCCB4, 0xCCB4, Lv),
+        (0xCCB5, 0xCCCF, Lvt), (0xCCD0, 0xCCD0, Lv), (0xCCD1, 0xCCEB, Lvt), (0xCCEC, 0xCCEC, Lv),
+        (0xCCED, 0xCD07, Lvt), (0xCD08, 0xCD08, Lv), (0xCD09, 0xCD23, Lvt), (0xCD24, 0xCD24, Lv),
+        (0xCD25, 0xCD3F, Lvt), (0xCD40, 0xCD40, Lv), (0xCD41, 0xCD5B, Lvt), (0xCD5C, 0xCD5C, Lv),
+        (0xCD5D, 0xCD77, Lvt), (0xCD78, 0xCD78, Lv), (0xCD79, 0xCD93, Lvt), (0xCD94, 0xCD94, Lv),
+        (0xCD95, 0xCDAF, Lvt), (0xCDB0, 0xCDB0, Lv), (0xCDB1, 0xCDCB, Lvt), (0xCDCC, 0xCDCC, Lv),
+        (0xCDCD, 0xCDE7, Lvt), (0xCDE8, 0xCDE8, Lv), (0xCDE9, 0xCE03, Lvt), (0xCE04, 0xCE04, Lv),
+        (0xCE05, 0xCE1F, Lvt), (0xCE20, 0xCE20, Lv), (0xCE21, 0xCE3B, Lvt), (0xCE3C, 0xCE3C, Lv),
+        (0xCE3D, 0xCE57, Lvt), (0xCE58, 0xCE58, Lv), (0xCE59, 0xCE73, Lvt), (0xCE74, 0xCE74, Lv),
+        (0xCE75, 0xCE8F, Lvt), (0xCE90, 0xCE90, Lv), (0xCE91, 0xCEAB, Lvt), (0xCEAC, 0xCEAC, Lv),
+        (0xCEAD, 0xCEC7, Lvt), (0xCEC8, 0xCEC8, Lv), (0xCEC9, 0xCEE3, Lvt), (0xCEE4, 0xCEE4, Lv),
+        (0xCEE5, 0xCEFF, Lvt), (0xCF00, 0xCF00, Lv), (0xCF01, 0xCF1B, Lvt), (0xCF1C, 0xCF1C, Lv),
+        (0xCF1D, 0xCF37, Lvt), (0xCF38, 0xCF38, Lv), (0xCF39, 0xCF53, Lvt), (0xCF54, 0xCF54, Lv),
+        (0xCF55, 0xCF6F, Lvt), (0xCF70, 0xCF70, Lv), (0xCF71, 0xCF8B, Lvt), (0xCF8C, 0xCF8C, Lv),
+        (0xCF8D, 0xCFA7, Lvt), (0xCFA8, 0xCFA8, Lv), (0xCFA9, 0xCFC3, Lvt), (0xCFC4, 0xCFC4, Lv),
+        (0xCFC5, 0xCFDF, Lvt), (0xCFE0, 0xCFE0, Lv), (0xCFE1, 0xCFFB, Lvt), (0xCFFC, 0xCFFC, Lv),
+        (0xCFFD, 0xD017, Lvt), (0xD018, 0xD018, Lv), (0xD019, 0xD033, Lvt), (0xD034, 0xD034, Lv),
+        (0xD035, 0xD04F, Lvt), (0xD050, 0xD050, Lv), (0xD051, 0xD06B, Lvt), (0xD06C, 0xD06C, Lv),
+        (0xD06D, 0xD087, Lvt), (0xD088, 0xD088, Lv), (0xD089, 0xD0A3, Lvt), (0xD0A4, 0xD0A4, Lv),
+        (0xD0A5, 0xD0BF, Lvt), (0xD0C0, 0xD0C0, Lv), (0xD0C1, 0xD0DB, Lvt), (0xD0DC, 0xD0DC, Lv),
+        (0xD0DD, 0xD0F7, Lvt), (0xD0F8, 0xD0F8, Lv), (0xD0F9, 0xD113, Lvt), (0xD114, 0xD114, Lv),
+        (0xD115, 0xD12F, Lvt), (0xD130, 0xD130, Lv), (0xD131, 0xD14B, Lvt), (0xD14C, 0xD14C, Lv),
+        (0xD14D, 0xD167, Lvt), (0xD168, 0xD168, Lv), (0xD169, 0xD183, Lvt), (0xD184, 0xD184, Lv),
+        (0xD185, 0xD19F, Lvt), (0xD1A0, 0xD1A0, Lv), (0xD1A1, 0xD1BB, Lvt), (0xD1BC, 0xD1BC, Lv),
+        (0xD1BD, 0xD1D7, Lvt), (0xD1D8, 0xD1D8, Lv), (0xD1D9, 0xD1F3, Lvt), (0xD1F4, 0xD1F4, Lv),
+        (0xD1F5, 0xD20F, Lvt), (0xD210, 0xD210, Lv), (0xD211, 0xD22B, Lvt), (0xD22C, 0xD22C, Lv),
+        (0xD22D, 0xD247, Lvt), (0xD248, 0xD248, Lv), (0xD249, 0xD263, Lvt), (0xD264, 0xD264, Lv),
+        (0xD265, 0xD27F, Lvt), (0xD280, 0xD280, Lv), (0xD281, 0xD29B, Lvt), (0xD29C, 0xD29C, Lv),
+        (0xD29D, 0xD2B7, Lvt), (0xD2B8, 0xD2B8, Lv), (0xD2B9, 0xD2D3, Lvt), (0xD2D4, 0xD2D4, Lv),
+        (0xD2D5, 0xD2EF, Lvt), (0xD2F0, 0xD2F0, Lv), (0xD2F1, 0xD30B, Lvt), (0xD30C, 0xD30C, Lv),
+        (0xD30D, 0xD327, Lvt), (0xD328, 0xD328, Lv), (0xD329, 0xD343, Lvt), (0xD344, 0xD344, Lv),
+        (0xD345, 0xD35F, Lvt), (0xD360, 0xD360, Lv), (0xD361, 0xD37B, Lvt), (0xD37C, 0xD37C, Lv),
+        (0xD37D, 0xD397, Lvt), (0xD398, 0xD398, Lv), (0xD399, 0xD3B3, Lvt), (0xD3B4, 0xD3B4, Lv),
+        (0xD3B5, 0xD3CF, Lvt), (0xD3D0, 0xD3D0, Lv), (0xD3D1, 0xD3EB, Lvt), (0xD3EC, 0xD3EC, Lv),
+        (0xD3ED, 0xD407, Lvt), (0xD408, 0xD408, Lv), (0xD409, 0xD423, Lvt), (0xD424, 0xD424, Lv),
+        (0xD425, 0xD43F, Lvt), (0xD440, 0xD440, Lv), (0xD441, 0xD45B, Lvt), (0xD45C, 0xD45C, Lv),
+        (0xD45D, 0xD477, Lvt), (0xD478, 0xD478, Lv), (0xD479, 0xD493, Lvt), (0xD494, 0xD494, Lv),
+        (0xD495, 0xD4AF, Lvt), (0xD4B0, 0xD4B0, Lv), (0xD4B1, 0xD4CB, Lvt), (0xD4CC, 0xD4CC, Lv),
+        (0xD4CD, 0xD4E7, Lvt), (0xD4E8, 0xD4E8, Lv), (0xD4E9, 0xD503, Lvt), (0xD504, 0xD504, Lv),
+        (0xD505, 0xD51F, Lvt), (0xD520, 0xD520, Lv), (0xD521, 0xD53B, Lvt), (0xD53C, 0xD53C, Lv),
+        (0xD53D, 0xD557, Lvt), (0xD558, 0xD558, Lv), (0xD559, 0xD573, Lvt), (0xD574, 0xD574, Lv),
+        (0xD575, 0xD58F, Lvt), (0xD590, 0xD590, Lv), (0xD591, 0xD5AB, Lvt), (0xD5AC, 0xD5AC, Lv),
+        (0xD5AD, 0xD5C7, Lvt), (0xD5C8, 0xD5C8, Lv), (0xD5C9, 0xD5E3, Lvt), (0xD5E4, 0xD5E4, Lv),
+        (0xD5E5, 0xD5FF, Lvt), (0xD600, 0xD600, Lv), (0xD601, 0xD61B, Lvt), (0xD61C, 0xD61C, Lv),
+        (0xD61D, 0xD637, Lvt), (0xD638, 0xD638, Lv), (0xD639, 0xD653, Lvt), (0xD654, 0xD654, Lv),
+        (0xD655, 0xD66F, Lvt), (0xD670, 0xD670, Lv), (0xD671, 0xD68B, Lvt), (0xD68C, 0xD68C, Lv),
+        (0xD68D, 0xD6A7, Lvt), (0xD6A8, 0xD6A8, Lv), (0xD6A9, 0xD6C3, Lvt), (0xD6C4, 0xD6C4, Lv),
+        (0xD6C5, 0xD6DF, Lvt), (0xD6E0, 0xD6E0, Lv), (0xD6E1, 0xD6FB, Lvt), (0xD6FC, 0xD6FC, Lv),
+        (0xD6FD, 0xD717, Lvt), (0xD718, 0xD718, Lv), (0xD719, 0xD733, Lvt), (0xD734, 0xD734, Lv),
+        (0xD735, 0xD74F, Lvt), (0xD750, 0xD750, Lv), (0xD751, 0xD76B, Lvt), (0xD76C, 0xD76C, Lv),
+        (0xD76D, 0xD787, Lvt), (0xD788, 0xD788, Lv), (0xD789, 0xD7A3, Lvt), (0xD7B0, 0xD7C6, V),
+        (0xD7CB, 0xD7FB, T), (0xFB1E, 0xFB1E, Extend), (0xFE00, 0xFE0F, Extend),
+        (0xFE20, 0xFE2F, Extend), (0xFEFF, 0xFEFF, Control), (0xFF9E, 0xFF9F, Extend),
+        (0xFFF0, 0xFFFB, Control), (0x101FD, 0x101FD, Extend), (0x102E0, 0x102E0, Extend),
+        (0x10376, 0x1037A, Extend), (0x10A01, 0x10A03, Extend), (0x10A05, 0x10A06, Extend),
+        (0x10A0C, 0x10A0F, Extend), (0x10A38, 0x10A3A, Extend), (0x10A3F, 0x10A3F, Extend),
+        (0x10AE5, 0x10AE6, Extend), (0x10D24, 0x10D27, Extend), (0x10EAB, 0x10EAC, Extend),
+        (0x10EFD, 0x10EFF, Extend), (0x10F46, 0x10F50, Extend), (0x10F82, 0x10F85, Extend),
+        (0x11000, 0x11000, SpacingMark), (0x11001, 0x11001, Extend), (0x11002, 0x11002, SpacingMark),
+        (0x11038, 0x11046, Extend), (0x11070, 0x11070, Extend), (0x11073, 0x11074, Extend),
+        (0x1107F, 0x11081, Extend), (0x11082, 0x11082, SpacingMark), (0x110B0, 0x110B2, SpacingMark),
+        (0x110B3, 0x110B6, Extend), (0x110B7, 0x110B8, SpacingMark), (0x110B9, 0x110BA, Extend),
+        (0x110BD, 0x110BD, Prepend), (0x110C2, 0x110C2, Extend), (0x110CD, 0x110CD, Prepend),
+        (0x11100, 0x11102, Extend), (0x11127, 0x1112B, Extend), (0x1112C, 0x1112C, SpacingMark),
+        (0x1112D, 0x11134, Extend), (0x11145, 0x11146, SpacingMark), (0x11173, 0x11173, Extend),
+        (0x11180, 0x11181, Extend), (0x11182, 0x11182, SpacingMark), (0x111B3, 0x111B5, SpacingMark),
+        (0x111B6, 0x111BE, Extend), (0x111BF, 0x111C0, SpacingMark), (0x111C2, 0x111C3, Prepend),
+        (0x111C9, 0x111CC, Extend), (0x111CE, 0x111CE, SpacingMark), (0x111CF, 0x111CF, Extend),
+        (0x1122C, 0x1122E, SpacingMark), (0x1122F, 0x11231, Extend), (0x11232, 0x11233, SpacingMark),
+        (0x11234, 0x11234, Extend), (0x11235, 0x11235, SpacingMark), (0x11236, 0x11237, Extend),
+        (0x1123E, 0x1123E, Extend), (0x11241, 0x11241, Extend), (0x112DF, 0x112DF, Extend),
+        (0x112E0, 0x112E2, SpacingMark), (0x112E3, 0x112EA, Extend), (0x11300, 0x11301, Extend),
+        (0x11302, 0x11303, SpacingMark), (0x1133B, 0x1133C, Extend), (0x1133E, 0x1133E, Extend),
+        (0x1133F, 0x1133F, SpacingMark), (0x11340, 0x11340, Extend), (0x11341, 0x11344, SpacingMark),
+        (0x11347, 0x11348, SpacingMark), (0x1134B, 0x1134D, SpacingMark), (0x11357, 0x11357, Extend),
+        (0x11362, 0x11363, SpacingMark), (0x11366, 0x1136C, Extend), (0x11370, 0x11374, Extend),
+        (0x11435, 0x11437, SpacingMark), (0x11438, 0x1143F, Extend), (0x11440, 0x11441, SpacingMark),
+        (0x11442, 0x11444, Extend), (0x11445, 0x11445, SpacingMark), (0x11446, 0x11446, Extend),
+        (0x1145E, 0x1145E, Extend), (0x114B0, 0x114B0, Extend), (0x114B1, 0x114B2, SpacingMark),
+        (0x114B3, 0x114B8, Extend), (0x114B9, 0x114B9, SpacingMark), (0x114BA, 0x114BA, Extend),
+        (0x114BB, 0x114BC, SpacingMark), (0x114BD, 0x114BD, Extend), (0x114BE, 0x114BE, SpacingMark),
+        (0x114BF, 0x114C0, Extend), (0x114C1, 0x114C1, SpacingMark), (0x114C2, 0x114C3, Extend),
+        (0x115AF, 0x115AF, Extend), (0x115B0, 0x115B1, SpacingMark), (0x115B2, 0x115B5, Extend),
+        (0x115B8, 0x115BB, SpacingMark), (0x115BC, 0x115BD, Extend), (0x115BE, 0x115BE, SpacingMark),
+        (0x115BF, 0x115C0, Extend), (0x115DC, 0x115DD, Extend), (0x11630, 0x11632, SpacingMark),
+        (0x11633, 0x1163A, Extend), (0x1163B, 0x1163C, SpacingMark), (0x1163D, 0x1163D, Extend),
+        (0x1163E, 0x1163E, SpacingMark), (0x1163F, 0x11640, Extend), (0x116AB, 0x116AB, Extend),
+        (0x116AC, 0x116AC, SpacingMark), (0x116AD, 0x116AD, Extend), (0x116AE, 0x116AF, SpacingMark),
+        (0x116B0, 0x116B5, Extend), (0x116B6, 0x116B6, SpacingMark), (0x116B7, 0x116B7, Extend),
+        (0x1171D, 0x1171F, Extend), (0x11722, 0x11725, Extend), (0x11726, 0x11726, SpacingMark),
+        (0x11727, 0x1172B, Extend), (0x1182C, 0x1182E, SpacingMark), (0x1182F, 0x11837, Extend),
+        (0x11838, 0x11838, SpacingMark), (0x11839, 0x1183A, Extend), (0x11930, 0x11930, Extend),
+        (0x11931, 0x11935, SpacingMark), (0x11937, 0x11938, SpacingMark), (0x1193B, 0x1193C, Extend),
+        (0x1193D, 0x1193D, SpacingMark), (0x1193E, 0x1193E, Extend), (0x1193F, 0x1193F, Prepend),
+        (0x11940, 0x11940, SpacingMark), (0x11941, 0x11941, Prepend), (0x11942, 0x11942, SpacingMark),
+        (0x11943, 0x11943, Extend), (0x119D1, 0x119D3, SpacingMark), (0x119D4, 0x119D7, Extend),
+        (0x119DA, 0x119DB, Extend), (0x119DC, 0x119DF, SpacingMark), (0x119E0, 0x119E0, Extend),
+        (0x119E4, 0x119E4, SpacingMark), (0x11A01, 0x11A0A, Extend), (0x11A33, 0x11A38, Extend),
+        (0x11A39, 0x11A39, SpacingMark), (0x11A3A, 0x11A3A, Prepend), (0x11A3B, 0x11A3E, Extend),
+        (0x11A47, 0x11A47, Extend), (0x11A51, 0x11A56, Extend), (0x11A57, 0x11A58, SpacingMark),
+        (0x11A59, 0x11A5B, Extend), (0x11A84, 0x11A89, Prepend), (0x11A8A, 0x11A96, Extend),
+        (0x11A97, 0x11A97, SpacingMark), (0x11A98, 0x11A99, Extend), (0x11C2F, 0x11C2F, SpacingMark),
+        (0x11C30, 0x11C36, Extend), (0x11C38, 0x11C3D, Extend), (0x11C3E, 0x11C3E, SpacingMark),
+        (0x11C3F, 0x11C3F, Extend), (0x11C92, 0x11CA7, Extend), (0x11CA9, 0x11CA9, SpacingMark),
+        (0x11CAA, 0x11CB0, Extend), (0x11CB1, 0x11CB1, SpacingMark), (0x11CB2, 0x11CB3, Extend),
+        (0x11CB4, 0x11CB4, SpacingMark), (0x11CB5, 0x11CB6, Extend), (0x11D31, 0x11D36, Extend),
+        (0x11D3A, 0x11D3A, Extend), (0x11D3C, 0x11D3D, Extend), (0x11D3F, 0x11D45, Extend),
+        (0x11D46, 0x11D46, Prepend), (0x11D47, 0x11D47, Extend), (0x11D8A, 0x11D8E, SpacingMark),
+        (0x11D90, 0x11D91, Extend), (0x11D93, 0x11D94, SpacingMark), (0x11D95, 0x11D95, Extend),
+        (0x11D96, 0x11D96, SpacingMark), (0x11D97, 0x11D97, Extend), (0x11EF3, 0x11EF4, Extend),
+        (0x11EF5, 0x11EF6, SpacingMark), (0x11F00, 0x11F01, Extend), (0x11F02, 0x11F02, Prepend),
+        (0x11F03, 0x11F03, SpacingMark), (0x11F34, 0x11F35, SpacingMark), (0x11F36, 0x11F3A, Extend),
+        (0x11F3E, 0x11F3F, SpacingMark), (0x11F40, 0x11F40, Extend), (0x11F41, 0x11F41, SpacingMark),
+        (0x11F42, 0x11F42, Extend), (0x13430, 0x1343F, Control), (0x13440, 0x13440, Extend),
+        (0x13447, 0x13455, Extend), (0x16AF0, 0x16AF4, Extend), (0x16B30, 0x16B36, Extend),
+        (0x16F4F, 0x16F4F, Extend), (0x16F51, 0x16F87, SpacingMark), (0x16F8F, 0x16F92, Extend),
+        (0x16FE4, 0x16FE4, Extend), (0x16FF0, 0x16FF1, SpacingMark), (0x1BC9D, 0x1BC9E, Extend),
+        (0x1BCA0, 0x1BCA3, Control), (0x1CF00, 0x1CF2D, Extend), (0x1CF30, 0x1CF46, Extend),
+        (0x1D165, 0x1D165, Extend), (0x1D166, 0x1D166, SpacingMark), (0x1D167, 0x1D169, Extend),
+        (0x1D16D, 0x1D16D, SpacingMark), (0x1D16E, 0x1D172, Extend), (0x1D173, 0x1D17A, Control),
+        (0x1D17B, 0x1D182, Extend), (0x1D185, 0x1D18B, Extend), (0x1D1AA, 0x1D1AD, Extend),
+        (0x1D242, 0x1D244, Extend), (0x1DA00, 0x1DA36, Extend), (0x1DA3B, 0x1DA6C, Extend),
+        (0x1DA75, 0x1DA75, Extend), (0x1DA84, 0x1DA84, Extend), (0x1DA9B, 0x1DA9F, Extend),
+        (0x1DAA1, 0x1DAAF, Extend), (0x1E000, 0x1E006, Extend), (0x1E008, 0x1E018, Extend),
+        (0x1E01B, 0x1E021, Extend), (0x1E023, 0x1E024, Extend), (0x1E026, 0x1E02A, Extend),
+        (0x1E08F, 0x1E08F, Extend), (0x1E130, 0x1E136, Extend), (0x1E2AE, 0x1E2AE, Extend),
+        (0x1E2EC, 0x1E2EF, Extend), (0x1E4EC, 0x1E4EF, Extend), (0x1E8D0, 0x1E8D6, Extend),
+        (0x1E944, 0x1E94A, Extend), (0x1F000, 0x1F0FF, ExtendedPictographic),
+        (0x1F10D, 0x1F10F, ExtendedPictographic), (0x1F12F, 0x1F12F, ExtendedPictographic),
+        (0x1F16C, 0x1F171, ExtendedPictographic), (0x1F17E, 0x1F17F, ExtendedPictographic),
+        (0x1F18E, 0x1F18E, ExtendedPictographic), (0x1F191, 0x1F19A, ExtendedPictographic),
+        (0x1F1AD, 0x1F1E5, ExtendedPictographic), (0x1F1E6, 0x1F1FF, RegionalIndicator),
+        (0x1F201, 0x1F20F, ExtendedPictographic), (0x1F21A, 0x1F21A, ExtendedPictographic),
+        (0x1F22F, 0x1F22F, ExtendedPictographic), (0x1F232, 0x1F23A, ExtendedPictographic),
+        (0x1F23C, 0x1F23F, ExtendedPictographic), (0x1F249, 0x1F3FA, ExtendedPictographic),
+        (0x1F3FB, 0x1F3FF, Extend), (0x1F400, 0x1F53D, ExtendedPictographic),
+        (0x1F546, 0x1F64F, ExtendedPictographic), (0x1F680, 0x1F6FF, ExtendedPictographic),
+        (0x1F774, 0x1F77F, ExtendedPictographic), (0x1F7D5, 0x1F7FF, ExtendedPictographic),
+        (0x1F80C, 0x1F80F, ExtendedPictographic), (0x1F848, 0x1F84F, ExtendedPictographic),
+        (0x1F85A, 0x1F85F, ExtendedPictographic), (0x1F888, 0x1F88F, ExtendedPictographic),
+        (0x1F8AE, 0x1F8FF, ExtendedPictographic), (0x1F90C, 0x1F93A, ExtendedPictographic),
+        (0x1F93C, 0x1F945, ExtendedPictographic), (0x1F947, 0x1FAFF, ExtendedPictographic),
+        (0x1FC00, 0x1FFFD, ExtendedPictographic), (0xE0000, 0xE001F, Control),
+        (0xE0020, 0xE007F, Extend), (0xE0080, 0xE00FF, Control), (0xE0100, 0xE01EF, Extend),
+        (0xE01F0, 0xE0FFF, Control),
+    ];
+}
+
+/// Word_Break property values of UAX #29.
+pub mod word {
+    /// A Word_Break property value.  Characters not in the table are `Other`.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum WordBreak {
+        ALetter,
+        Cr,
+        DoubleQuote,
+        Extend,
+        ExtendNumLet,
+        Format,
+        HebrewLetter,
+        Katakana,
+        Lf,
+        MidLetter,
+        MidNum,
+        MidNumLet,
+        Newline,
+        Numeric,
+        RegionalIndicator,
+        SingleQuote,
+        WSegSpace,
+        Zwj,
+        Other,
+    }
+    use self::WordBreak::*;
+
+    /// The Word_Break value of every code point which has one.
+    #[rustfmt::skip]
+    pub const WORD_BREAK: &[(u32, u32, WordBreak)] = &[
+        (0xA, 0xA, Lf), (0xB, 0xC, Newline), (0xD, 0xD, Cr), (0x20, 0x20, WSegSpace),
+        (0x22, 0x22, DoubleQuote), (0x27, 0x27, SingleQuote), (0x2C, 0x2C, MidNum),
+        (0x2E, 0x2E, MidNumLet), (0x30, 0x39, Numeric), (0x3A, 0x3A, MidLetter), (0x3B, 0x3B, MidNum),
+        (0x41, 0x5A, ALetter), (0x5F, 0x5F, ExtendNumLet), (0x61, 0x7A, ALetter),
+        (0x85, 0x85, Newline), (0xAA, 0xAA, ALetter), (0xAD, 0xAD, Format), (0xB5, 0xB5, ALetter),
+        (0xB7, 0xB7, MidLetter), (0xBA, 0xBA, ALetter), (0xC0, 0xD6, ALetter), (0xD8, 0xF6, ALetter),
+        (0xF8, 0x2D7, ALetter), (0x2DE, 0x2FF, ALetter), (0x300, 0x36F, Extend),
+        (0x370, 0x374, ALetter), (0x376, 0x377, ALetter), (0x37A, 0x37D, ALetter),
+        (0x37E, 0x37E, MidNum), (0x37F, 0x37F, ALetter), (0x386, 0x386, ALetter),
+        (0x387, 0x387, MidLetter), (0x388, 0x38A, ALetter), (0x38C, 0x38C, ALetter),
+        (0x38E, 0x3A1, ALetter), (0x3A3, 0x3F5, ALetter), (0x3F7, 0x481, ALetter),
+        (0x483, 0x489, Extend), (0x48A, 0x52F, ALetter), (0x531, 0x556, ALetter),
+        (0x559, 0x55C, ALetter), (0x55E, 0x55E, ALetter), (0x55F, 0x55F, MidLetter),
+        (0x560, 0x588, ALetter), (0x589, 0x589, MidNum), (0x58A, 0x58A, ALetter),
+        (0x591, 0x5BD, Extend), (0x5BF, 0x5BF, Extend), (0x5C1, 0x5C2, Extend), (0x5C4, 0x5C5, Extend),
+        (0x5C7, 0x5C7, Extend), (0x5D0, 0x5EA, HebrewLetter), (0x5EF, 0x5F2, HebrewLetter),
+        (0x5F3, 0x5F3, ALetter), (0x5F4, 0x5F4, MidLetter), (0x600, 0x605, Numeric),
+        (0x60C, 0x60D, MidNum), (0x610, 0x61A, Extend), (0x61C, 0x61C, Format),
+        (0x620, 0x64A, ALetter), (0x64B, 0x65F, Extend), (0x660, 0x669, Numeric),
+        (0x66B, 0x66B, Numeric), (0x66C, 0x66C, MidNum), (0x66E, 0x66F, ALetter),
+        (0x670, 0x670, Extend), (0x671, 0x6D3, ALetter), (0x6D5, 0x6D5, ALetter),
+        (0x6D6, 0x6DC, Extend), (0x6DD, 0x6DD, Numeric), (0x6DF, 0x6E4, Extend),
+        (0x6E5, 0x6E6, ALetter), (0x6E7, 0x6E8, Extend), (0x6EA, 0x6ED, Extend),
+        (0x6EE, 0x6EF, ALetter), (0x6F0, 0x6F9, Numeric), (0x6FA, 0x6FC, ALetter),
+        (0x6FF, 0x6FF, ALetter), (0x70F, 0x710, ALetter), (0x711, 0x711, Extend),
+        (0x712, 0x72F, ALetter), (0x730, 0x74A, Extend), (0x74D, 0x7A5, ALetter),
+        (0x7A6, 0x7B0, Extend), (0x7B1, 0x7B1, ALetter), (0x7C0, 0x7C9, Numeric),
+        (0x7CA, 0x7EA, ALetter), (0x7EB, 0x7F3, Extend), (0x7F4, 0x7F5, ALetter),
+        (0x7F8, 0x7F8, MidNum), (0x7FA, 0x7FA, ALetter), (0x7FD, 0x7FD, Extend),
+        (0x800, 0x815, ALetter), (0x816, 0x819, Extend), (0x81A, 0x81A, ALetter),
+        (0x81B, 0x823, Extend), (0x824, 0x824, ALetter), (0x825, 0x827, Extend),
+        (0x828, 0x828, ALetter), (0x829, 0x82D, Extend), (0x840, 0x858, ALetter),
+        (0x859, 0x85B, Extend), (0x860, 0x86A, ALetter), (0x870, 0x887, ALetter),
+        (0x889, 0x88E, ALetter), (0x890, 0x891, Numeric), (0x898, 0x89F, Extend),
+        (0x8A0, 0x8C9, ALetter), (0x8CA, 0x8E1, Extend), (0x8E2, 0x8E2, Numeric),
+        (0x8E3, 0x903, Extend), (0x904, 0x939, ALetter), (0x93A, 0x93C, Extend),
+        (0x93D, 0x93D, ALetter), (0x93E, 0x94F, Extend), (0x950, 0x950, ALetter),
+        (0x951, 0x957, Extend), (0x958, 0x961, ALetter), (0x962, 0x963, Extend),
+        (0x966, 0x96F, Numeric), (0x971, 0x980, ALetter), (0x981, 0x983, Extend),
+        (0x985, 0x98C, ALetter), (0x98F, 0x990, ALetter), (0x993, 0x9A8, ALetter),
+        (0x9AA, 0x9B0, ALetter), (0x9B2, 0x9B2, ALetter), (0x9B6, 0x9B9, ALetter),
+        (0x9BC, 0x9BC, Extend), (0x9BD, 0x9BD, ALetter), (0x9BE, 0x9C4, Extend),
+        (0x9C7, 0x9C8, Extend), (0x9CB, 0x9CD, Extend), (0x9CE, 0x9CE, ALetter),
+        (0x9D7, 0x9D7, Extend), (0x9DC, 0x9DD, ALetter), (0x9DF, 0x9E1, ALetter),
+        (0x9E2, 0x9E3, Extend), (0x9E6, 0x9EF, Numeric), (0x9F0, 0x9F1, ALetter),
+        (0x9FC, 0x9FC, ALetter), (0x9FE, 0x9FE, Extend), (0xA01, 0xA03, Extend),
+        (0xA05, 0xA0A, ALetter), (0xA0F, 0xA10, ALetter), (0xA13, 0xA28, ALetter),
+        (0xA2A, 0xA30, ALetter), (0xA32, 0xA33, ALetter), (0xA35, 0xA36, ALetter),
+        (0xA38, 0xA39, ALetter), (0xA3C, 0xA3C, Extend), (0xA3E, 0xA42, Extend),
+        (0xA47, 0xA48, Extend), (0xA4B, 0xA4D, Extend), (0xA51, 0xA51, Extend),
+        (0xA59, 0xA5C, ALetter), (0xA5E, 0xA5E, ALetter), (0xA66, 0xA6F, Numeric),
+        (0xA70, 0xA71, Extend), (0xA72, 0xA74, ALetter), (0xA75, 0xA75, Extend),
+        (0xA81, 0xA83, Extend), (0xA85, 0xA8D, ALetter), (0xA8F, 0xA91, ALetter),
+        (0xA93, 0xAA8, ALetter), (0xAAA, 0xAB0, ALetter), (0xAB2, 0xAB3, ALetter),
+        (0xAB5, 0xAB9, ALetter), (0xABC, 0xABC, Extend), (0xABD, 0xABD, ALetter),
+        (0xABE, 0xAC5, Extend), (0xAC7, 0xAC9, Extend), (0xACB, 0xACD, Extend),
+        (0xAD0, 0xAD0, ALetter), (0xAE0, 0xAE1, ALetter), (0xAE2, 0xAE3, Extend),
+        (0xAE6, 0xAEF, Numeric), (0xAF9, 0xAF9, ALetter), (0xAFA, 0xAFF, Extend),
+        (0xB01, 0xB03, Extend), (0xB05, 0xB0C, ALetter), (0xB0F, 0xB10, ALetter),
+        (0xB13, 0xB28, ALetter), (0xB2A, 0xB30, ALetter), (0xB32, 0xB33, ALetter),
+        (0xB35, 0xB39, ALetter), (0xB3C, 0xB3C, Extend), (0xB3D, 0xB3D, ALetter),
+        (0xB3E, 0xB44, Extend), (0xB47, 0xB48, Extend), (0xB4B, 0xB4D, Extend), (0xB55, 0xB57, Extend),
+        (0xB5C, 0xB5D, ALetter), (0xB5F, 0xB61, ALetter), (0xB62, 0xB63, Extend),
+        (0xB66, 0xB6F, Numeric), (0xB71, 0xB71, ALetter), (0xB82, 0xB82, Extend),
+        (0xB83, 0xB83, ALetter), (0xB85, 0xB8A, ALetter), (0xB8E, 0xB90, ALetter),
+        (0xB92, 0xB95, ALetter), (0xB99, 0xB9A, ALetter), (0xB9C, 0xB9C, ALetter),
+        (0xB9E, 0xB9F, ALetter), (0xBA3, 0xBA4, ALetter), (0xBA8, 0xBAA, ALetter),
+        (0xBAE, 0xBB9, ALetter), (0xBBE, 0xBC2, Extend), (0xBC6, 0xBC8, Extend),
+        (0xBCA, 0xBCD, Extend), (0xBD0, 0xBD0, ALetter), (0xBD7, 0xBD7, Extend),
+        (0xBE6, 0xBEF, Numeric), (0xC00, 0xC04, Extend), (0xC05, 0xC0C, ALetter),
+        (0xC0E, 0xC10, ALetter), (0xC12, 0xC28, ALetter), (0xC2A, 0xC39, ALetter),
+        (0xC3C, 0xC3C, Extend), (0xC3D, 0xC3D, ALetter), (0xC3E, 0xC44, Extend),
+        (0xC46, 0xC48, Extend), (0xC4A, 0xC4D, Extend), (0xC55, 0xC56, Extend),
+        (0xC58, 0xC5A, ALetter), (0xC5D, 0xC5D, ALetter), (0xC60, 0xC61, ALetter),
+        (0xC62, 0xC63, Extend), (0xC66, 0xC6F, Numeric), (0xC80, 0xC80, ALetter),
+        (0xC81, 0xC83, Extend), (0xC85, 0xC8C, ALetter), (0xC8E, 0xC90, ALetter),
+        (0xC92, 0xCA8, ALetter), (0xCAA, 0xCB3, ALetter), (0xCB5, 0xCB9, ALetter),
+        (0xCBC, 0xCBC, Extend), (0xCBD, 0xCBD, ALetter), (0xCBE, 0xCC4, Extend),
+        (0xCC6, 0xCC8, Extend), (0xCCA, 0xCCD, Extend), (0xCD5, 0xCD6, Extend),
+        (0xCDD, 0xCDE, ALetter), (0xCE0, 0xCE1, ALetter), (0xCE2, 0xCE3, Extend),
+        (0xCE6, 0xCEF, Numeric), (0xCF1, 0xCF2, ALetter), (0xCF3, 0xCF3, Extend),
+        (0xD00, 0xD03, Extend), (0xD04, 0xD0C, ALetter), (0xD0E, 0xD10, ALetter),
+        (0xD12, 0xD3A, ALetter), (0xD3B, 0xD3C, Extend), (0xD3D, 0xD3D, ALetter),
+        (0xD3E, 0xD44, Extend), (0xD46, 0xD48, Extend), (0xD4A, 0xD4D, Extend),
+        (0xD4E, 0xD4E, ALetter), (0xD54, 0xD56, ALetter), (0xD57, 0xD57, Extend),
+        (0xD5F, 0xD61, ALetter), (0xD62, 0xD63, Extend), (0xD66, 0xD6F, Numeric),
+        (0xD7A, 0xD7F, ALetter), (0xD81, 0xD83, Extend), (0xD85, 0xD96, ALetter),
+        (0xD9A, 0xDB1, ALetter), (0xDB3, 0xDBB, ALetter), (0xDBD, 0xDBD, ALetter),
+        (0xDC0, 0xDC6, ALetter), (0xDCA, 0xDCA, Extend), (0xDCF, 0xDD4, Extend),
+        (0xDD6, 0xDD6, Extend), (0xDD8, 0xDDF, Extend), (0xDE6, 0xDEF, Numeric),
+        (0xDF2, 0xDF3, Extend), (0xE31, 0xE31, Extend), (0xE34, 0xE3A, Extend), (0xE47, 0xE4E, Extend),
+        (0xE50, 0xE59, Numeric), (0xEB1, 0xEB1, Extend), (0xEB4, 0xEBC, Extend),
+        (0xEC8, 0xECE, Extend), (0xED0, 0xED9, Numeric), (0xF00, 0xF00, ALetter),
+        (0xF18, 0xF19, Extend), (0xF20, 0xF29, Numeric), (0xF35, 0xF35, Extend),
+        (0xF37, 0xF37, Extend), (0xF39, 0xF39, Extend), (0xF3E, 0xF3F, Extend),
+        (0xF40, 0xF47, ALetter), (0xF49, 0xF6C, ALetter), (0xF71, 0xF84, Extend),
+        (0xF86, 0xF87, Extend), (0xF88, 0xF8C, ALetter), (0xF8D, 0xF97, Extend),
+        (0xF99, 0xFBC, Extend), (0xFC6, 0xFC6, Extend), (0x102B, 0x103E, Extend),
+        (0x1040, 0x1049, Numeric), (0x1056, 0x1059, Extend), (0x105E, 0x1060, Extend),
+        (0x1062, 0x1064, Extend), (0x1067, 0x106D, Extend), (0x1071, 0x1074, Extend),
+        (0x1082, 0x108D, Extend), (0x108F, 0x108F, Extend), (0x1090, 0x1099, Numeric),
+        (0x109A, 0x109D, Extend), (0x10A0, 0x10C5, ALetter), (0x10C7, 0x10C7, ALetter),
+        (0x10CD, 0x10CD, ALetter), (0x10D0, 0x10FA, ALetter), (0x10FC, 0x1248, ALetter),
+        (0x124A, 0x124D, ALetter), (0x1250, 0x1256, ALetter), (0x1258, 0x1258, ALetter),
+        (0x125A, 0x125D, ALetter), (0x1260, 0x1288, ALetter), (0x128A, 0x128D, ALetter),
+        (0x1290, 0x12B0, ALetter), (0x12B2, 0x12B5, ALetter), (0x12B8, 0x12BE, ALetter),
+        (0x12C0, 0x12C0, ALetter), (0x12C2, 0x12C5, ALetter), (0x12C8, 0x12D6, ALetter),
+        (0x12D8, 0x1310, ALetter), (0x1312, 0x1315, ALetter), (0x1318, 0x135A, ALetter),
+        (0x135D, 0x135F, Extend), (0x1380, 0x138F, ALetter), (0x13A0, 0x13F5, ALetter),
+        (0x13F8, 0x13FD, ALetter), (0x1401, 0x166C, ALetter), (0x166F, 0x167F, ALetter),
+        (0x1680, 0x1680, WSegSpace), (0x1681, 0x169A, ALetter), (0x16A0, 0x16EA, ALetter),
+        (0x16EE, 0x16F8, ALetter), (0x1700, 0x1711, ALetter), (0x1712, 0x1715, Extend),
+        (0x171F, 0x1731, ALetter), (0x1732, 0x1734, Extend), (0x1740, 0x1751, ALetter),
+        (0x1752, 0x1753, Extend), (0x1760, 0x176C, ALetter), (0x176E, 0x1770, ALetter),
+        (0x1772, 0x1773, Extend), (0x17B4, 0x17D3, Extend), (0x17DD, 0x17DD, Extend),
+        (0x17E0, 0x17E9, Numeric), (0x180B, 0x180D, Extend), (0x180E, 0x180E, Format),
+        (0x180F, 0x180F, Extend), (0x1810, 0x1819, Numeric), (0x1820, 0x1878, ALetter),
+        (0x1880, 0x1884, ALetter), (0x1885, 0x1886, Extend), (0x1887, 0x18A8, ALetter),
+        (0x18A9, 0x18A9, Extend), (0x18AA, 0x18AA, ALetter), (0x18B0, 0x18F5, ALetter),
+        (0x1900, 0x191E, ALetter), (0x1920, 0x192B, Extend), (0x1930, 0x193B, Extend),
+        (0x1946, 0x194F, Numeric), (0x19D0, 0x19D9, Numeric), (0x1A00, 0x1A16, ALetter),
+        (0x1A17, 0x1A1B, Extend), (0x1A55, 0x1A5E, Extend), (0x1A60, 0x1A7C, Extend),
+        (0x1A7F, 0x1A7F, Extend), (0x1A80, 0x1A89, Numeric), (0x1A90, 0x1A99, Numeric),
+        (0x1AB0, 0x1ACE, Extend), (0x1B00, 0x1B04, Extend), (0x1B05, 0x1B33, ALetter),
+        (0x1B34, 0x1B44, Extend), (0x1B45, 0x1B4C, ALetter), (0x1B50, 0x1B59, Numeric),
+        (0x1B6B, 0x1B73, Extend), (0x1B80, 0x1B82, Extend), (0x1B83, 0x1BA0, ALetter),
+        (0x1BA1, 0x1BAD, Extend), (0x1BAE, 0x1BAF, ALetter), (0x1BB0, 0x1BB9, Numeric),
+        (0x1BBA, 0x1BE5, ALetter), (0x1BE6, 0x1BF3, Extend), (0x1C00, 0x1C23, ALetter),
+        (0x1C24, 0x1C37, Extend), (0x1C40, 0x1C49, Numeric), (0x1C4D, 0x1C4F, ALetter),
+        (0x1C50, 0x1C59, Numeric), (0x1C5A, 0x1C7D, ALetter), (0x1C80, 0x1C88, ALetter),
+        (0x1C90, 0x1CBA, ALetter), (0x1CBD, 0x1CBF, ALetter), (0x1CD0, 0x1CD2, Extend),
+        (0x1CD4, 0x1CE8, Extend), (0x1CE9, 0x1CEC, ALetter), (0x1CED, 0x1CED, Extend),
+        (0x1CEE, 0x1CF3, ALetter), (0x1CF4, 0x1CF4, Extend), (0x1CF5, 0x1CF6, ALetter),
+        (0x1CF7, 0x1CF9, Extend), (0x1CFA, 0x1CFA, ALetter), (0x1D00, 0x1DBF, ALetter),
+        (0x1DC0, 0x1DFF, Extend), (0x1E00, 0x1F15, ALetter), (0x1F18, 0x1F1D, ALetter),
+        (0x1F20, 0x1F45, ALetter), (0x1F48, 0x1F4D, ALetter), (0x1F50, 0x1F57, ALetter),
+        (0x1F59, 0x1F59, ALetter), (0x1F5B, 0x1F5B, ALetter), (0x1F5D, 0x1F5D, ALetter),
+        (0x1F5F, 0x1F7D, ALetter), (0x1F80, 0x1FB4, ALetter), (0x1FB6, 0x1FBC, ALetter),
+        (0x1FBE, 0x1FBE, ALetter), (0x1FC2, 0x1FC4, ALetter), (0x1FC6, 0x1FCC, ALetter),
+        (0x1FD0, 0x1FD3, ALetter), (0x1FD6, 0x1FDB, ALetter), (0x1FE0, 0x1FEC, ALetter),
+        (0x1FF2, 0x1FF4, ALetter), (0x1FF6, 0x1FFC, ALetter), (0x2000, 0x2006, WSegSpace),
+        (0x2008, 0x200A, WSegSpace), (0x200C, 0x200C, Extend), (0x200D, 0x200D, Zwj),
+        (0x200E, 0x200F, Format), (0x2018, 0x2019, MidNumLet), (0x2024, 0x2024, MidNumLet),
+        (0x2027, 0x2027, MidLetter), (0x2028, 0x2029, Newline), (0x202A, 0x202E, Format),
+        (0x202F, 0x202F, ExtendNumLet), (0x203F, 0x2040, ExtendNumLet), (0x2044, 0x2044, MidNum),
+        (0x2054, 0x2054, ExtendNumLet), (0x205F, 0x205F, WSegSpace), (0x2060, 0x2064, Format),
+        (0x2066, 0x206F, Format), (0x2071, 0x2071, ALetter), (0x207F, 0x207F, ALetter),
+        (0x2090, 0x209C, ALetter), (0x20D0, 0x20F0, Extend), (0x2102, 0x2102, ALetter),
+        (0x2107, 0x2107, ALetter), (0x210A, 0x2113, ALetter), (0x2115, 0x2115, ALetter),
+        (0x2119, 0x211D, ALetter), (0x2124, 0x2124, ALetter), (0x2126, 0x2126, ALetter),
+        (0x2128, 0x2128, ALetter), (0x212A, 0x212D, ALetter), (0x212F, 0x2139, ALetter),
+        (0x213C, 0x213F, ALetter), (0x2145, 0x2149, ALetter), (0x214E, 0x214E, ALetter),
+        (0x2160, 0x2188, ALetter), (0x24B6, 0x24E9, ALetter), (0x2C00, 0x2CE4, ALetter),
+        (0x2CEB, 0x2CEE, ALetter), (0x2CEF, 0x2CF1, Extend), (0x2CF2, 0x2CF3, ALetter),
+        (0x2D00, 0x2D25, ALetter), (0x2D27, 0x2D27, ALetter), (0x2D2D, 0x2D2D, ALetter),
+        (0x2D30, 0x2D67, ALetter), (0x2D6F, 0x2D6F, ALetter), (0x2D7F, 0x2D7F, Extend),
+        (0x2D80, 0x2D96, ALetter), (0x2DA0, 0x2DA6, ALetter), (0x2DA8, 0x2DAE, ALetter),
+        (0x2DB0, 0x2DB6, ALetter), (0x2DB8, 0x2DBE, ALetter), (0x2DC0, 0x2DC6, ALetter),
+        (0x2DC8, 0x2DCE, ALetter), (0x2DD0, 0x2DD6, ALetter), (0x2DD8, 0x2DDE, ALetter),
+        (0x2DE0, 0x2DFF, Extend), (0x2E2F, 0x2E2F, ALetter), (0x3000, 0x3000, WSegSpace),
+        (0x3005, 0x3005, ALetter), (0x302A, 0x302F, Extend), (0x3031, 0x3035, Katakana),
+        (0x303B, 0x303C, ALetter), (0x3099, 0x309A, Extend), (0x309B, 0x309C, Katakana),
+        (0x30A0, 0x30FA, Katakana), (0x30FC, 0x30FF, Katakana), (0x3105, 0x312F, ALetter),
+        (0x3131, 0x318E, ALetter), (0x31A0, 0x31BF, ALetter), (0x31F0, 0x31FF, Katakana),
+        (0x32D0, 0x32FE, Katakana), (0x3300, 0x3357, Katakana), (0xA000, 0xA48C, ALetter),
+        (0xA4D0, 0xA4FD, ALetter), (0xA500, 0xA60C, ALetter), (0xA610, 0xA61F, ALetter),
+        (0xA620, 0xA629, Numeric), (0xA62A, 0xA62B, ALetter), (0xA640, 0xA66E, ALetter),
+        (0xA66F, 0xA672, Extend), (0xA674, 0xA67D, Extend), (0xA67F, 0xA69D, ALetter),
+        (0xA69E, 0xA69F, Extend), (0xA6A0, 0xA6EF, ALetter), (0xA6F0, 0xA6F1, Extend),
+        (0xA708, 0xA7CA, ALetter), (0xA7D0, 0xA7D1, ALetter), (0xA7D3, 0xA7D3, ALetter),
+        (0xA7D5, 0xA7D9, ALetter), (0xA7F2, 0xA801, ALetter), (0xA802, 0xA802, Extend),
+        (0xA803, 0xA805, ALetter), (0xA806, 0xA806, Extend), (0xA807, 0xA80A, ALetter),
+        (0xA80B, 0xA80B, Extend), (0xA80C, 0xA822, ALetter), (0xA823, 0xA827, Extend),
+        (0xA82C, 0xA82C, Extend), (0xA840, 0xA873, ALetter), (0xA880, 0xA881, Extend),
+        (0xA882, 0xA8B3, ALetter), (0xA8B4, 0xA8C5, Extend), (0xA8D0, 0xA8D9, Numeric),
+        (0xA8E0, 0xA8F1, Extend), (0xA8F2, 0xA8F7, ALetter), (0xA8FB, 0xA8FB, ALetter),
+        (0xA8FD, 0xA8FE, ALetter), (0xA8FF, 0xA8FF, Extend), (0xA900, 0xA909, Numeric),
+        (0xA90A, 0xA925, ALetter), (0xA926, 0xA92D, Extend), (0xA930, 0xA946, ALetter),
+        (0xA947, 0xA953, Extend), (0xA960, 0xA97C, ALetter), (0xA980, 0xA983, Extend),
+        (0xA984, 0xA9B2, ALetter), (0xA9B3, 0xA9C0, Extend), (0xA9CF, 0xA9CF, ALetter),
+        (0xA9D0, 0xA9D9, Numeric), (0xA9E5, 0xA9E5, Extend), (0xA9F0, 0xA9F9, Numeric),
+        (0xAA00, 0xAA28, ALetter), (0xAA29, 0xAA36, Extend), (0xAA40, 0xAA42, ALetter),
+        (0xAA43, 0xAA43, Extend), (0xAA44, 0xAA4B, ALetter), (0xAA4C, 0xAA4D, Extend),
+        (0xAA50, 0xAA59, Numeric), (0xAA7B, 0xAA7D, Extend), (0xAAB0, 0xAAB0, Extend),
+        (0xAAB2, 0xAAB4, Extend), (0xAAB7, 0xAAB8, Extend), (0xAABE, 0xAABF, Extend),
+        (0xAAC1, 0xAAC1, Extend), (0xAAE0, 0xAAEA, ALetter), (0xAAEB, 0xAAEF, Extend),
+        (0xAAF2, 0xAAF4, ALetter), (0xAAF5, 0xAAF6, Extend), (0xAB01, 0xAB06, ALetter),
+        (0xAB09, 0xAB0E, ALetter), (0xAB11, 0xAB16, ALetter), (0xAB20, 0xAB26, ALetter),
+        (0xAB28, 0xAB2E, ALetter), (0xAB30, 0xAB69, ALetter), (0xAB70, 0xABE2, ALetter),
+        (0xABE3, 0xABEA, Extend), (0xABEC, 0xABED, Extend), (0xABF0, 0xABF9, Numeric),
+        (0xAC00, 0xD7A3, ALetter), (0xD7B0, 0xD7C6, ALetter), (0xD7CB, 0xD7FB, ALetter),
+        (0xFB00, 0xFB06, ALetter), (0xFB13, 0xFB17, ALetter), (0xFB1D, 0xFB1D, HebrewLetter),
+        (0xFB1E, 0xFB1E, Extend), (0xFB1F, 0xFB28, HebrewLetter), (0xFB2A, 0xFB36, HebrewLetter),
+        (0xFB38, 0xFB3C, HebrewLetter), (0xFB3E, 0xFB3E, HebrewLetter), (0xFB40, 0xFB41, HebrewLetter),
+        (0xFB43, 0xFB44, HebrewLetter), (0xFB46, 0xFB4F, HebrewLetter), (0xFB50, 0xFBB1, ALetter),
+        (0xFBD3, 0xFD3D, ALetter), (0xFD50, 0xFD8F, ALetter), (0xFD92, 0xFDC7, ALetter),
+        (0xFDF0, 0xFDFB, ALetter), (0xFE00, 0xFE0F, Extend), (0xFE10, 0xFE10, MidNum),
+        (0xFE13, 0xFE13, MidLetter), (0xFE14, 0xFE14, MidNum), (0xFE20, 0xFE2F, Extend),
+        (0xFE33, 0xFE34, ExtendNumLet), (0xFE4D, 0xFE4F, ExtendNumLet), (0xFE50, 0xFE50, MidNum),
+        (0xFE52, 0xFE52, MidNumLet), (0xFE54, 0xFE54, MidNum), (0xFE55, 0xFE55, MidLetter),
+        (0xFE70, 0xFE74, ALetter), (0xFE76, 0xFEFC, ALetter), (0xFEFF, 0xFEFF, Format),
+        (0xFF07, 0xFF07, MidNumLet), (0xFF0C, 0xFF0C, MidNum), (0xFF0E, 0xFF0E, MidNumLet),
+        (0xFF10, 0xFF19, Numeric), (0xFF1A, 0xFF1A, MidLetter), (0xFF1B, 0xFF1B, MidNum),
+        (0xFF21, 0xFF3A, ALetter), (0xFF3F, 0xFF3F, ExtendNumLet), (0xFF41, 0xFF5A, ALetter),
+        (0xFF66, 0xFF9D, Katakana), (0xFF9E, 0xFF9F, Extend), (0xFFA0, 0xFFBE, ALetter),
+        (0xFFC2, 0xFFC7, ALetter), (0xFFCA, 0xFFCF, ALetter), (0xFFD2, 0xFFD7, ALetter),
+        (0xFFDA, 0xFFDC, ALetter), (0xFFF9, 0xFFFB, Format), (0x10000, 0x1000B, ALetter),
+        (0x1000D, 0x10026, ALetter), (0x10028, 0x1003A, ALetter), (0x1003C, 0x1003D, ALetter),
+        (0x1003F, 0x1004D, ALetter), (0x10050, 0x1005D, ALetter), (0x10080, 0x100FA, ALetter),
+        (0x10140, 0x10174, ALetter), (0x101FD, 0x101FD, Extend), (0x10280, 0x1029C, ALetter),
+        (0x102A0, 0x102D0, ALetter), (0x102E0, 0x102E0, Extend), (0x10300, 0x1031F, ALetter),
+        (0x1032D, 0x1034A, ALetter), (0x10350, 0x10375, ALetter), (0x10376, 0x1037A, Extend),
+        (0x10380, 0x1039D, ALetter), (0x103A0, 0x103C3, ALetter), (0x103C8, 0x103CF, ALetter),
+        (0x103D1, 0x103D5, ALetter), (0x10400, 0x1049D, ALetter), (0x104A0, 0x104A9, Numeric),
+        (0x104B0, 0x104D3, ALetter), (0x104D8, 0x104FB, ALetter), (0x10500, 0x10527, ALetter),
+        (0x10530, 0x10563, ALetter), (0x10570, 0x1057A, ALetter), (0x1057C, 0x1058A, ALetter),
+        (0x1058C, 0x10592, ALetter), (0x10594, 0x10595, ALetter), (0x10597, 0x105A1, ALetter),
+        (0x105A3, 0x105B1, ALetter), (0x105B3, 0x105B9, ALetter), (0x105BB, 0x105BC, ALetter),
+        (0x10600, 0x10736, ALetter), (0x10740, 0x10755, ALetter), (0x10760, 0x10767, ALetter),
+        (0x10780, 0x10785, ALetter), (0x10787, 0x107B0, ALetter), (0x107B2, 0x107BA, ALetter),
+        (0x10800, 0x10805, ALetter), (0x10808, 0x10808, ALetter), (0x1080A, 0x10835, ALetter),
+        (0x10837, 0x10838, ALetter), (0x1083C, 0x1083C, ALetter), (0x1083F, 0x10855, ALetter),
+        (0x10860, 0x10876, ALetter), (0x10880, 0x1089E, ALetter), (0x108E0, 0x108F2, ALetter),
+        (0x108F4, 0x108F5, ALetter), (0x10900, 0x10915, ALetter), (0x10920, 0x10939, ALetter),
+        (0x10980, 0x109B7, ALetter), (0x109BE, 0x109BF, ALetter), (0x10A00, 0x10A00, ALetter),
+        (0x10A01, 0x10A03, Extend), (0x10A05, 0x10A06, Extend), (0x10A0C, 0x10A0F, Extend),
+        (0x10A10, 0x10A13, ALetter), (0x10A15, 0x10A17, ALetter), (0x10A19, 0x10A35, ALetter),
+        (0x10A38, 0x10A3A, Extend), (0x10A3F, 0x10A3F, Extend), (0x10A60, 0x10A7C, ALetter),
+        (0x10A80, 0x10A9C, ALetter), (0x10AC0, 0x10AC7, ALetter), (0x10AC9, 0x10AE4, ALetter),
+        (0x10AE5, 0x10AE6, Extend), (0x10B00, 0x10B35, ALetter), (0x10B40, 0x10B55, ALetter),
+        (0x10B60, 0x10B72, ALetter), (0x10B80, 0x10B91, ALetter), (0x10C00, 0x10C48, ALetter),
+        (0x10C80, 0x10CB2, ALetter), (0x10CC0, 0x10CF2, ALetter), (0x10D00, 0x10D23, ALetter),
+        (0x10D24, 0x10D27, Extend), (0x10D30, 0x10D39, Numeric), (0x10E80, 0x10EA9, ALetter),
+        (0x10EAB, 0x10EAC, Extend), (0x10EB0, 0x10EB1, ALetter), (0x10EFD, 0x10EFF, Extend),
+        (0x10F00, 0x10F1C, ALetter), (0x10F27, 0x10F27, ALetter), (0x10F30, 0x10F45, ALetter),
+        (0x10F46, 0x10F50, Extend), (0x10F70, 0x10F81, ALetter), (0x10F82, 0x10F85, Extend),
+        (0x10FB0, 0x10FC4, ALetter), (0x10FE0, 0x10FF6, ALetter), (0x11000, 0x11002, Extend),
+        (0x11003, 0x11037, ALetter), (0x11038, 0x11046, Extend), (0x11066, 0x1106F, Numeric),
+        (0x11070, 0x11070, Extend), (0x11071, 0x11072, ALetter), (0x11073, 0x11074, Extend),
+        (0x11075, 0x11075, ALetter), (0x1107F, 0x11082, Extend), (0x11083, 0x110AF, ALetter),
+        (0x110B0, 0x110BA, Extend), (0x110BD, 0x110BD, Numeric), (0x110C2, 0x110C2, Extend),
+        (0x110CD, 0x110CD, Numeric), (0x110D0, 0x110E8, ALetter), (0x110F0, 0x110F9, Numeric),
+        (0x11100, 0x11102, Extend), (0x11103, 0x11126, ALetter), (0x11127, 0x11134, Extend),
+        (0x11136, 0x1113F, Numeric), (0x11144, 0x11144, ALetter), (0x11145, 0x11146, Extend),
+        (0x11147, 0x11147, ALetter), (0x11150, 0x11172, ALetter), (0x11173, 0x11173, Extend),
+        (0x11176, 0x11176, ALetter), (0x11180, 0x11182, Extend), (0x11183, 0x111B2, ALetter),
+        (0x111B3, 0x111C0, Extend), (0x111C1, 0x111C4, ALetter), (0x111C9, 0x111CC, Extend),
+        (0x111CE, 0x111CF, Extend), (0x111D0, 0x111D9, Numeric), (0x111DA, 0x111DA, ALetter),
+        (0x111DC, 0x111DC, ALetter), (0x11200, 0x11211, ALetter), (0x11213, 0x1122B, ALetter),
+        (0x1122C, 0x11237, Extend), (0x1123E, 0x1123E, Extend), (0x1123F, 0x11240, ALetter),
+        (0x11241, 0x11241, Extend), (0x11280, 0x11286, ALetter), (0x11288, 0x11288, ALetter),
+        (0x1128A, 0x1128D, ALetter), (0x1128F, 0x1129D, ALetter), (0x1129F, 0x112A8, ALetter),
+        (0x112B0, 0x112DE, ALetter), (0x112DF, 0x112EA, Extend), (0x112F0, 0x112F9, Numeric),
+        (0x11300, 0x11303, Extend), (0x11305, 0x1130C, ALetter), (0x1130F, 0x11310, ALetter),
+        (0x11313, 0x11328, ALetter), (0x1132A, 0x11330, ALetter), (0x11332, 0x11333, ALetter),
+        (0x11335, 0x11339, ALetter), (0x1133B, 0x1133C, Extend), (0x1133D, 0x1133D, ALetter),
+        (0x1133E, 0x11344, Extend), (0x11347, 0x11348, Extend), (0x1134B, 0x1134D, Extend),
+        (0x11350, 0x11350, ALetter), (0x11357, 0x11357, Extend), (0x1135D, 0x11361, ALetter),
+        (0x11362, 0x11363, Extend), (0x11366, 0x1136C, Extend), (0x11370, 0x11374, Extend),
+        (0x11400, 0x11434, ALetter), (0x11435, 0x11446, Extend), (0x11447, 0x1144A, ALetter),
+        (0x11450, 0x11459, Numeric), (0x1145E, 0x1145E, Extend), (0x1145F, 0x11461, ALetter),
+        (0x11480, 0x114AF, ALetter), (0x114B0, 0x114C3, Extend), (0x114C4, 0x114C5, ALetter),
+        (0x114C7, 0x114C7, ALetter), (0x114D0, 0x114D9, Numeric), (0x11580, 0x115AE, ALetter),
+        (0x115AF, 0x115B5, Extend), (0x115B8, 0x115C0, Extend), (0x115D8, 0x115DB, ALetter),
+        (0x115DC, 0x115DD, Extend), (0x11600, 0x1162F, ALetter), (0x11630, 0x11640, Extend),
+        (0x11644, 0x11644, ALetter), (0x11650, 0x11659, Numeric), (0x11680, 0x116AA, ALetter),
+        (0x116AB, 0x116B7, Extend), (0x116B8, 0x116B8, ALetter), (0x116C0, 0x116C9, Numeric),
+        (0x1171D, 0x1172B, Extend), (0x11730, 0x11739, Numeric), (0x11800, 0x1182B, ALetter),
+        (0x1182C, 0x1183A, Extend), (0x118A0, 0x118DF, ALetter), (0x118E0, 0x118E9, Numeric),
+        (0x118FF, 0x11906, ALetter), (0x11909, 0x11909, ALetter), (0x1190C, 0x11913, ALetter),
+        (0x11915, 0x11916, ALetter), (0x11918, 0x1192F, ALetter), (0x11930, 0x11935, Extend),
+        (0x11937, 0x11938, Extend), (0x1193B, 0x1193E, Extend), (0x1193F, 0x1193F, ALetter),
+        (0x11940, 0x11940, Extend), (0x11941, 0x11941, ALetter), (0x11942, 0x11943, Extend),
+        (0x11950, 0x11959, Numeric), (0x119A0, 0x119A7, ALetter), (0x119AA, 0x119D0, ALetter),
+        (0x119D1, 0x119D7, Extend), (0x119DA, 0x119E0, Extend), (0x119E1, 0x119E1, ALetter),
+        (0x119E3, 0x119E3, ALetter), (0x119E4, 0x119E4, Extend), (0x11A00, 0x11A00, ALetter),
+        (0x11A01, 0x11A0A, Extend), (0x11A0B, 0x11A32, ALetter), (0x11A33, 0x11A39, Extend),
+        (0x11A3A, 0x11A3A, ALetter), (0x11A3B, 0x11A3E, Extend), (0x11A47, 0x11A47, Extend),
+        (0x11A50, 0x11A50, ALetter), (0x11A51, 0x11A5B, Extend), (0x11A5C, 0x11A89, ALetter),
+        (0x11A8A, 0x11A99, Extend), (0x11A9D, 0x11A9D, ALetter), (0x11AB0, 0x11AF8, ALetter),
+        (0x11C00, 0x11C08, ALetter), (0x11C0A, 0x11C2E, ALetter), (0x11C2F, 0x11C36, Extend),
+        (0x11C38, 0x11C3F, Extend), (0x11C40, 0x11C40, ALetter), (0x11C50, 0x11C59, Numeric),
+        (0x11C72, 0x11C8F, ALetter), (0x11C92, 0x11CA7, Extend), (0x11CA9, 0x11CB6, Extend),
+        (0x11D00, 0x11D06, ALetter), (0x11D08, 0x11D09, ALetter), (0x11D0B, 0x11D30, ALetter),
+        (0x11D31, 0x11D36, Extend), (0x11D3A, 0x11D3A, Extend), (0x11D3C, 0x11D3D, Extend),
+        (0x11D3F, 0x11D45, Extend), (0x11D46, 0x11D46, ALetter), (0x11D47, 0x11D47, Extend),
+        (0x11D50, 0x11D59, Numeric), (0x11D60, 0x11D65, ALetter), (0x11D67, 0x11D68, ALetter),
+        (0x11D6A, 0x11D89, ALetter), (0x11D8A, 0x11D8E, Extend), (0x11D90, 0x11D91, Extend),
+        (0x11D93, 0x11D97, Extend), (0x11D98, 0x11D98, ALetter), (0x11DA0, 0x11DA9, Numeric),
+        (0x11EE0, 0x11EF2, ALetter), (0x11EF3, 0x11EF6, Extend), (0x11F00, 0x11F01, Extend),
+        (0x11F02, 0x11F02, ALetter), (0x11F03, 0x11F03, Extend), (0x11F04, 0x11F10, ALetter),
+        (0x11F12, 0x11F33, ALetter), (0x11F34, 0x11F3A, Extend), (0x11F3E, 0x11F42, Extend),
+        (0x11F50, 0x11F59, Numeric), (0x11FB0, 0x11FB0, ALetter), (0x12000, 0x12399, ALetter),
+        (0x12400, 0x1246E, ALetter), (0x12480, 0x12543, ALetter), (0x12F90, 0x12FF0, ALetter),
+        (0x13000, 0x1342F, ALetter), (0x13430, 0x1343F, Format), (0x13440, 0x13440, Extend),
+        (0x13441, 0x13446, ALetter), (0x13447, 0x13455, Extend), (0x14400, 0x14646, ALetter),
+        (0x16800, 0x16A38, ALetter), (0x16A40, 0x16A5E, ALetter), (0x16A60, 0x16A69, Numeric),
+        (0x16A70, 0x16ABE, ALetter), (0x16AC0, 0x16AC9, Numeric), (0x16AD0, 0x16AED, ALetter),
+        (0x16AF0, 0x16AF4, Extend), (0x16B00, 0x16B2F, ALetter), (0x16B30, 0x16B36, Extend),
+        (0x16B40, 0x16B43, ALetter), (0x16B50, 0x16B59, Numeric), (0x16B63, 0x16B77, ALetter),
+        (0x16B7D, 0x16B8F, ALetter), (0x16E40, 0x16E7F, ALetter), (0x16F00, 0x16F4A, ALetter),
+        (0x16F4F, 0x16F4F, Extend), (0x16F50, 0x16F50, ALetter), (0x16F51, 0x16F87, Extend),
+        (0x16F8F, 0x16F92, Extend), (0x16F93, 0x16F9F, ALetter), (0x16FE0, 0x16FE1, ALetter),
+        (0x16FE3, 0x16FE3, ALetter), (0x16FE4, 0x16FE4, Extend), (0x16FF0, 0x16FF1, Extend),
+        (0x1AFF0, 0x1AFF3, Katakana), (0x1AFF5, 0x1AFFB, Katakana), (0x1AFFD, 0x1AFFE, Katakana),
+        (0x1B000, 0x1B000, Katakana), (0x1B120, 0x1B122, Katakana), (0x1B155, 0x1B155, Katakana),
+        (0x1B164, 0x1B167, Katakana), (0x1BC00, 0x1BC6A, ALetter), (0x1BC70, 0x1BC7C, ALetter),
+        (0x1BC80, 0x1BC88, ALetter), (0x1BC90, 0x1BC99, ALetter), (0x1BC9D, 0x1BC9E, Extend),
+        (0x1BCA0, 0x1BCA3, Format), (0x1CF00, 0x1CF2D, Extend), (0x1CF30, 0x1CF46, Extend),
+        (0x1D165, 0x1D169, Extend), (0x1D16D, 0x1D172, Extend), (0x1D173, 0x1D17A, Format),
+        (0x1D17B, 0x1D182, Extend), (0x1D185, 0x1D18B, Extend), (0x1D1AA, 0x1D1AD, Extend),
+        (0x1D242, 0x1D244, Extend), (0x1D400, 0x1D454, ALetter), (0x1D456, 0x1D49C, ALetter),
+        (0x1D49E, 0x1D49F, ALetter), (0x1D4A2, 0x1D4A2, ALetter), (0x1D4A5, 0x1D4A6, ALetter),
+        (0x1D4A9, 0x1D4AC, ALetter), (0x1D4AE, 0x1D4B9, ALetter), (0x1D4BB, 0x1D4BB, ALetter),
+        (0x1D4BD, 0x1D4C3, ALetter), (0x1D4C5, 0x1D505, ALetter), (0x1D507, 0x1D50A, ALetter),
+        (0x1D50D, 0x1D514, ALetter), (0x1D516, 0x1D51C, ALetter), (0x1D51E, 0x1D539, ALetter),
+        (0x1D53B, 0x1D53E, ALetter), (0x1D540, 0x1D544, ALetter), (0x1D546, 0x1D546, ALetter),
+        (0x1D54A, 0x1D550, ALetter), (0x1D552, 0x1D6A5, ALetter), (0x1D6A8, 0x1D6C0, ALetter),
+        (0x1D6C2, 0x1D6DA, ALetter), (0x1D6DC, 0x1D6FA, ALetter), (0x1D6FC, 0x1D714, ALetter),
+        (0x1D716, 0x1D734, ALetter), (0x1D736, 0x1D74E, ALetter), (0x1D750, 0x1D76E, ALetter),
+        (0x1D770, 0x1D788, ALetter), (0x1D78A, 0x1D7A8, ALetter), (0x1D7AA, 0x1D7C2, ALetter),
+        (0x1D7C4, 0x1D7CB, ALetter), (0x1D7CE, 0x1D7FF, Numeric), (0x1DA00, 0x1DA36, Extend),
+        (0x1DA3B, 0x1DA6C, Extend), (0x1DA75, 0x1DA75, Extend), (0x1DA84, 0x1DA84, Extend),
+        (0x1DA9B, 0x1DA9F, Extend), (0x1DAA1, 0x1DAAF, Extend), (0x1DF00, 0x1DF1E, ALetter),
+        (0x1DF25, 0x1DF2A, ALetter), (0x1E000, 0x1E006, Extend), (0x1E008, 0x1E018, Extend),
+        (0x1E01B, 0x1E021, Extend), (0x1E023, 0x1E024, Extend), (0x1E026, 0x1E02A, Extend),
+        (0x1E030, 0x1E06D, ALetter), (0x1E08F, 0x1E08F, Extend), (0x1E100, 0x1E12C, ALetter),
+        (0x1E130, 0x1E136, Extend), (0x1E137, 0x1E13D, ALetter), (0x1E140, 0x1E149, Numeric),
+        (0x1E14E, 0x1E14E, ALetter), (0x1E290, 0x1E2AD, ALetter), (0x1E2AE, 0x1E2AE, Extend),
+        (0x1E2C0, 0x1E2EB, ALetter), (0x1E2EC, 0x1E2EF, Extend), (0x1E2F0, 0x1E2F9, Numeric),
+        (0x1E4D0, 0x1E4EB, ALetter), (0x1E4EC, 0x1E4EF, Extend), (0x1E4F0, 0x1E4F9, Numeric),
+        (0x1E7E0, 0x1E7E6, ALetter), (0x1E7E8, 0x1E7EB, ALetter), (0x1E7ED, 0x1E7EE, ALetter),
+        (0x1E7F0, 0x1E7FE, ALetter), (0x1E800, 0x1E8C4, ALetter), (0x1E8D0, 0x1E8D6, Extend),
+        (0x1E900, 0x1E943, ALetter), (0x1E944, 0x1E94A, Extend), (0x1E94B, 0x1E94B, ALetter),
+        (0x1E950, 0x1E959, Numeric), (0x1EE00, 0x1EE03, ALetter), (0x1EE05, 0x1EE1F, ALetter),
+        (0x1EE21, 0x1EE22, ALetter), (0x1EE24, 0x1EE24, ALetter), (0x1EE27, 0x1EE27, ALetter),
+        (0x1EE29, 0x1EE32, ALetter), (0x1EE34, 0x1EE37, ALetter), (0x1EE39, 0x1EE39, ALetter),
+        (0x1EE3B, 0x1EE3B, ALetter), (0x1EE42, 0x1EE42, ALetter), (0x1EE47, 0x1EE47, ALetter),
+        (0x1EE49, 0x1EE49, ALetter), (0x1EE4B, 0x1EE4B, ALetter), (0x1EE4D, 0x1EE4F, ALetter),
+        (0x1EE51, 0x1EE52, ALetter), (0x1EE54, 0x1EE54, ALetter), (0x1EE57, 0x1EE57, ALetter),
+        (0x1EE59, 0x1EE59, ALetter), (0x1EE5B, 0x1EE5B, ALetter), (0x1EE5D, 0x1EE5D, ALetter),
+        (0x1EE5F, 0x1EE5F, ALetter), (0x1EE61, 0x1EE62, ALetter), (0x1EE64, 0x1EE64, ALetter),
+        (0x1EE67, 0x1EE6A, ALetter), (0x1EE6C, 0x1EE72, ALetter), (0x1EE74, 0x1EE77, ALetter),
+        (0x1EE79, 0x1EE7C, ALetter), (0x1EE7E, 0x1EE7E, ALetter), (0x1EE80, 0x1EE89, ALetter),
+        (0x1EE8B, 0x1EE9B, ALetter), (0x1EEA1, 0x1EEA3, ALetter), (0x1EEA5, 0x1EEA9, ALetter),
+        (0x1EEAB, 0x1EEBB, ALetter), (0x1F130, 0x1F149, ALetter), (0x1F150, 0x1F169, ALetter),
+        (0x1F170, 0x1F189, ALetter), (0x1F1E6, 0x1F1FF, RegionalIndicator), (0x1F3FB, 0x1F3FF, Extend),
+        (0x1FBF0, 0x1FBF9, Numeric), (0xE0001, 0xE0001, Format), (0xE0020, 0xE007F, Extend),
+        (0xE0100, 0xE01EF, Extend),
+    ];
+}
+
+/// Sentence_Break property values of UAX #29.
+pub mod sentence {
+    /// A Sentence_Break property value.  Characters not in the table are `Other`.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum SentenceBreak {
+        ATerm,
+        Close,
+        Cr,
+        Extend,
+        Format,
+        Lf,
+        Lower,
+        Numeric,
+        OLetter,
+        SContinue,
+        STerm,
+        Sep,
+        Sp,
+        Upper,
+        Other,
+    }
+    use self::SentenceBreak::*;
+
+    /// The Sentence_Break value of every code point which has one.
+    #[rustfmt::skip]
+    pub const SENTENCE_BREAK: &[(u32, u32, SentenceBreak)] = &[
+        (0x9, 0x9, Sp), (0xA, 0xA, Lf), (0xB, 0xC, Sp), (0xD, 0xD, Cr), (0x20, 0x20, Sp),
+        (0x21, 0x21, STerm), (0x22, 0x22, Close), (0x27, 0x29, Close), (0x2C, 0x2D, SContinue),
+        (0x2E, 0x2E, ATerm), (0x30, 0x39, Numeric), (0x3A, 0x3A, SContinue), (0x3F, 0x3F, STerm),
+        (0x41, 0x5A, Upper), (0x5B, 0x5B, Close), (0x5D, 0x5D, Close), (0x61, 0x7A, Lower),
+        (0x7B, 0x7B, Close), (0x7D, 0x7D, Close), (0x85, 0x85, Sep), (0xA0, 0xA0, Sp),
+        (0xAA, 0xAA, Lower), (0xAB, 0xAB, Close), (0xAD, 0xAD, Format), (0xB5, 0xB5, Lower),
+        (0xBA, 0xBA, Lower), (0xBB, 0xBB, Close), (0xC0, 0xD6, Upper), (0xD8, 0xDE, Upper),
+        (0xDF, 0xF6, Lower), (0xF8, 0xFF, Lower), (0x100, 0x100, Upper), (0x101, 0x101, Lower),
+        (0x102, 0x102, Upper), (0x103, 0x103, Lower), (0x104, 0x104, Upper), (0x105, 0x105, Lower),
+        (0x106, 0x106, Upper), (0x107, 0x107, Lower), (0x108, 0x108, Upper), (0x109, 0x109, Lower),
+        (0x10A, 0x10A, Upper), (0x10B, 0x10B, Lower), (0x10C, 0x10C, Upper), (0x10D, 0x10D, Lower),
+        (0x10E, 0x10E, Upper), (0x10F, 0x10F, Lower), (0x110, 0x110, Upper), (0x111, 0x111, Lower),
+        (0x112, 0x112, Upper), (0x113, 0x113, Lower), (0x114, 0x114, Upper), (0x115, 0x115, Lower),
+        (0x116, 0x116, Upper), (0x117, 0x117, Lower), (0x118, 0x118, Upper), (0x119, 0x119, Lower),
+        (0x11A, 0x11A, Upper), (0x11B, 0x11B, Lower), (0x11C, 0x11C, Upper), (0x11D, 0x11D, Lower),
+        (0x11E, 0x11E, Upper), (0x11F, 0x11F, Lower), (0x120, 0x120, Upper), (0x121, 0x121, Lower),
+        (0x122, 0x122, Upper), (0x123, 0x123, Lower), (0x124, 0x124, Upper), (0x125, 0x125, Lower),
+        (0x126, 0x126, Upper), (0x127, 0x127, Lower), (0x128, 0x128, Upper), (0x129, 0x129, Lower),
+        (0x12A, 0x12A, Upper), (0x12B, 0x12B, Lower), (0x12C, 0x12C, Upper), (0x12D, 0x12D, Lower),
+        (0x12E, 0x12E, Upper), (0x12F, 0x12F, Lower), (0x130, 0x130, Upper), (0x131, 0x131, Lower),
+        (0x132, 0x132, Upper), (0x133, 0x133, Lower), (0x134, 0x134, Upper), (0x135, 0x135, Lower),
+        (0x136, 0x136, Upper), (0x137, 0x138, Lower), (0x139, 0x139, Upper), (0x13A, 0x13A, Lower),
+        (0x13B, 0x13B, Upper), (0x13C, 0x13C, Lower), (0x13D, 0x13D, Upper), (0x13E, 0x13E, Lower),
+        (0x13F, 0x13F, Upper), (0x140, 0x140, Lower), (0x141, 0x141, Upper), (0x142, 0x142, Lower),
+        (0x143, 0x143, Upper), (0x144, 0x144, Lower), (0x145, 0x145, Upper), (0x146, 0x146, Lower),
+        (0x147, 0x147, Upper), (0x148, 0x149, Lower), (0x14A, 0x14A, Upper), (0x14B, 0x14B, Lower),
+        (0x14C, 0x14C, Upper), (0x14D, 0x14D, Lower), (0x14E, 0x14E, Upper), (0x14F, 0x14F, Lower),
+        (0x150, 0x150, Upper), (0x151, 0x151, Lower), (0x152, 0x152, Upper), (0x153, 0x153, Lower),
+        (0x154, 0x154, Upper), (0x155, 0x155, Lower), (0x156, 0x156, Upper), (0x157, 0x157, Lower),
+        (0x158, 0x158, Upper), (0x159, 0x159, Lower), (0x15A, 0x15A, Upper), (0x15B, 0x15B, Lower),
+        (0x15C, 0x15C, Upper), (0x15D, 0x15D, Lower), (0x15E, 0x15E, Upper), (0x15F, 0x15F, Lower),
+        (0x160, 0x160, Upper), (0x161, 0x161, Lower), (0x162, 0x162, Upper), (0x163, 0x163, Lower),
+        (0x164, 0x164, Upper), (0x165, 0x165, Lower), (0x166, 0x166, Upper), (0x167, 0x167, Lower),
+        (0x168, 0x168, Upper), (0x169, 0x169, Lower), (0x16A, 0x16A, Upper), (0x16B, 0x16B, Lower),
+        (0x16C, 0x16C, Upper), (0x16D, 0x16D, Lower), (0x16E, 0x16E, Upper), (0x16F, 0x16F, Lower),
+        (0x170, 0x170, Upper), (0x171, 0x171, Lower), (0x172, 0x172, Upper), (0x173, 0x173, Lower),
+        (0x174, 0x174, Upper), (0x175, 0x175, Lower), (0x176, 0x176, Upper), (0x177, 0x177, Lower),
+        (0x178, 0x179, Upper), (0x17A, 0x17A, Lower), (0x17B, 0x17B, Upper), (0x17C, 0x17C, Lower),
+        (0x17D, 0x17D, Upper), (0x17E, 0x180, Lower), (0x181, 0x182, Upper), (0x183, 0x183, Lower),
+        (0x184, 0x184, Upper), (0x185, 0x185, Lower), (0x186, 0x187, Upper), (0x188, 0x188, Lower),
+        (0x189, 0x18B, Upper), (0x18C, 0x18D, Lower), (0x18E, 0x191, Upper), (0x192, 0x192, Lower),
+        (0x193, 0x194, Upper), (0x195, 0x195, Lower), (0x196, 0x198, Upper), (0x199, 0x19B, Lower),
+        (0x19C, 0x19D, Upper), (0x19E, 0x19E, Lower), (0x19F, 0x1A0, Upper), (0x1A1, 0x1A1, Lower),
+        (0x1A2, 0x1A2, Upper), (0x1A3, 0x1A3, Lower), (0x1A4, 0x1A4, Upper), (0x1A5, 0x1A5, Lower),
+        (0x1A6, 0x1A7, Upper), (0x1A8, 0x1A8, Lower), (0x1A9, 0x1A9, Upper), (0x1AA, 0x1AB, Lower),
+        (0x1AC, 0x1AC, Upper), (0x1AD, 0x1AD, Lower), (0x1AE, 0x1AF, Upper), (0x1B0, 0x1B0, Lower),
+        (0x1B1, 0x1B3, Upper), (0x1B4, 0x1B4, Lower), (0x1B5, 0x1B5, Upper), (0x1B6, 0x1B6, Lower),
+        (0x1B7, 0x1B8, Upper), (0x1B9, 0x1BA, Lower), (0x1BB, 0x1BB, OLetter), (0x1BC, 0x1BC, Upper),
+        (0x1BD, 0x1BF, Lower), (0x1C0, 0x1C3, OLetter), (0x1C4, 0x1C5, Upper), (0x1C6, 0x1C6, Lower),
+        (0x1C7, 0x1C8, Upper), (0x1C9, 0x1C9, Lower), (0x1CA, 0x1CB, Upper), (0x1CC, 0x1CC, Lower),
+        (0x1CD, 0x1CD, Upper), (0x1CE, 0x1CE, Lower), (0x1CF, 0x1CF, Upper), (0x1D0, 0x1D0, Lower),
+        (0x1D1, 0x1D1, Upper), (0x1D2, 0x1D2, Lower), (0x1D3, 0x1D3, Upper), (0x1D4, 0x1D4, Lower),
+        (0x1D5, 0x1D5, Upper), (0x1D6, 0x1D6, Lower), (0x1D7, 0x1D7, Upper), (0x1D8, 0x1D8, Lower),
+        (0x1D9, 0x1D9, Upper), (0x1DA, 0x1DA, Lower), (0x1DB, 0x1DB, Upper), (0x1DC, 0x1DD, Lower),
+        (0x1DE, 0x1DE, Upper), (0x1DF, 0x1DF, Lower), (0x1E0, 0x1E0, Upper), (0x1E1, 0x1E1, Lower),
+        (0x1E2, 0x1E2, Upper), (0x1E3, 0x1E3, Lower), (0x1E4, 0x1E4, Upper), (0x1E5, 0x1E5, Lower),
+        (0x1E6, 0x1E6, Upper), (0x1E7, 0x1E7, Lower), (0x1E8, 0x1E8, Upper), (0x1E9, 0x1E9, Lower),
+        (0x1EA, 0x1EA, Upper), (0x1EB, 0x1EB, Lower), (0x1EC, 0x1EC, Upper), (0x1ED, 0x1ED, Lower),
+        (0x1EE, 0x1EE, Upper), (0x1EF, 0x1F0, Lower), (0x1F1, 0x1F2, Upper), (0x1F3, 0x1F3, Lower),
+        (0x1F4, 0x1F4, Upper), (0x1F5, 0x1F5, Lower), (0x1F6, 0x1F8, Upper), (0x1F9, 0x1F9, Lower),
+        (0x1FA, 0x1FA, Upper), (0x1FB, 0x1FB, Lower), (0x1FC, 0x1FC, Upper), (0x1FD, 0x1FD, Lower),
+        (0x1FE, 0x1FE, Upper), (0x1FF, 0x1FF, Lower), (0x200, 0x200, Upper), (0x201, 0x201, Lower),
+        (0x202, 0x202, Upper), (0x203, 0x203, Lower), (0x204, 0x204, Upper), (0x205, 0x205, Lower),
+        (0x206, 0x206, Upper), (0x207, 0x207, Lower), (0x208, 0x208, Upper), (0x209, 0x209, Lower),
+        (0x20A, 0x20A, Upper), (0x20B, 0x20B, Lower), (0x20C, 0x20C, Upper), (0x20D, 0x20D, Lower),
+        (0x20E, 0x20E, Upper), (0x20F, 0x20F, Lower), (0x210, 0x210, Upper), (0x211, 0x211, Lower),
+        (0x212, 0x212, Upper), (0x213, 0x213, Lower), (0x214, 0x214, Upper), (0x215, 0x215, Lower),
+        (0x216, 0x216, Upper), (0x217, 0x217, Lower), (0x218, 0x218, Upper), (0x219, 0x219, Lower),
+        (0x21A, 0x21A, Upper), (0x21B, 0x21B, Lower), (0x21C, 0x21C, Upper), (0x21D, 0x21D, Lower),
+        (0x21E, 0x21E, Upper), (0x21F, 0x21F, Lower), (0x220, 0x220, Upper), (0x221, 0x221, Lower),
+        (0x222, 0x222, Upper), (0x223, 0x223, Lower), (0x224, 0x224, Upper), (0x225, 0x225, Lower),
+        (0x226, 0x226, Upper), (0x227, 0x227, Lower), (0x228, 0x228, Upper), (0x229, 0x229, Lower),
+        (0x22A, 0x22A, Upper), (0x22B, 0x22B, Lower), (0x22C, 0x22C, Upper), (0x22D, 0x22D, Lower),
+        (0x22E, 0x22E, Upper), (0x22F, 0x22F, Lower), (0x230, 0x230, Upper), (0x231, 0x231, Lower),
+        (0x232, 0x232, Upper), (0x233, 0x239, Lower), (0x23A, 0x23B, Upper), (0x23C, 0x23C, Lower),
+        (0x23D, 0x23E, Upper), (0x23F, 0x240, Lower), (0x241, 0x241, Upper), (0x242, 0x242, Lower),
+        (0x243, 0x246, Upper), (0x247, 0x247, Lower), (0x248, 0x248, Upper), (0x249, 0x249, Lower),
+        (0x24A, 0x24A, Upper), (0x24B, 0x24B, Lower), (0x24C, 0x24C, Upper), (0x24D, 0x24D, Lower),
+        (0x24E, 0x24E, Upper), (0x24F, 0x293, Lower), (0x294, 0x294, OLetter), (0x295, 0x2B8, Lower),
+        (0x2B9, 0x2BF, OLetter), (0x2C0, 0x2C1, Lower), (0x2C6, 0x2D1, OLetter), (0x2E0, 0x2E4, Lower),
+        (0x2EC, 0x2EC, OLetter), (0x2EE, 0x2EE, OLetter), (0x300, 0x36F, Extend),
+        (0x370, 0x370, Upper), (0x371, 0x371, Lower), (0x372, 0x372, Upper), (0x373, 0x373, Lower),
+        (0x374, 0x374, OLetter), (0x376, 0x376, Upper), (0x377, 0x377, Lower), (0x37A, 0x37D, Lower),
+        (0x37F, 0x37F, Upper), (0x386, 0x386, Upper), (0x388, 0x38A, Upper), (0x38C, 0x38C, Upper),
+        (0x38E, 0x38F, Upper), (0x390, 0x390, Lower), (0x391, 0x3A1, Upper), (0x3A3, 0x3AB, Upper),
+        (0x3AC, 0x3CE, Lower), (0x3CF, 0x3CF, Upper), (0x3D0, 0x3D1, Lower), (0x3D2, 0x3D4, Upper),
+        (0x3D5, 0x3D7, Lower), (0x3D8, 0x3D8, Upper), (0x3D9, 0x3D9, Lower), (0x3DA, 0x3DA, Upper),
+        (0x3DB, 0x3DB, Lower), (0x3DC, 0x3DC, Upper), (0x3DD, 0x3DD, Lower), (0x3DE, 0x3DE, Upper),
+        (0x3DF, 0x3DF, Lower), (0x3E0, 0x3E0, Upper), (0x3E1, 0x3E1, Lower), (0x3E2, 0x3E2, Upper),
+        (0x3E3, 0x3E3, Lower), (0x3E4, 0x3E4, Upper), (0x3E5, 0x3E5, Lower), (0x3E6, 0x3E6, Upper),
+        (0x3E7, 0x3E7, Lower), (0x3E8, 0x3E8, Upper), (0x3E9, 0x3E9, Lower), (0x3EA, 0x3EA, Upper),
+        (0x3EB, 0x3EB, Lower), (0x3EC, 0x3EC, Upper), (0x3ED, 0x3ED, Lower), (0x3EE, 0x3EE, Upper),
+        (0x3EF, 0x3F3, Lower), (0x3F4, 0x3F4, Upper), (0x3F5, 0x3F5, Lower), (0x3F7, 0x3F7, Upper),
+        (0x3F8, 0x3F8, Lower), (0x3F9, 0x3FA, Upper), (0x3FB, 0x3FC, Lower), (0x3FD, 0x42F, Upper),
+        (0x430, 0x45F, Lower), (0x460, 0x460, Upper), (0x461, 0x461, Lower), (0x462, 0x462, Upper),
+        (0x463, 0x463, Lower), (0x464, 0x464, Upper), (0x465, 0x465, Lower), (0x466, 0x466, Upper),
+        (0x467, 0x467, Lower), (0x468, 0x468, Upper), (0x469, 0x469, Lower), (0x46A, 0x46A, Upper),
+        (0x46B, 0x46B, Lower), (0x46C, 0x46C, Upper), (0x46D, 0x46D, Lower), (0x46E, 0x46E, Upper),
+        (0x46F, 0x46F, Lower), (0x470, 0x470, Upper), (0x471, 0x471, Lower), (0x472, 0x472, Upper),
+        (0x473, 0x473, Lower), (0x474, 0x474, Upper), (0x475, 0x475, Lower), (0x476, 0x476, Upper),
+        (0x477, 0x477, Lower), (0x478, 0x478, Upper), (0x479, 0x479, Lower), (0x47A, 0x47A, Upper),
+        (0x47B, 0x47B, Lower), (0x47C, 0x47C, Upper), (0x47D, 0x47D, Lower), (0x47E, 0x47E, Upper),
+        (0x47F, 0x47F, Lower), (0x480, 0x480, Upper), (0x481, 0x481, Lower), (0x483, 0x489, Extend),
+        (0x48A, 0x48A, Upper), (0x48B, 0x48B, Lower), (0x48C, 0x48C, Upper), (0x48D, 0x48D, Lower),
+        (0x48E, 0x48E, Upper), (0x48F, 0x48F, Lower), (0x490, 0x490, Upper), (0x491, 0x491, Lower),
+        (0x492, 0x492, Upper), (0x493, 0x493, Lower), (0x494, 0x494, Upper), (0x495, 0x495, Lower),
+        (0x496, 0x496, Upper), (0x497, 0x497, Lower), (0x498, 0x498, Upper), (0x499, 0x499, Lower),
+        (0x49A, 0x49A, Upper), (0x49B, 0x49B, Lower), (0x49C, 0x49C, Upper), (0x49D, 0x49D, Lower),
+        (0x49E, 0x49E, Upper), (0x49F, 0x49F, Lower), (0x4A0, 0x4A0, Upper), (0x4A1, 0x4A1, Lower),
+        (0x4A2, 0x4A2, Upper), (0x4A3, 0x4A3, Lower), (0x4A4, 0x4A4, Upper), (0x4A5, 0x4A5, Lower),
+        (0x4A6, 0x4A6, Upper), (0x4A7, 0x4A7, Lower), (0x4A8, 0x4A8, Upper), (0x4A9, 0x4A9, Lower),
+        (0x4AA, 0x4AA, Upper), (0x4AB, 0x4AB, Lower), (0x4AC, 0x4AC, Upper), (0x4AD, 0x4AD, Lower),
+        (0x4AE, 0x4AE, Upper), (0x4AF, 0x4AF, Lower), (0x4B0, 0x4B0, Upper), (0x4B1, 0x4B1, Lower),
+        (0x4B2, 0x4B2, Upper), (0x4B3, 0x4B3, Lower), (0x4B4, 0x4B4, Upper), (0x4B5, 0x4B5, Lower),
+        (0x4B6, 0x4B6, Upper), (0x4B7, 0x4B7, Lower), (0x4B8, 0x4B8, Upper), (0x4B9, 0x4B9, Lower),
+        (0x4BA, 0x4BA, Upper), (0x4BB, 0x4BB, Lower), (0x4BC, 0x4BC, Upper), (0x4BD, 0x4BD, Lower),
+        (0x4BE, 0x4BE, Upper), (0x4BF, 0x4BF, Lower), (0x4C0, 0x4C1, Upper), (0x4C2, 0x4C2, Lower),
+        (0x4C3, 0x4C3, Upper), (0x4C4, 0x4C4, Lower), (0x4C5, 0x4C5, Upper), (0x4C6, 0x4C6, Lower),
+        (0x4C7, 0x4C7, Upper), (0x4C8, 0x4C8, Lower), (0x4C9, 0x4C9, Upper), (0x4CA, 0x4CA, Lower),
+        (0x4CB, 0x4CB, Upper), (0x4CC, 0x4CC, Lower), (0x4CD, 0x4CD, Upper), (0x4CE, 0x4CF, Lower),
+        (0x4D0, 0x4D0, Upper), (0x4D1, 0x4D1, Lower), (0x4D2, 0x4D2, Upper), (0x4D3, 0x4D3, Lower),
+        (0x4D4, 0x4D4, Upper), (0x4D5, 0x4D5, Lower), (0x4D6, 0x4D6, Upper), (0x4D7, 0x4D7, Lower),
+        (0x4D8, 0x4D8, Upper), (0x4D9, 0x4D9, Lower), (0x4DA, 0x4DA, Upper), (0x4DB, 0x4DB, Lower),
+        (0x4DC, 0x4DC, Upper), (0x4DD, 0x4DD, Lower), (0x4DE, 0x4DE, Upper), (0x4DF, 0x4DF, Lower),
+        (0x4E0, 0x4E0, Upper), (0x4E1, 0x4E1, Lower), (0x4E2, 0x4E2, Upper), (0x4E3, 0x4E3, Lower),
+        (0x4E4, 0x4E4, Upper), (0x4E5, 0x4E5, Lower), (0x4E6, 0x4E6, Upper), (0x4E7, 0x4E7, Lower),
+        (0x4E8, 0x4E8, Upper), (0x4E9, 0x4E9, Lower), (0x4EA, 0x4EA, Upper), (0x4EB, 0x4EB, Lower),
+        (0x4EC, 0x4EC, Upper), (0x4ED, 0x4ED, Lower), (0x4EE, 0x4EE, Upper), (0x4EF, 0x4EF, Lower),
+        (0x4F0, 0x4F0, Upper), (0x4F1, 0x4F1, Lower), (0x4F2, 0x4F2, Upper), (0x4F3, 0x4F3, Lower),
+        (0x4F4, 0x4F4, Upper), (0x4F5, 0x4F5, Lower), (0x4F6, 0x4F6, Upper), (0x4F7, 0x4F7, Lower),
+        (0x4F8, 0x4F8, Upper), (0x4F9, 0x4F9, Lower), (0x4FA, 0x4FA, Upper), (0x4FB, 0x4FB, Lower),
+        (0x4FC, 0x4FC, Upper), (0x4FD, 0x4FD, Lower), (0x4FE, 0x4FE, Upper), (0x4FF, 0x4FF, Lower),
+        (0x500, 0x500, Upper), (0x501, 0x501, Lower), (0x502, 0x502, Upper), (0x503, 0x503, Lower),
+        (0x504, 0x504, Upper), (0x505, 0x505, Lower), (0x506, 0x506, Upper), (0x507, 0x507, Lower),
+        (0x508, 0x508, Upper), (0x509, 0x509, Lower), (0x50A, 0x50A, Upper), (0x50B, 0x50B, Lower),
+        (0x50C, 0x50C, Upper), (0x50D, 0x50D, Lower), (0x50E, 0x50E, Upper), (0x50F, 0x50F, Lower),
+        (0x510, 0x510, Upper), (0x511, 0x511, Lower), (0x512, 0x512, Upper), (0x513, 0x513, Lower),
+        (0x514, 0x514, Upper), (0x515, 0x515, Lower), (0x516, 0x516, Upper), (0x517, 0x517, Lower),
+        (0x518, 0x518, Upper), (0x519, 0x519, Lower), (0x51A, 0x51A, Upper), (0x51B, 0x51B, Lower),
+        (0x51C, 0x51C, Upper), (0x51D, 0x51D, Lower), (0x51E, 0x51E, Upper), (0x51F, 0x51F, Lower),
+        (0x520, 0x520, Upper), (0x521, 0x521, Lower), (0x522, 0x522, Upper), (0x523, 0x523, Lower),
+        (0x524, 0x524, Upper), (0x525, 0x525, Lower), (0x526, 0x526, Upper), (0x527, 0x527, Lower),
+        (0x528, 0x528, Upper), (0x529, 0x529, Lower), (0x52A, 0x52A, Upper), (0x52B, 0x52B, Lower),
+        (0x52C, 0x52C, Upper), (0x52D, 0x52D, Lower), (0x52E, 0x52E, Upper), (0x52F, 0x52F, Lower),
+        (0x531, 0x556, Upper), (0x559, 0x559, OLetter), (0x55D, 0x55D, SContinue),
+        (0x560, 0x588, Lower), (0x589, 0x589, STerm), (0x591, 0x5BD, Extend), (0x5BF, 0x5BF, Extend),
+        (0x5C1, 0x5C2, Extend), (0x5C4, 0x5C5, Extend), (0x5C7, 0x5C7, Extend),
+        (0x5D0, 0x5EA, OLetter), (0x5EF, 0x5F3, OLetter), (0x600, 0x605, Numeric),
+        (0x60C, 0x60D, SContinue), (0x610, 0x61A, Extend), (0x61C, 0x61C, Format),
+        (0x61D, 0x61F, STerm), (0x620, 0x64A, OLetter), (0x64B, 0x65F, Extend),
+        (0x660, 0x669, Numeric), (0x66B, 0x66C, Numeric), (0x66E, 0x66F, OLetter),
+        (0x670, 0x670, Extend), (0x671, 0x6D3, OLetter), (0x6D4, 0x6D4, STerm),
+        (0x6D5, 0x6D5, OLetter), (0x6D6, 0x6DC, Extend), (0x6DD, 0x6DD, Numeric),
+        (0x6DF, 0x6E4, Extend), (0x6E5, 0x6E6, OLetter), (0x6E7, 0x6E8, Extend),
+        (0x6EA, 0x6ED, Extend), (0x6EE, 0x6EF, OLetter), (0x6F0, 0x6F9, Numeric),
+        (0x6FA, 0x6FC, OLetter), (0x6FF, 0x6FF, OLetter), (0x700, 0x702, STerm),
+        (0x70F, 0x70F, Format), (0x710, 0x710, OLetter), (0x711, 0x711, Extend),
+        (0x712, 0x72F, OLetter), (0x730, 0x74A, Extend), (0x74D, 0x7A5, OLetter),
+        (0x7A6, 0x7B0, Extend), (0x7B1, 0x7B1, OLetter), (0x7C0, 0x7C9, Numeric),
+        (0x7CA, 0x7EA, OLetter), (0x7EB, 0x7F3, Extend), (0x7F4, 0x7F5, OLetter),
+        (0x7F8, 0x7F8, SContinue), (0x7F9, 0x7F9, STerm), (0x7FA, 0x7FA, OLetter),
+        (0x7FD, 0x7FD, Extend), (0x800, 0x815, OLetter), (0x816, 0x819, Extend),
+        (0x81A, 0x81A, OLetter), (0x81B, 0x823, Extend), (0x824, 0x824, OLetter),
+        (0x825, 0x827, Extend), (0x828, 0x828, OLetter), (0x829, 0x82D, Extend), (0x837, 0x837, STerm),
+        (0x839, 0x839, STerm), (0x83D, 0x83E, STerm), (0x840, 0x858, OLetter), (0x859, 0x85B, Extend),
+        (0x860, 0x86A, OLetter), (0x870, 0x887, OLetter), (0x889, 0x88E, OLetter),
+        (0x890, 0x891, Numeric), (0x898, 0x89F, Extend), (0x8A0, 0x8C9, OLetter),
+        (0x8CA, 0x8E1, Extend), (0x8E2, 0x8E2, Numeric), (0x8E3, 0x903, Extend),
+        (0x904, 0x939, OLetter), (0x93A, 0x93C, Extend), (0x93D, 0x93D, OLetter),
+        (0x93E, 0x94F, Extend), (0x950, 0x950, OLetter), (0x951, 0x957, Extend),
+        (0x958, 0x961, OLetter), (0x962, 0x963, Extend), (0x964, 0x965, STerm),
+        (0x966, 0x96F, Numeric), (0x971, 0x980, OLetter), (0x981, 0x983, Extend),
+        (0x985, 0x98C, OLetter), (0x98F, 0x990, OLetter), (0x993, 0x9A8, OLetter),
+        (0x9AA, 0x9B0, OLetter), (0x9B2, 0x9B2, OLetter), (0x9B6, 0x9B9, OLetter),
+        (0x9BC, 0x9BC, Extend), (0x9BD, 0x9BD, OLetter), (0x9BE, 0x9C4, Extend),
+        (0x9C7, 0x9C8, Extend), (0x9CB, 0x9CD, Extend), (0x9CE, 0x9CE, OLetter),
+        (0x9D7, 0x9D7, Extend), (0x9DC, 0x9DD, OLetter), (0x9DF, 0x9E1, OLetter),
+        (0x9E2, 0x9E3, Extend), (0x9E6, 0x9EF, Numeric), (0x9F0, 0x9F1, OLetter),
+        (0x9FC, 0x9FC, OLetter), (0x9FE, 0x9FE, Extend), (0xA01, 0xA03, Extend),
+        (0xA05, 0xA0A, OLetter), (0xA0F, 0xA10, OLetter), (0xA13, 0xA28, OLetter),
+        (0xA2A, 0xA30, OLetter), (0xA32, 0xA33, OLetter), (0xA35, 0xA36, OLetter),
+        (0xA38, 0xA39, OLetter), (0xA3C, 0xA3C, Extend), (0xA3E, 0xA42, Extend),
+        (0xA47, 0xA48, Extend), (0xA4B, 0xA4D, Extend), (0xA51, 0xA51, Extend),
+        (0xA59, 0xA5C, OLetter), (0xA5E, 0xA5E, OLetter), (0xA66, 0xA6F, Numeric),
+        (0xA70, 0xA71, Extend), (0xA72, 0xA74, OLetter), (0xA75, 0xA75, Extend),
+        (0xA81, 0xA83, Extend), (0xA85, 0xA8D, OLetter), (0xA8F, 0xA91, OLetter),
+        (0xA93, 0xAA8, OLetter), (0xAAA, 0xAB0, OLetter), (0xAB2, 0xAB3, OLetter),
+        (0xAB5, 0xAB9, OLetter), (0xABC, 0xABC, Extend), (0xABD, 0xABD, OLetter),
+        (0xABE, 0xAC5, Extend), (0xAC7, 0xAC9, Extend), (0xACB, 0xACD, Extend),
+        (0xAD0, 0xAD0, OLetter), (0xAE0, 0xAE1, OLetter), (0xAE2, 0xAE3, Extend),
+        (0xAE6, 0xAEF, Numeric), (0xAF9, 0xAF9, OLetter), (0xAFA, 0xAFF, Extend),
+        (0xB01, 0xB03, Extend), (0xB05, 0xB0C, OLetter), (0xB0F, 0xB10, OLetter),
+        (0xB13, 0xB28, OLetter), (0xB2A, 0xB30, OLetter), (0xB32, 0xB33, OLetter),
+        (0xB35, 0xB39, OLetter), (0xB3C, 0xB3C, Extend), (0xB3D, 0xB3D, OLetter),
+        (0xB3E, 0xB44, Extend), (0xB47, 0xB48, Extend), (0xB4B, 0xB4D, Extend), (0xB55, 0xB57, Extend),
+        (0xB5C, 0xB5D, OLetter), (0xB5F, 0xB61, OLetter), (0xB62, 0xB63, Extend),
+        (0xB66, 0xB6F, Numeric), (0xB71, 0xB71, OLetter), (0xB82, 0xB82, Extend),
+        (0xB83, 0xB83, OLetter), (0xB85, 0xB8A, OLetter), (0xB8E, 0xB90, OLetter),
+        (0xB92, 0xB95, OLetter), (0xB99, 0xB9A, OLetter), (0xB9C, 0xB9C, OLetter),
+        (0xB9E, 0xB9F, OLetter), (0xBA3, 0xBA4, OLetter), (0xBA8, 0xBAA, OLetter),
+        (0xBAE, 0xBB9, OLetter), (0xBBE, 0xBC2, Extend), (0xBC6, 0xBC8, Extend),
+        (0xBCA, 0xBCD, Extend), (0xBD0, 0xBD0, OLetter), (0xBD7, 0xBD7, Extend),
+        (0xBE6, 0xBEF, Numeric), (0xC00, 0xC04, Extend), (0xC05, 0xC0C, OLetter),
+        (0xC0E, 0xC10, OLetter), (0xC12, 0xC28, OLetter), (0xC2A, 0xC39, OLetter),
+        (0xC3C, 0xC3C, Extend), (0xC3D, 0xC3D, OLetter), (0xC3E, 0xC44, Extend),
+        (0xC46, 0xC48, Extend), (0xC4A, 0xC4D, Extend), (0xC55, 0xC56, Extend),
+        (0xC58, 0xC5A, OLetter), (0xC5D, 0xC5D, OLetter), (0xC60, 0xC61, OLetter),
+        (0xC62, 0xC63, Extend), (0xC66, 0xC6F, Numeric), (0xC80, 0xC80, OLetter),
+        (0xC81, 0xC83, Extend), (0xC85, 0xC8C, OLetter), (0xC8E, 0xC90, OLetter),
+        (0xC92, 0xCA8, OLetter), (0xCAA, 0xCB3, OLetter), (0xCB5, 0xCB9, OLetter),
+        (0xCBC, 0xCBC, Extend), (0xCBD, 0xCBD, OLetter), (0xCBE, 0xCC4, Extend),
+        (0xCC6, 0xCC8, Extend), (0xCCA, 0xCCD, Extend), (0xCD5, 0xCD6, Extend),
+        (0xCDD, 0xCDE, OLetter), (0xCE0, 0xCE1, OLetter), (0xCE2, 0xCE3, Extend),
+        (0xCE6, 0xCEF, Numeric), (0xCF1, 0xCF2, OLetter), (0xCF3, 0xCF3, Extend),
+        (0xD00, 0xD03, Extend), (0xD04, 0xD0C, OLetter), (0xD0E, 0xD10, OLetter),
+        (0xD12, 0xD3A, OLetter), (0xD3B, 0xD3C, Extend), (0xD3D, 0xD3D, OLetter),
+        (0xD3E, 0xD44, Extend), (0xD46, 0xD48, Extend), (0xD4A, 0xD4D, Extend),
+        (0xD4E, 0xD4E, OLetter), (0xD54, 0xD56, OLetter), (0xD57, 0xD57, Extend),
+        (0xD5F, 0xD61, OLetter), (0xD62, 0xD63, Extend), (0xD66, 0xD6F, Numeric),
+        (0xD7A, 0xD7F, OLetter), (0xD81, 0xD83, Extend), (0xD85, 0xD96, OLetter),
+        (0xD9A, 0xDB1, OLetter), (0xDB3, 0xDBB, OLetter), (0xDBD, 0xDBD, OLetter),
+        (0xDC0, 0xDC6, OLetter), (0xDCA, 0xDCA, Extend), (0xDCF, 0xDD4, Extend),
+        (0xDD6, 0xDD6, Extend), (0xDD8, 0xDDF, Extend), (0xDE6, 0xDEF, Numeric),
+        (0xDF2, 0xDF3, Extend), (0xE01, 0xE30, OLetter), (0xE31, 0xE31, Extend),
+        (0xE32, 0xE33, OLetter), (0xE34, 0xE3A, Extend), (0xE40, 0xE46, OLetter),
+        (0xE47, 0xE4E, Extend), (0xE50, 0xE59, Numeric), (0xE81, 0xE82, OLetter),
+        (0xE84, 0xE84, OLetter), (0xE86, 0xE8A, OLetter), (0xE8C, 0xEA3, OLetter),
+        (0xEA5, 0xEA5, OLetter), (0xEA7, 0xEB0, OLetter), (0xEB1, 0xEB1, Extend),
+        (0xEB2, 0xEB3, OLetter), (0xEB4, 0xEBC, Extend), (0xEBD, 0xEBD, OLetter),
+        (0xEC0, 0xEC4, OLetter), (0xEC6, 0xEC6, OLetter), (0xEC8, 0xECE, Extend),
+        (0xED0, 0xED9, Numeric), (0xEDC, 0xEDF, OLetter), (0xF00, 0xF00, OLetter),
+        (0xF18, 0xF19, Extend), (0xF20, 0xF29, Numeric), (0xF35, 0xF35, Extend),
+        (0xF37, 0xF37, Extend), (0xF39, 0xF39, Extend), (0xF3A, 0xF3D, Close), (0xF3E, 0xF3F, Extend),
+        (0xF40, 0xF47, OLetter), (0xF49, 0xF6C, OLetter), (0xF71, 0xF84, Extend),
+        (0xF86, 0xF87, Extend), (0xF88, 0xF8C, OLetter), (0xF8D, 0xF97, Extend),
+        (0xF99, 0xFBC, Extend), (0xFC6, 0xFC6, Extend), (0x1000, 0x102A, OLetter),
+        (0x102B, 0x103E, Extend), (0x103F, 0x103F, OLetter), (0x1040, 0x1049, Numeric),
+        (0x104A, 0x104B, STerm), (0x1050, 0x1055, OLetter), (0x1056, 0x1059, Extend),
+        (0x105A, 0x105D, OLetter), (0x105E, 0x1060, Extend), (0x1061, 0x1061, OLetter),
+        (0x1062, 0x1064, Extend), (0x1065, 0x1066, OLetter), (0x1067, 0x106D, Extend),
+        (0x106E, 0x1070, OLetter), (0x1071, 0x1074, Extend), (0x1075, 0x1081, OLetter),
+        (0x1082, 0x108D, Extend), (0x108E, 0x108E, OLetter), (0x108F, 0x108F, Extend),
+        (0x1090, 0x1099, Numeric), (0x109A, 0x109D, Extend), (0x10A0, 0x10C5, Upper),
+        (0x10C7, 0x10C7, Upper), (0x10CD, 0x10CD, Upper), (0x10D0, 0x10FA, OLetter),
+        (0x10FC, 0x10FC, Lower), (0x10FD, 0x1248, OLetter), (0x124A, 0x124D, OLetter),
+        (0x1250, 0x1256, OLetter), (0x1258, 0x1258, OLetter), (0x125A, 0x125D, OLetter),
+        (0x1260, 0x1288, OLetter), (0x128A, 0x128D, OLetter), (0x1290, 0x12B0, OLetter),
+        (0x12B2, 0x12B5, OLetter), (0x12B8, 0x12BE, OLetter), (0x12C0, 0x12C0, OLetter),
+        (0x12C2, 0x12C5, OLetter), (0x12C8, 0x12D6, OLetter), (0x12D8, 0x1310, OLetter),
+        (0x1312, 0x1315, OLetter), (0x1318, 0x135A, OLetter), (0x135D, 0x135F, Extend),
+        (0x1362, 0x1362, STerm), (0x1367, 0x1368, STerm), (0x1380, 0x138F, OLetter),
+        (0x13A0, 0x13F5, Upper), (0x13F8, 0x13FD, Lower), (0x1401, 0x166C, OLetter),
+        (0x166E, 0x166E, STerm), (0x166F, 0x167F, OLetter), (0x1680, 0x1680, Sp),
+        (0x1681, 0x169A, OLetter), (0x169B, 0x169C, Close), (0x16A0, 0x16EA, OLetter),
+        (0x16EE, 0x16F8, OLetter), (0x1700, 0x1711, OLetter), (0x1712, 0x1715, Extend),
+        (0x171F, 0x1731, OLetter), (0x1732, 0x1734, Extend), (0x1735, 0x1736, STerm),
+        (0x1740, 0x1751, OLetter), (0x1752, 0x1753, Extend), (0x1760, 0x176C, OLetter),
+        (0x176E, 0x1770, OLetter), (0x1772, 0x1773, Extend), (0x1780, 0x17B3, OLetter),
+        (0x17B4, 0x17D3, Extend), (0x17D4, 0x17D5, STerm), (0x17D7, 0x17D7, OLetter),
+        (0x17DC, 0x17DC, OLetter), (0x17DD, 0x17DD, Extend), (0x17E0, 0x17E9, Numeric),
+        (0x1802, 0x1802, SContinue), (0x1803, 0x1803, STerm), (0x1808, 0x1808, SContinue),
+        (0x1809, 0x1809, STerm), (0x180B, 0x180D, Extend), (0x180E, 0x180E, Format),
+        (0x180F, 0x180F, Extend), (0x1810, 0x1819, Numeric), (0x1820, 0x1878, OLetter),
+        (0x1880, 0x1884, OLetter), (0x1885, 0x1886, Extend), (0x1887, 0x18A8, OLetter),
+        (0x18A9, 0x18A9, Extend), (0x18AA, 0x18AA, OLetter), (0x18B0, 0x18F5, OLetter),
+        (0x1900, 0x191E, OLetter), (0x1920, 0x192B, Extend), (0x1930, 0x193B, Extend),
+        (0x1944, 0x1945, STerm), (0x1946, 0x194F, Numeric), (0x1950, 0x196D, OLetter),
+        (0x1970, 0x1974, OLetter), (0x1980, 0x19AB, OLetter), (0x19B0, 0x19C9, OLetter),
+        (0x19D0, 0x19D9, Numeric), (0x1A00, 0x1A16, OLetter), (0x1A17, 0x1A1B, Extend),
+        (0x1A20, 0x1A54, OLetter), (0x1A55, 0x1A5E, Extend), (0x1A60, 0x1A7C, Extend),
+        (0x1A7F, 0x1A7F, Extend), (0x1A80, 0x1A89, Numeric), (0x1A90, 0x1A99, Numeric),
+        (0x1AA7, 0x1AA7, OLetter), (0x1AA8, 0x1AAB, STerm), (0x1AB0, 0x1ACE, Extend),
+        (0x1B00, 0x1B04, Extend), (0x1B05, 0x1B33, OLetter), (0x1B34, 0x1B44, Extend),
+        (0x1B45, 0x1B4C, OLetter), (0x1B50, 0x1B59, Numeric), (0x1B5A, 0x1B5B, STerm),
+        (0x1B5E, 0x1B5F, STerm), (0x1B6B, 0x1B73, Extend), (0x1B7D, 0x1B7E, STerm),
+        (0x1B80, 0x1B82, Extend), (0x1B83, 0x1BA0, OLetter), (0x1BA1, 0x1BAD, Extend),
+        (0x1BAE, 0x1BAF, OLetter), (0x1BB0, 0x1BB9, Numeric), (0x1BBA, 0x1BE5, OLetter),
+        (0x1BE6, 0x1BF3, Extend), (0x1C00, 0x1C23, OLetter), (0x1C24, 0x1C37, Extend),
+        (0x1C3B, 0x1C3C, STerm), (0x1C40, 0x1C49, Numeric), (0x1C4D, 0x1C4F, OLetter),
+        (0x1C50, 0x1C59, Numeric), (0x1C5A, 0x1C7D, OLetter), (0x1C7E, 0x1C7F, STerm),
+        (0x1C80, 0x1C88, Lower), (0x1C90, 0x1CBA, OLetter), (0x1CBD, 0x1CBF, OLetter),
+        (0x1CD0, 0x1CD2, Extend), (0x1CD4, 0x1CE8, Extend), (0x1CE9, 0x1CEC, OLetter),
+        (0x1CED, 0x1CED, Extend), (0x1CEE, 0x1CF3, OLetter), (0x1CF4, 0x1CF4, Extend),
+        (0x1CF5, 0x1CF6, OLetter), (0x1CF7, 0x1CF9, Extend), (0x1CFA, 0x1CFA, OLetter),
+        (0x1D00, 0x1DBF, Lower), (0x1DC0, 0x1DFF, Extend), (0x1E00, 0x1E00, Upper),
+        (0x1E01, 0x1E01, Lower), (0x1E02, 0x1E02, Upper), (0x1E03, 0x1E03, Lower),
+        (0x1E04, 0x1E04, Upper), (0x1E05, 0x1E05, Lower), (0x1E06, 0x1E06, Upper),
+        (0x1E07, 0x1E07, Lower), (0x1E08, 0x1E08, Upper), (0x1E09, 0x1E09, Lower),
+        (0x1E0A, 0x1E0A, Upper), (0x1E0B, 0x1E0B, Lower), (0x1E0C, 0x1E0C, Upper),
+        (0x1E0D, 0x1E0D, Lower), (0x1E0E, 0x1E0E, Upper), (0x1E0F, 0x1E0F, Lower),
+        (0x1E10, 0x1E10, Upper), (0x1E11, 0x1E11, Lower), (0x1E12, 0x1E12, Upper),
+        (0x1E13, 0x1E13, Lower), (0x1E14, 0x1E14, Upper), (0x1E15, 0x1E15, Lower),
+        (0x1E16, 0x1E16, Upper), (0x1E17, 0x1E17, Lower), (0x1E18, 0x1E18, Upper),
+        (0x1E19, 0x1E19, Lower), (0x1E1A, 0x1E1A, Upper), (0x1E1B, 0x1E1B, Lower),
+        (0x1E1C, 0x1E1C, Upper), (0x1E1D, 0x1E1D, Lower), (0x1E1E, 0x1E1E, Upper),
+        (0x1E1F, 0x1E1F, Lower), (0x1E20, 0x1E20, Upper), (0x1E21, 0x1E21, Lower),
+        (0x1E22, 0x1E22, Upper), (0x1E23, 0x1E23, Lower), (0x1E24, 0x1E24, Upper),
+        (0x1E25, 0x1E25, Lower), (0x1E26, 0x1E26, Upper), (0x1E27, 0x1E27, Lower),
+        (0x1E28, 0x1E28, Upper), (0x1E29, 0x1E29, Lower), (0x1E2A, 0x1E2A, Upper),
+        (0x1E2B, 0x1E2B, Lower), (0x1E2C, 0x1E2C, Upper), (0x1E2D, 0x1E2D, Lower),
+        (0x1E2E, 0x1E2E, Upper), (0x1E2F, 0x1E2F, Lower), (0x1E30, 0x1E30, Upper),
+        (0x1E31, 0x1E31, Lower), (0x1E32, 0x1E32, Upper), (0x1E33, 0x1E33, Lower),
+        (0x1E34, 0x1E34, Upper), (0x1E35, 0x1E35, Lower), (0x1E36, 0x1E36, Upper),
+        (0x1E37, 0x1E37, Lower), (0x1E38, 0x1E38, Upper), (0x1E39, 0x1E39, Lower),
+        (0x1E3A, 0x1E3A, Upper), (0x1E3B, 0x1E3B, Lower), (0x1E3C, 0x1E3C, Upper),
+        (0x1E3D, 0x1E3D, Lower), (0x1E3E, 0x1E3E, Upper), (0x1E3F, 0x1E3F, Lower),
+        (0x1E40, 0x1E40, Upper), (0x1E41, 0x1E41, Lower), (0x1E42, 0x1E42, Upper),
+        (0x1E43, 0x1E43, Lower), (0x1E44, 0x1E44, Upper), (0x1E45, 0x1E45, Lower),
+        (0x1E46, 0x1E46, Upper), (0x1E47, 0x1E47, Lower), (0x1E48, 0x1E48, Upper),
+        (0x1E49, 0x1E49, Lower), (0x1E4A, 0x1E4A, Upper), (0x1E4B, 0x1E4B, Lower),
+        (0x1E4C, 0x1E4C, Upper), (0x1E4D, 0x1E4D, Lower), (0x1E4E, 0x1E4E, Upper),
+        (0x1E4F, 0x1E4F, Lower), (0x1E50, 0x1E50, Upper), (0x1E51, 0x1E51, Lower),
+        (0x1E52, 0x1E52, Upper), (0x1E53, 0x1E53, Lower), (0x1E54, 0x1E54, Upper),
+        (0x1E55, 0x1E55, Lower), (0x1E56, 0x1E56, Upper), (0x1E57, 0x1E57, Lower),
+        (0x1E58, 0x1E58, Upper), (0x1E59, 0x1E59, Lower), (0x1E5A, 0x1E5A, Upper),
+        (0x1E5B, 0x1E5B, Lower), (0x1E5C, 0x1E5C, Upper), (0x1E5D, 0x1E5D, Lower),
+        (0x1E5E, 0x1E5E, Upper), (0x1E5F, 0x1E5F, Lower), (0x1E60, 0x1E60, Upper),
+        (0x1E61, 0x1E61, Lower), (0x1E62, 0x1E62, Upper), (0x1E63, 0x1E63, Lower),
+        (0x1E64, 0x1E64, Upper), (0x1E65, 0x1E65, Lower), (0x1E66, 0x1E66, Upper),
+        (0x1E67, 0x1E67, Lower), (0x1E68, 0x1E68, Upper), (0x1E69, 0x1E69, Lower),
+        (0x1E6A, 0x1E6A, Upper), (0x1E6B, 0x1E6B, Lower), (0x1E6C, 0x1E6C, Upper),
+        (0x1E6D, 0x1E6D, Lower), (0x1E6E, 0x1E6E, Upper), (0x1E6F, 0x1E6F, Lower),
+        (0x1E70, 0x1E70, Upper), (0x1E71, 0x1E71, Lower), (0x1E72, 0x1E72, Upper),
+        (0x1E73, 0x1E73, Lower), (0x1E74, 0x1E74, Upper), (0x1E75, 0x1E75, Lower),
+        (0x1E76, 0x1E76, Upper), (0x1E77, 0x1E77, Lower), (0x1E78, 0x1E78, Upper),
+        (0x1E79, 0x1E79, Lower), (0x1E7A, 0x1E7A, Upper), (0x1E7B, 0x1E7B, Lower),
+        (0x1E7C, 0x1E7C, Upper), (0x1E7D, 0x1E7D, Lower), (0x1E7E, 0x1E7E, Upper),
+        (0x1E7F, 0x1E7F, Lower), (0x1E80, 0x1E80, Upper), (0x1E81, 0x1E81, Lower),
+        (0x1E82, 0x1E82, Upper), (0x1E83, 0x1E83, Lower), (0x1E84, 0x1E84, Upper),
+        (0x1E85, 0x1E85, Lower), (0x1E86, 0x1E86, Upper), (0x1E87, 0x1E87, Lower),
+        (0x1E88, 0x1E88, Upper), (0x1E89, 0x1E89, Lower), (0x1E8A, 0x1E8A, Upper),
+        (0x1E8B, 0x1E8B, Lower), (0x1E8C, 0x1E8C, Upper), (0x1E8D, 0x1E8D, Lower),
+        (0x1E8E, 0x1E8E, Upper), (0x1E8F, 0x1E8F, Lower), (0x1E90, 0x1E90, Upper),
+        (0x1E91, 0x1E91, Lower), (0x1E92, 0x1E92, Upper), (0x1E93, 0x1E93, Lower),
+        (0x1E94, 0x1E94, Upper), (0x1E95, 0x1E9D, Lower), (0x1E9E, 0x1E9E, Upper),
+        (0x1E9F, 0x1E9F, Lower), (0x1EA0, 0x1EA0, Upper), (0x1EA1, 0x1EA1, Lower),
+        (0x1EA2, 0x1EA2, Upper), (0x1EA3, 0x1EA3, Lower), (0x1EA4, 0x1EA4, Upper),
+        (0x1EA5, 0x1EA5, Lower), (0x1EA6, 0x1EA6, Upper), (0x1EA7, 0x1EA7, Lower),
+        (0x1EA8, 0x1EA8, Upper), (0x1EA9, 0x1EA9, Lower), (0x1EAA, 0x1EAA, Upper),
+        (0x1EAB, 0x1EAB, Lower), (0x1EAC, 0x1EAC, Upper), (0x1EAD, 0x1EAD, Lower),
+        (0x1EAE, 0x1EAE, Upper), (0x1EAF, 0x1EAF, Lower), (0x1EB0, 0x1EB0, Upper),
+        (0x1EB1, 0x1EB1, Lower), (0x1EB2, 0x1EB2, Upper), (0x1EB3, 0x1EB3, Lower),
+        (0x1EB4, 0x1EB4, Upper), (0x1EB5, 0x1EB5, Lower), (0x1EB6, 0x1EB6, Upper),
+        (0x1EB7, 0x1EB7, Lower), (0x1EB8, 0x1EB8, Upper), (0x1EB9, 0x1EB9, Lower),
+        (0x1EBA, 0x1EBA, Upper), (0x1EBB, 0x1EBB, Lower), (0x1EBC, 0x1EBC, Upper),
+        (0x1EBD, 0x1EBD, Lower), (0x1EBE, 0x1EBE, Upper), (0x1EBF, 0x1EBF, Lower),
+        (0x1EC0, 0x1EC0, Upper), (0x1EC1, 0x1EC1, Lower), (0x1EC2, 0x1EC2, Upper),
+        (0x1EC3, 0x1EC3, Lower), (0x1EC4, 0x1EC4, Upper), (0x1EC5, 0x1EC5, Lower),
+        (0x1EC6, 0x1EC6, Upper), (0x1EC7, 0x1EC7, Lower), (0x1EC8, 0x1EC8, Upper),
+        (0x1EC9, 0x1EC9, Lower), (0x1ECA, 0x1ECA, Upper), (0x1ECB, 0x1ECB, Lower),
+        (0x1ECC, 0x1ECC, Upper), (0x1ECD, 0x1ECD, Lower), (0x1ECE, 0x1ECE, Upper),
+        (0x1ECF, 0x1ECF, Lower), (0x1ED0, 0x1ED0, Upper), (0x1ED1, 0x1ED1, Lower),
+        (0x1ED2, 0x1ED2, Upper), (0x1ED3, 0x1ED3, Lower), (0x1ED4, 0x1ED4, Upper),
+        (0x1ED5, 0x1ED5, Lower), (0x1ED6, 0x1ED6, Upper), (0x1ED7, 0x1ED7, Lower),
+        (0x1ED8, 0x1ED8, Upper), (0x1ED9, 0x1ED9, Lower), (0x1EDA, 0x1EDA, Upper),
+        (0x1EDB, 0x1EDB, Lower), (0x1EDC, 0x1EDC, Upper), (0x1EDD, 0x1EDD, Lower),
+        (0x1EDE, 0x1EDE, Upper), (0x1EDF, 0x1EDF, Lower), (0x1EE0, 0x1EE0, Upper),
+        (0x1EE1, 0x1EE1, Lower), (0x1EE2, 0x1EE2, Upper), (0x1EE3, 0x1EE3, Lower),
+        (0x1EE4, 0x1EE4, Upper), (0x1EE5, 0x1EE5, Lower), (0x1EE6, 0x1EE6, Upper),
+        (0x1EE7, 0x1EE7, Lower), (0x1EE8, 0x1EE8, Upper), (0x1EE9, 0x1EE9, Lower),
+        (0x1EEA, 0x1EEA, Upper), (0x1EEB, 0x1EEB, Lower), (0x1EEC, 0x1EEC, Upper),
+        (0x1EED, 0x1EED, Lower), (0x1EEE, 0x1EEE, Upper), (0x1EEF, 0x1EEF, Lower),
+        (0x1EF0, 0x1EF0, Upper), (0x1EF1, 0x1EF1, Lower), (0x1EF2, 0x1EF2, Upper),
+        (0x1EF3, 0x1EF3, Lower), (0x1EF4, 0x1EF4, Upper), (0x1EF5, 0x1EF5, Lower),
+        (0x1EF6, 0x1EF6, Upper), (0x1EF7, 0x1EF7, Lower), (0x1EF8, 0x1EF8, Upper),
+        (0x1EF9, 0x1EF9, Lower), (0x1EFA, 0x1EFA, Upper), (0x1EFB, 0x1EFB, Lower),
+        (0x1EFC, 0x1EFC, Upper), (0x1EFD, 0x1EFD, Lower), (0x1EFE, 0x1EFE, Upper),
+        (0x1EFF, 0x1F07, Lower), (0x1F08, 0x1F0F, Upper), (0x1F10, 0x1F15, Lower),
+        (0x1F18, 0x1F1D, Upper), (0x1F20, 0x1F27, Lower), (0x1F28, 0x1F2F, Upper),
+        (0x1F30, 0x1F37, Lower), (0x1F38, 0x1F3F, Upper), (0x1F40, 0x1F45, Lower),
+        (0x1F48, 0x1F4D, Upper), (0x1F50, 0x1F57, Lower), (0x1F59, 0x1F59, Upper),
+        (0x1F5B, 0x1F5B, Upper), (0x1F5D, 0x1F5D, Upper), (0x1F5F, 0x1F5F, Upper),
+        (0x1F60, 0x1F67, Lower), (0x1F68, 0x1F6F, Upper), (0x1F70, 0x1F7D, Lower),
+        (0x1F80, 0x1F87, Lower), (0x1F88, 0x1F8F, Upper), (0x1F90, 0x1F97, Lower),
+        (0x1F98, 0x1F9F, Upper), (0x1FA0, 0x1FA7, Lower), (0x1FA8, 0x1FAF, Upper),
+        (0x1FB0, 0x1FB4, Lower), (0x1FB6, 0x1FB7, Lower), (0x1FB8, 0x1FBC, Upper),
+        (0x1FBE, 0x1FBE, Lower), (0x1FC2, 0x1FC4, Lower), (0x1FC6, 0x1FC7, Lower),
+        (0x1FC8, 0x1FCC, Upper), (0x1FD0, 0x1FD3, Lower), (0x1FD6, 0x1FD7, Lower),
+        (0x1FD8, 0x1FDB, Upper), (0x1FE0, 0x1FE7, Lower), (0x1FE8, 0x1FEC, Upper),
+        (0x1FF2, 0x1FF4, Lower), (0x1FF6, 0x1FF7, Lower), (0x1FF8, 0x1FFC, Upper),
+        (0x2000, 0x200A, Sp), (0x200B, 0x200B, Format), (0x200C, 0x200D, Extend),
+        (0x200E, 0x200F, Format), (0x2013, 0x2014, SContinue), (0x2018, 0x201F, Close),
+        (0x2024, 0x2024, ATerm), (0x2028, 0x2029, Sep), (0x202A, 0x202E, Format), (0x202F, 0x202F, Sp),
+        (0x2039, 0x203A, Close), (0x203C, 0x203D, STerm), (0x2045, 0x2046, Close),
+        (0x2047, 0x2049, STerm), (0x205F, 0x205F, Sp), (0x2060, 0x2064, Format),
+        (0x2066, 0x206F, Format), (0x2071, 0x2071, Lower), (0x207D, 0x207E, Close),
+        (0x207F, 0x207F, Lower), (0x208D, 0x208E, Close), (0x2090, 0x209C, Lower),
+        (0x20D0, 0x20F0, Extend), (0x2102, 0x2102, Upper), (0x2107, 0x2107, Upper),
+        (0x210A, 0x210A, Lower), (0x210B, 0x210D, Upper), (0x210E, 0x210F, Lower),
+        (0x2110, 0x2112, Upper), (0x2113, 0x2113, Lower), (0x2115, 0x2115, Upper),
+        (0x2119, 0x211D, Upper), (0x2124, 0x2124, Upper), (0x2126, 0x2126, Upper),
+        (0x2128, 0x2128, Upper), (0x212A, 0x212D, Upper), (0x212F, 0x212F, Lower),
+        (0x2130, 0x2133, Upper), (0x2134, 0x2134, Lower), (0x2135, 0x2138, OLetter),
+        (0x2139, 0x2139, Lower), (0x213C, 0x213D, Lower), (0x213E, 0x213F, Upper),
+        (0x2145, 0x2145, Upper), (0x2146, 0x2149, Lower), (0x214E, 0x214E, Lower),
+        (0x2160, 0x216F, Upper), (0x2170, 0x217F, Lower), (0x2180, 0x2182, OLetter),
+        (0x2183, 0x2183, Upper), (0x2184, 0x2184, Lower), (0x2185, 0x2188, OLetter),
+        (0x2308, 0x230B, Close), (0x2329, 0x232A, Close), (0x24B6, 0x24CF, Upper),
+        (0x24D0, 0x24E9, Lower), (0x275B, 0x2760, Close), (0x2768, 0x2775, Close),
+        (0x27C5, 0x27C6, Close), (0x27E6, 0x27EF, Close), (0x2983, 0x2998, Close),
+        (0x29D8, 0x29DB, Close), (0x29FC, 0x29FD, Close), (0x2C00, 0x2C2F, Upper),
+        (0x2C30, 0x2C5F, Lower), (0x2C60, 0x2C60, Upper), (0x2C61, 0x2C61, Lower),
+        (0x2C62, 0x2C64, Upper), (0x2C65, 0x2C66, Lower), (0x2C67, 0x2C67, Upper),
+        (0x2C68, 0x2C68, Lower), (0x2C69, 0x2C69, Upper), (0x2C6A, 0x2C6A, Lower),
+        (0x2C6B, 0x2C6B, Upper), (0x2C6C, 0x2C6C, Lower), (0x2C6D, 0x2C70, Upper),
+        (0x2C71, 0x2C71, Lower), (0x2C72, 0x2C72, Upper), (0x2C73, 0x2C74, Lower),
+        (0x2C75, 0x2C75, Upper), (0x2C76, 0x2C7D, Lower), (0x2C7E, 0x2C80, Upper),
+        (0x2C81, 0x2C81, Lower), (0x2C82, 0x2C82, Upper), (0x2C83, 0x2C83, Lower),
+        (0x2C84, 0x2C84, Upper), (0x2C85, 0x2C85, Lower), (0x2C86, 0x2C86, Upper),
+        (0x2C87, 0x2C87, Lower), (0x2C88, 0x2C88, Upper), (0x2C89, 0x2C89, Lower),
+        (0x2C8A, 0x2C8A, Upper), (0x2C8B, 0x2C8B, Lower), (0x2C8C, 0x2C8C, Upper),
+        (0x2C8D, 0x2C8D, Lower), (0x2C8E, 0x2C8E, Upper), (0x2C8F, 0x2C8F, Lower),
+        (0x2C90, 0x2C90, Upper), (0x2C91, 0x2C91, Lower), (0x2C92, 0x2C92, Upper),
+        (0x2C93, 0x2C93, Lower), (0x2C94, 0x2C94, Upper), (0x2C95, 0x2C95, Lower),
+        (0x2C96, 0x2C96, Upper), (0x2C97, 0x2C97, Lower), (0x2C98, 0x2C98, Upper),
+        (0x2C99, 0x2C99, Lower), (0x2C9A, 0x2C9A, Upper), (0x2C9B, 0x2C9B, Lower),
+        (0x2C9C, 0x2C9C, Upper), (0x2C9D, 0x2C9D, Lower), (0x2C9E, 0x2C9E, Upper),
+        (0x2C9F, 0x2C9F, Lower), (0x2CA0, 0x2CA0, Upper), (0x2CA1, 0x2CA1, Lower),
+        (0x2CA2, 0x2CA2, Upper), (0x2CA3, 0x2CA3, Lower), (0x2CA4, 0x2CA4, Upper),
+        (0x2CA5, 0x2CA5, Lower), (0x2CA6, 0x2CA6, Upper), (0x2CA7, 0x2CA7, Lower),
+        (0x2CA8, 0x2CA8, Upper), (0x2CA9, 0x2CA9, Lower), (0x2CAA, 0x2CAA, Upper),
+        (0x2CAB, 0x2CAB, Lower), (0x2CAC, 0x2CAC, Upper), (0x2CAD, 0x2CAD, Lower),
+        (0x2CAE, 0x2CAE, Upper), (0x2CAF, 0x2CAF, Lower), (0x2CB0, 0x2CB0, Upper),
+        (0x2CB1, 0x2CB1, Lower), (0x2CB2, 0x2CB2, Upper), (0x2CB3, 0x2CB3, Lower),
+        (0x2CB4, 0x2CB4, Upper), (0x2CB5, 0x2CB5, Lower), (0x2CB6, 0x2CB6, Upper),
+        (0x2CB7, 0x2CB7, Lower), (0x2CB8, 0x2CB8, Upper), (0x2CB9, 0x2CB9, Lower),
+        (0x2CBA, 0x2CBA, Upper), (0x2CBB, 0x2CBB, Lower), (0x2CBC, 0x2CBC, Upper),
+        (0x2CBD, 0x2CBD, Lower), (0x2CBE, 0x2CBE, Upper), (0x2CBF, 0x2CBF, Lower),
+        (0x2CC0, 0x2CC0, Upper), (0x2CC1, 0x2CC1, Lower), (0x2CC2, 0x2CC2, Upper),
+        (0x2CC3, 0x2CC3, Lower), (0x2CC4, 0x2CC4, Upper), (0x2CC5, 0x2CC5, Lower),
+        (0x2CC6, 0x2CC6, Upper), (0x2CC7, 0x2CC7, Lower), (0x2CC8, 0x2CC8, Upper),
+        (0x2CC9, 0x2CC9, Lower), (0x2CCA, 0x2CCA, Upper), (0x2CCB, 0x2CCB, Lower),
+        (0x2CCC, 0x2CCC, Upper), (0x2CCD, 0x2CCD, Lower), (0x2CCE, 0x2CCE, Upper),
+        (0x2CCF, 0x2CCF, Lower), (0x2CD0, 0x2CD0, Upper), (0x2CD1, 0x2CD1, Lower),
+        (0x2CD2, 0x2CD2, Upper), (0x2CD3, 0x2CD3, Lower), (0x2CD4, 0x2CD4, Upper),
+        (0x2CD5, 0x2CD5, Lower), (0x2CD6, 0x2CD6, Upper), (0x2CD7, 0x2CD7, Lower),
+        (0x2CD8, 0x2CD8, Upper), (0x2CD9, 0x2CD9, Lower), (0x2CDA, 0x2CDA, Upper),
+        (0x2CDB, 0x2CDB, Lower), (0x2CDC, 0x2CDC, Upper), (0x2CDD, 0x2CDD, Lower),
+        (0x2CDE, 0x2CDE, Upper), (0x2CDF, 0x2CDF, Lower), (0x2CE0, 0x2CE0, Upper),
+        (0x2CE1, 0x2CE1, Lower), (0x2CE2, 0x2CE2, Upper), (0x2CE3, 0x2CE4, Lower),
+        (0x2CEB, 0x2CEB, Upper), (0x2CEC, 0x2CEC, Lower), (0x2CED, 0x2CED, Upper),
+        (0x2CEE, 0x2CEE, Lower), (0x2CEF, 0x2CF1, Extend), (0x2CF2, 0x2CF2, Upper),
+        (0x2CF3, 0x2CF3, Lower), (0x2D00, 0x2D25, Lower), (0x2D27, 0x2D27, Lower),
+        (0x2D2D, 0x2D2D, Lower), (0x2D30, 0x2D67, OLetter), (0x2D6F, 0x2D6F, OLetter),
+        (0x2D7F, 0x2D7F, Extend), (0x2D80, 0x2D96, OLetter), (0x2DA0, 0x2DA6, OLetter),
+        (0x2DA8, 0x2DAE, OLetter), (0x2DB0, 0x2DB6, OLetter), (0x2DB8, 0x2DBE, OLetter),
+        (0x2DC0, 0x2DC6, OLetter), (0x2DC8, 0x2DCE, OLetter), (0x2DD0, 0x2DD6, OLetter),
+        (0x2DD8, 0x2DDE, OLetter), (0x2DE0, 0x2DFF, Extend), (0x2E00, 0x2E0D, Close),
+        (0x2E1C, 0x2E1D, Close), (0x2E20, 0x2E29, Close), (0x2E2E, 0x2E2E, STerm),
+        (0x2E2F, 0x2E2F, OLetter), (0x2E3C, 0x2E3C, STerm), (0x2E42, 0x2E42, Close),
+        (0x2E53, 0x2E54, STerm), (0x2E55, 0x2E5C, Close), (0x3000, 0x3000, Sp),
+        (0x3001, 0x3001, SContinue), (0x3002, 0x3002, STerm), (0x3005, 0x3007, OLetter),
+        (0x3008, 0x3011, Close), (0x3014, 0x301B, Close), (0x301D, 0x301F, Close),
+        (0x3021, 0x3029, OLetter), (0x302A, 0x302F, Extend), (0x3031, 0x3035, OLetter),
+        (0x3038, 0x303C, OLetter), (0x3041, 0x3096, OLetter), (0x3099, 0x309A, Extend),
+        (0x309D, 0x309F, OLetter), (0x30A1, 0x30FA, OLetter), (0x30FC, 0x30FF, OLetter),
+        (0x3105, 0x312F, OLetter), (0x3131, 0x318E, OLetter), (0x31A0, 0x31BF, OLetter),
+        (0x31F0, 0x31FF, OLetter), (0x3400, 0x4DBF, OLetter), (0x4E00, 0xA48C, OLetter),
+        (0xA4D0, 0xA4FD, OLetter), (0xA4FF, 0xA4FF, STerm), (0xA500, 0xA60C, OLetter),
+        (0xA60E, 0xA60F, STerm), (0xA610, 0xA61F, OLetter), (0xA620, 0xA629, Numeric),
+        (0xA62A, 0xA62B, OLetter), (0xA640, 0xA640, Upper), (0xA641, 0xA641, Lower),
+        (0xA642, 0xA642, Upper), (0xA643, 0xA643, Lower), (0xA644, 0xA644, Upper),
+        (0xA645, 0xA645, Lower), (0xA646, 0xA646, Upper), (0xA647, 0xA647, Lower),
+        (0xA648, 0xA648, Upper), (0xA649, 0xA649, Lower), (0xA64A, 0xA64A, Upper),
+        (0xA64B, 0xA64B, Lower), (0xA64C, 0xA64C, Upper), (0xA64D, 0xA64D, Lower),
+        (0xA64E, 0xA64E, Upper), (0xA64F, 0xA64F, Lower), (0xA650, 0xA650, Upper),
+        (0xA651, 0xA651, Lower), (0xA652, 0xA652, Upper), (0xA653, 0xA653, Lower),
+        (0xA654, 0xA654, Upper), (0xA655, 0xA655, Lower), (0xA656, 0xA656, Upper),
+        (0xA657, 0xA657, Lower), (0xA658, 0xA658, Upper), (0xA659, 0xA659, Lower),
+        (0xA65A, 0xA65A, Upper), (0xA65B, 0xA65B, Lower), (0xA65C, 0xA65C, Upper),
+        (0xA65D, 0xA65D, Lower), (0xA65E, 0xA65E, Upper), (0xA65F, 0xA65F, Lower),
+        (0xA660, 0xA660, Upper), (0xA661, 0xA661, Lower), (0xA662, 0xA662, Upper),
+        (0xA663, 0xA663, Lower), (0xA664, 0xA664, Upper), (0xA665, 0xA665, Lower),
+        (0xA666, 0xA666, Upper), (0xA667, 0xA667, Lower), (0xA668, 0xA668, Upper),
+        (0xA669, 0xA669, Lower), (0xA66A, 0xA66A, Upper), (0xA66B, 0xA66B, Lower),
+        (0xA66C, 0xA66C, Upper), (0xA66D, 0xA66D, Lower), (0xA66E, 0xA66E, OLetter),
+        (0xA66F, 0xA672, Extend), (0xA674, 0xA67D, Extend), (0xA67F, 0xA67F, OLetter),
+        (0xA680, 0xA680, Upper), (0xA681, 0xA681, Lower), (0xA682, 0xA682, Upper),
+        (0xA683, 0xA683, Lower), (0xA684, 0xA684, Upper), (0xA685, 0xA685, Lower),
+        (0xA686, 0xA686, Upper), (0xA687, 0xA687, Lower), (0xA688, 0xA688, Upper),
+        (0xA689, 0xA689, Lower), (0xA68A, 0xA68A, Upper), (0xA68B, 0xA68B, Lower),
+        (0xA68C, 0xA68C, Upper), (0xA68D, 0xA68D, Lower), (0xA68E, 0xA68E, Upper),
+        (0xA68F, 0xA68F, Lower), (0xA690, 0xA690, Upper), (0xA691, 0xA691, Lower),
+        (0xA692, 0xA692, Upper), (0xA693, 0xA693, Lower), (0xA694, 0xA694, Upper),
+        (0xA695, 0xA695, Lower), (0xA696, 0xA696, Upper), (0xA697, 0xA697, Lower),
+        (0xA698, 0xA698, Upper), (0xA699, 0xA699, Lower), (0xA69A, 0xA69A, Upper),
+        (0xA69B, 0xA69D, Lower), (0xA69E, 0xA69F, Extend), (0xA6A0, 0xA6EF, OLetter),
+        (0xA6F0, 0xA6F1, Extend), (0xA6F3, 0xA6F3, STerm), (0xA6F7, 0xA6F7, STerm),
+        (0xA717, 0xA71F, OLetter), (0xA722, 0xA722, Upper), (0xA723, 0xA723, Lower),
+        (0xA724, 0xA724, Upper), (0xA725, 0xA725, Lower), (0xA726, 0xA726, Upper),
+        (0xA727, 0xA727, Lower), (0xA728, 0xA728, Upper), (0xA729, 0xA729, Lower),
+        (0xA72A, 0xA72A, Upper), (0xA72B, 0xA72B, Lower), (0xA72C, 0xA72C, Upper),
+        (0xA72D, 0xA72D, Lower), (0xA72E, 0xA72E, Upper), (0xA72F, 0xA731, Lower),
+        (0xA732, 0xA732, Upper), (0xA733, 0xA733, Lower), (0xA734, 0xA734, Upper),
+        (0xA735, 0xA735, Lower), (0xA736, 0xA736, Upper), (0xA737, 0xA737, Lower),
+        (0xA738, 0xA738, Upper), (0xA739, 0xA739, Lower), (0xA73A, 0xA73A, Upper),
+        (0xA73B, 0xA73B, Lower), (0xA73C, 0xA73C, Upper), (0xA73D, 0xA73D, Lower),
+        (0xA73E, 0xA73E, Upper), (0xA73F, 0xA73F, Lower), (0xA740, 0xA740, Upper),
+        (0xA741, 0xA741, Lower), (0xA742, 0xA742, Upper), (0xA743, 0xA743, Lower),
+        (0xA744, 0xA744, Upper), (0xA745, 0xA745, Lower), (0xA746, 0xA746, Upper),
+        (0xA747, 0xA747, Lower), (0xA748, 0xA748, Upper), (0xA749, 0xA749, Lower),
+        (0xA74A, 0xA74A, Upper), (0xA74B, 0xA74B, Lower), (0xA74C, 0xA74C, Upper),
+        (0xA74D, 0xA74D, Lower), (0xA74E, 0xA74E, Upper), (0xA74F, 0xA74F, Lower),
+        (0xA750, 0xA750, Upper), (0xA751, 0xA751, Lower), (0xA752, 0xA752, Upper),
+        (0xA753, 0xA753, Lower), (0xA754, 0xA754, Upper), (0xA755, 0xA755, Lower),
+        (0xA756, 0xA756, Upper), (0xA757, 0xA757, Lower), (0xA758, 0xA758, Upper),
+        (0xA759, 0xA759, Lower), (0xA75A, 0xA75A, Upper), (0xA75B, 0xA75B, Lower),
+        (0xA75C, 0xA75C, Upper), (0xA75D, 0xA75D, Lower), (0xA75E, 0xA75E, Upper),
+        (0xA75F, 0xA75F, Lower), (0xA760, 0xA760, Upper), (0xA761, 0xA761, Lower),
+        (0xA762, 0xA762, Upper), (0xA763, 0xA763, Lower), (0xA764, 0xA764, Upper),
+        (0xA765, 0xA765, Lower), (0xA766, 0xA766, Upper), (0xA767, 0xA767, Lower),
+        (0xA768, 0xA768, Upper), (0xA769, 0xA769, Lower), (0xA76A, 0xA76A, Upper),
+        (0xA76B, 0xA76B, Lower), (0xA76C, 0xA76C, Upper), (0xA76D, 0xA76D, Lower),
+        (0xA76E, 0xA76E, Upper), (0xA76F, 0xA778, Lower), (0xA779, 0xA779, Upper),
+        (0xA77A, 0xA77A, Lower), (0xA77B, 0xA77B, Upper), (0xA77C, 0xA77C, Lower),
+        (0xA77D, 0xA77E, Upper), (0xA77F, 0xA77F, Lower), (0xA780, 0xA780, Upper),
+        (0xA781, 0xA781, Lower), (0xA782, 0xA782, Upper), (0xA783, 0xA783, Lower),
+        (0xA784, 0xA784, Upper), (0xA785, 0xA785, Lower), (0xA786, 0xA786, Upper),
+        (0xA787, 0xA787, Lower), (0xA788, 0xA788, OLetter), (0xA78B, 0xA78B, Upper),
+        (0xA78C, 0xA78C, Lower), (0xA78D, 0xA78D, Upper), (0xA78E, 0xA78E, Lower),
+        (0xA78F, 0xA78F, OLetter), (0xA790, 0xA790, Upper), (0xA791, 0xA791, Lower),
+        (0xA792, 0xA792, Upper), (0xA793, 0xA795, Lower), (0xA796, 0xA796, Upper),
+        (0xA797, 0xA797, Lower), (0xA798, 0xA798, Upper), (0xA799, 0xA799, Lower),
+        (0xA79A, 0xA79A, Upper), (0xA79B, 0xA79B, Lower), (0xA79C, 0xA79C, Upper),
+        (0xA79D, 0xA79D, Lower), (0xA79E, 0xA79E, Upper), (0xA79F, 0xA79F, Lower),
+        (0xA7A0, 0xA7A0, Upper), (0xA7A1, 0xA7A1, Lower), (0xA7A2, 0xA7A2, Upper),
+        (0xA7A3, 0xA7A3, Lower), (0xA7A4, 0xA7A4, Upper), (0xA7A5, 0xA7A5, Lower),
+        (0xA7A6, 0xA7A6, Upper), (0xA7A7, 0xA7A7, Lower), (0xA7A8, 0xA7A8, Upper),
+        (0xA7A9, 0xA7A9, Lower), (0xA7AA, 0xA7AE, Upper), (0xA7AF, 0xA7AF, Lower),
+        (0xA7B0, 0xA7B4, Upper), (0xA7B5, 0xA7B5, Lower), (0xA7B6, 0xA7B6, Upper),
+        (0xA7B7, 0xA7B7, Lower), (0xA7B8, 0xA7B8, Upper), (0xA7B9, 0xA7B9, Lower),
+        (0xA7BA, 0xA7BA, Upper), (0xA7BB, 0xA7BB, Lower), (0xA7BC, 0xA7BC, Upper),
+        (0xA7BD, 0xA7BD, Lower), (0xA7BE, 0xA7BE, Upper), (0xA7BF, 0xA7BF, Lower),
+        (0xA7C0, 0xA7C0, Upper), (0xA7C1, 0xA7C1, Lower), (0xA7C2, 0xA7C2, Upper),
+        (0xA7C3, 0xA7C3, Lower), (0xA7C4, 0xA7C7, Upper), (0xA7C8, 0xA7C8, Lower),
+        (0xA7C9, 0xA7C9, Upper), (0xA7CA, 0xA7CA, Lower), (0xA7D0, 0xA7D0, Upper),
+        (0xA7D1, 0xA7D1, Lower), (0xA7D3, 0xA7D3, Lower), (0xA7D5, 0xA7D5, Lower),
+        (0xA7D6, 0xA7D6, Upper), (0xA7D7, 0xA7D7, Lower), (0xA7D8, 0xA7D8, Upper),
+        (0xA7D9, 0xA7D9, Lower), (0xA7F2, 0xA7F4, Lower), (0xA7F5, 0xA7F5, Upper),
+        (0xA7F6, 0xA7F6, Lower), (0xA7F7, 0xA7F7, OLetter), (0xA7F8, 0xA7FA, Lower),
+        (0xA7FB, 0xA801, OLetter), (0xA802, 0xA802, Extend), (0xA803, 0xA805, OLetter),
+        (0xA806, 0xA806, Extend), (0xA807, 0xA80A, OLetter), (0xA80B, 0xA80B, Extend),
+        (0xA80C, 0xA822, OLetter), (0xA823, 0xA827, Extend), (0xA82C, 0xA82C, Extend),
+        (0xA840, 0xA873, OLetter), (0xA876, 0xA877, STerm), (0xA880, 0xA881, Extend),
+        (0xA882, 0xA8B3, OLetter), (0xA8B4, 0xA8C5, Extend), (0xA8CE, 0xA8CF, STerm),
+        (0xA8D0, 0xA8D9, Numeric), (0xA8E0, 0xA8F1, Extend), (0xA8F2, 0xA8F7, OLetter),
+        (0xA8FB, 0xA8FB, OLetter), (0xA8FD, 0xA8FE, OLetter), (0xA8FF, 0xA8FF, Extend),
+        (0xA900, 0xA909, Numeric), (0xA90A, 0xA925, OLetter), (0xA926, 0xA92D, Extend),
+        (0xA92F, 0xA92F, STerm), (0xA930, 0xA946, OLetter), (0xA947, 0xA953, Extend),
+        (0xA960, 0xA97C, OLetter), (0xA980, 0xA983, Extend), (0xA984, 0xA9B2, OLetter),
+        (0xA9B3, 0xA9C0, Extend), (0xA9C8, 0xA9C9, STerm), (0xA9CF, 0xA9CF, OLetter),
+        (0xA9D0, 0xA9D9, Numeric), (0xA9E0, 0xA9E4, OLetter), (0xA9E5, 0xA9E5, Extend),
+        (0xA9E6, 0xA9EF, OLetter), (0xA9F0, 0xA9F9, Numeric), (0xA9FA, 0xA9FE, OLetter),
+        (0xAA00, 0xAA28, OLetter), (0xAA29, 0xAA36, Extend), (0xAA40, 0xAA42, OLetter),
+        (0xAA43, 0xAA43, Extend), (0xAA44, 0xAA4B, OLetter), (0xAA4C, 0xAA4D, Extend),
+        (0xAA50, 0xAA59, Numeric), (0xAA5D, 0xAA5F, STerm), (0xAA60, 0xAA76, OLetter),
+        (0xAA7A, 0xAA7A, OLetter), (0xAA7B, 0xAA7D, Extend), (0xAA7E, 0xAAAF, OLetter),
+        (0xAAB0, 0xAAB0, Extend), (0xAAB1, 0xAAB1, OLetter), (0xAAB2, 0xAAB4, Extend),
+        (0xAAB5, 0xAAB6, OLetter), (0xAAB7, 0xAAB8, Extend), (0xAAB9, 0xAABD, OLetter),
+        (0xAABE, 0xAABF, Extend), (0xAAC0, 0xAAC0, OLetter), (0xAAC1, 0xAAC1, Extend),
+        (0xAAC2, 0xAAC2, OLetter), (0xAADB, 0xAADD, OLetter), (0xAAE0, 0xAAEA, OLetter),
+        (0xAAEB, 0xAAEF, Extend), (0xAAF0, 0xAAF1, STerm), (0xAAF2, 0xAAF4, OLetter),
+        (0xAAF5, 0xAAF6, Extend), (0xAB01, 0xAB06, OLetter), (0xAB09, 0xAB0E, OLetter),
+        (0xAB11, 0xAB16, OLetter), (0xAB20, 0xAB26, OLetter), (0xAB28, 0xAB2E, OLetter),
+        (0xAB30, 0xAB5A, Lower), (0xAB5C, 0xAB69, Lower), (0xAB70, 0xABBF, Lower),
+        (0xABC0, 0xABE2, OLetter), (0xABE3, 0xABEA, Extend), (0xABEB, 0xABEB, STerm),
+        (0xABEC, 0xABED, Extend), (0xABF0, 0xABF9, Numeric), (0xAC00, 0xD7A3, OLetter),
+        (0xD7B0, 0xD7C6, OLetter), (0xD7CB, 0xD7FB, OLetter), (0xF900, 0xFA6D, OLetter),
+        (0xFA70, 0xFAD9, OLetter), (0xFB00, 0xFB06, Lower), (0xFB13, 0xFB17, Lower),
+        (0xFB1D, 0xFB1D, OLetter), (0xFB1E, 0xFB1E, Extend), (0xFB1F, 0xFB28, OLetter),
+        (0xFB2A, 0xFB36, OLetter), (0xFB38, 0xFB3C, OLetter), (0xFB3E, 0xFB3E, OLetter),
+        (0xFB40, 0xFB41, OLetter), (0xFB43, 0xFB44, OLetter), (0xFB46, 0xFBB1, OLetter),
+        (0xFBD3, 0xFD3D, OLetter), (0xFD3E, 0xFD3F, Close), (0xFD50, 0xFD8F, OLetter),
+        (0xFD92, 0xFDC7, OLetter), (0xFDF0, 0xFDFB, OLetter), (0xFE00, 0xFE0F, Extend),
+        (0xFE10, 0xFE11, SContinue), (0xFE13, 0xFE13, SContinue), (0xFE17, 0xFE18, Close),
+        (0xFE20, 0xFE2F, Extend), (0xFE31, 0xFE32, SContinue), (0xFE35, 0xFE44, Close),
+        (0xFE47, 0xFE48, Close), (0xFE50, 0xFE51, SContinue), (0xFE52, 0xFE52, ATerm),
+        (0xFE55, 0xFE55, SContinue), (0xFE56, 0xFE57, STerm), (0xFE58, 0xFE58, SContinue),
+        (0xFE59, 0xFE5E, Close), (0xFE63, 0xFE63, SContinue), (0xFE70, 0xFE74, OLetter),
+        (0xFE76, 0xFEFC, OLetter), (0xFEFF, 0xFEFF, Format), (0xFF01, 0xFF01, STerm),
+        (0xFF08, 0xFF09, Close), (0xFF0C, 0xFF0D, SContinue), (0xFF0E, 0xFF0E, ATerm),
+        (0xFF10, 0xFF19, Numeric), (0xFF1A, 0xFF1A, SContinue), (0xFF1F, 0xFF1F, STerm),
+        (0xFF21, 0xFF3A, Upper), (0xFF3B, 0xFF3B, Close), (0xFF3D, 0xFF3D, Close),
+        (0xFF41, 0xFF5A, Lower), (0xFF5B, 0xFF5B, Close), (0xFF5D, 0xFF5D, Close),
+        (0xFF5F, 0xFF60, Close), (0xFF61, 0xFF61, STerm), (0xFF62, 0xFF63, Close),
+        (0xFF64, 0xFF64, SContinue), (0xFF66, 0xFF9D, OLetter), (0xFF9E, 0xFF9F, Extend),
+        (0xFFA0, 0xFFBE, OLetter), (0xFFC2, 0xFFC7, OLetter), (0xFFCA, 0xFFCF, OLetter),
+        (0xFFD2, 0xFFD7, OLetter), (0xFFDA, 0xFFDC, OLetter), (0xFFF9, 0xFFFB, Format),
+        (0x10000, 0x1000B, OLetter), (0x1000D, 0x10026, OLetter), (0x10028, 0x1003A, OLetter),
+        (0x1003C, 0x1003D, OLetter), (0x1003F, 0x1004D, OLetter), (0x10050, 0x1005D, OLetter),
+        (0x10080, 0x100FA, OLetter), (0x10140, 0x10174, OLetter), (0x101FD, 0x101FD, Extend),
+        (0x10280, 0x1029C, OLetter), (0x102A0, 0x102D0, OLetter), (0x102E0, 0x102E0, Extend),
+        (0x10300, 0x1031F, OLetter), (0x1032D, 0x1034A, OLetter), (0x10350, 0x10375, OLetter),
+        (0x10376, 0x1037A, Extend), (0x10380, 0x1039D, OLetter), (0x103A0, 0x103C3, OLetter),
+        (0x103C8, 0x103CF, OLetter), (0x103D1, 0x103D5, OLetter), (0x10400, 0x10427, Upper),
+        (0x10428, 0x1044F, Lower), (0x10450, 0x1049D, OLetter), (0x104A0, 0x104A9, Numeric),
+        (0x104B0, 0x104D3, Upper), (0x104D8, 0x104FB, Lower), (0x10500, 0x10527, OLetter),
+        (0x10530, 0x10563, OLetter), (0x10570, 0x1057A, Upper), (0x1057C, 0x1058A, Upper),
+        (0x1058C, 0x10592, Upper), (0x10594, 0x10595, Upper), (0x10597, 0x105A1, Lower),
+        (0x105A3, 0x105B1, Lower), (0x105B3, 0x105B9, Lower), (0x105BB, 0x105BC, Lower),
+        (0x10600, 0x10736, OLetter), (0x10740, 0x10755, OLetter), (0x10760, 0x10767, OLetter),
+        (0x10780, 0x10780, Lower), (0x10781, 0x10782, OLetter), (0x10783, 0x10785, Lower),
+        (0x10787, 0x107B0, Lower), (0x107B2, 0x107BA, Lower), (0x10800, 0x10805, OLetter),
+        (0x10808, 0x10808, OLetter), (0x1080A, 0x10835, OLetter), (0x10837, 0x10838, OLetter),
+        (0x1083C, 0x1083C, OLetter), (0x1083F, 0x10855, OLetter), (0x10860, 0x10876, OLetter),
+        (0x10880, 0x1089E, OLetter), (0x108E0, 0x108F2, OLetter), (0x108F4, 0x108F5, OLetter),
+        (0x10900, 0x10915, OLetter), (0x10920, 0x10939, OLetter), (0x10980, 0x109B7, OLetter),
+        (0x109BE, 0x109BF, OLetter), (0x10A00, 0x10A00, OLetter), (0x10A01, 0x10A03, Extend),
+        (0x10A05, 0x10A06, Extend), (0x10A0C, 0x10A0F, Extend), (0x10A10, 0x10A13, OLetter),
+        (0x10A15, 0x10A17, OLetter), (0x10A19, 0x10A35, OLetter), (0x10A38, 0x10A3A, Extend),
+        (0x10A3F, 0x10A3F, Extend), (0x10A56, 0x10A57, STerm), (0x10A60, 0x10A7C, OLetter),
+        (0x10A80, 0x10A9C, OLetter), (0x10AC0, 0x10AC7, OLetter), (0x10AC9, 0x10AE4, OLetter),
+        (0x10AE5, 0x10AE6, Extend), (0x10B00, 0x10B35, OLetter), (0x10B40, 0x10B55, OLetter),
+        (0x10B60, 0x10B72, OLetter), (0x10B80, 0x10B91, OLetter), (0x10C00, 0x10C48, OLetter),
+        (0x10C80, 0x10CB2, Upper), (0x10CC0, 0x10CF2, Lower), (0x10D00, 0x10D23, OLetter),
+        (0x10D24, 0x10D27, Extend), (0x10D30, 0x10D39, Numeric), (0x10E80, 0x10EA9, OLetter),
+        (0x10EAB, 0x10EAC, Extend), (0x10EB0, 0x10EB1, OLetter), (0x10EFD, 0x10EFF, Extend),
+        (0x10F00, 0x10F1C, OLetter), (0x10F27, 0x10F27, OLetter), (0x10F30, 0x10F45, OLetter),
+        (0x10F46, 0x10F50, Extend), (0x10F55, 0x10F59, STerm), (0x10F70, 0x10F81, OLetter),
+        (0x10F82, 0x10F85, Extend), (0x10F86, 0x10F89, STerm), (0x10FB0, 0x10FC4, OLetter),
+        (0x10FE0, 0x10FF6, OLetter), (0x11000, 0x11002, Extend), (0x11003, 0x11037, OLetter),
+        (0x11038, 0x11046, Extend), (0x11047, 0x11048, STerm), (0x11066, 0x1106F, Numeric),
+        (0x11070, 0x11070, Extend), (0x11071, 0x11072, OLetter), (0x11073, 0x11074, Extend),
+        (0x11075, 0x11075, OLetter), (0x1107F, 0x11082, Extend), (0x11083, 0x110AF, OLetter),
+        (0x110B0, 0x110BA, Extend), (0x110BD, 0x110BD, Numeric), (0x110BE, 0x110C1, STerm),
+        (0x110C2, 0x110C2, Extend), (0x110CD, 0x110CD, Numeric), (0x110D0, 0x110E8, OLetter),
+        (0x110F0, 0x110F9, Numeric), (0x11100, 0x11102, Extend), (0x11103, 0x11126, OLetter),
+        (0x11127, 0x11134, Extend), (0x11136, 0x1113F, Numeric), (0x11141, 0x11143, STerm),
+        (0x11144, 0x11144, OLetter), (0x11145, 0x11146, Extend), (0x11147, 0x11147, OLetter),
+        (0x11150, 0x11172, OLetter), (0x11173, 0x11173, Extend), (0x11176, 0x11176, OLetter),
+        (0x11180, 0x11182, Extend), (0x11183, 0x111B2, OLetter), (0x111B3, 0x111C0, Extend),
+        (0x111C1, 0x111C4, OLetter), (0x111C5, 0x111C6, STerm), (0x111C9, 0x111CC, Extend),
+        (0x111CD, 0x111CD, STerm), (0x111CE, 0x111CF, Extend), (0x111D0, 0x111D9, Numeric),
+        (0x111DA, 0x111DA, OLetter), (0x111DC, 0x111DC, OLetter), (0x111DE, 0x111DF, STerm),
+        (0x11200, 0x11211, OLetter), (0x11213, 0x1122B, OLetter), (0x1122C, 0x11237, Extend),
+        (0x11238, 0x11239, STerm), (0x1123B, 0x1123C, STerm), (0x1123E, 0x1123E, Extend),
+        (0x1123F, 0x11240, OLetter), (0x11241, 0x11241, Extend), (0x11280, 0x11286, OLetter),
+        (0x11288, 0x11288, OLetter), (0x1128A, 0x1128D, OLetter), (0x1128F, 0x1129D, OLetter),
+        (0x1129F, 0x112A8, OLetter), (0x112A9, 0x112A9, STerm), (0x112B0, 0x112DE, OLetter),
+        (0x112DF, 0x112EA, Extend), (0x112F0, 0x112F9, Numeric), (0x11300, 0x11303, Extend),
+        (0x11305, 0x1130C, OLetter), (0x1130F, 0x11310, OLetter), (0x11313, 0x11328, OLetter),
+        (0x1132A, 0x11330, OLetter), (0x11332, 0x11333, OLetter), (0x11335, 0x11339, OLetter),
+        (0x1133B, 0x1133C, Extend), (0x1133D, 0x1133D, OLetter), (0x1133E, 0x11344, Extend),
+        (0x11347, 0x11348, Extend), (0x1134B, 0x1134D, Extend), (0x11350, 0x11350, OLetter),
+        (0x11357, 0x11357, Extend), (0x1135D, 0x11361, OLetter), (0x11362, 0x11363, Extend),
+        (0x11366, 0x1136C, Extend), (0x11370, 0x11374, Extend), (0x11400, 0x11434, OLetter),
+        (0x11435, 0x11446, Extend), (0x11447, 0x1144A, OLetter), (0x1144B, 0x1144C, STerm),
+        (0x11450, 0x11459, Numeric), (0x1145E, 0x1145E, Extend), (0x1145F, 0x11461, OLetter),
+        (0x11480, 0x114AF, OLetter), (0x114B0, 0x114C3, Extend), (0x114C4, 0x114C5, OLetter),
+        (0x114C7, 0x114C7, OLetter), (0x114D0, 0x114D9, Numeric), (0x11580, 0x115AE, OLetter),
+        (0x115AF, 0x115B5, Extend), (0x115B8, 0x115C0, Extend), (0x115C2, 0x115C3, STerm),
+        (0x115C9, 0x115D7, STerm), (0x115D8, 0x115DB, OLetter), (0x115DC, 0x115DD, Extend),
+        (0x11600, 0x1162F, OLetter), (0x11630, 0x11640, Extend), (0x11641, 0x11642, STerm),
+        (0x11644, 0x11644, OLetter), (0x11650, 0x11659, Numeric), (0x11680, 0x116AA, OLetter),
+        (0x116AB, 0x116B7, Extend), (0x116B8, 0x116B8, OLetter), (0x116C0, 0x116C9, Numeric),
+        (0x11700, 0x1171A, OLetter), (0x1171D, 0x1172B, Extend), (0x11730, 0x11739, Numeric),
+        (0x1173C, 0x1173E, STerm), (0x11740, 0x11746, OLetter), (0x11800, 0x1182B, OLetter),
+        (0x1182C, 0x1183A, Extend), (0x118A0, 0x118BF, Upper), (0x118C0, 0x118DF, Lower),
+        (0x118E0, 0x118E9, Numeric), (0x118FF, 0x11906, OLetter), (0x11909, 0x11909, OLetter),
+        (0x1190C, 0x11913, OLetter), (0x11915, 0x11916, OLetter), (0x11918, 0x1192F, OLetter),
+        (0x11930, 0x11935, Extend), (0x11937, 0x11938, Extend), (0x1193B, 0x1193E, Extend),
+        (0x1193F, 0x1193F, OLetter), (0x11940, 0x11940, Extend), (0x11941, 0x11941, OLetter),
+        (0x11942, 0x11943, Extend), (0x11944, 0x11944, STerm), (0x11946, 0x11946, STerm),
+        (0x11950, 0x11959, Numeric), (0x119A0, 0x119A7, OLetter), (0x119AA, 0x119D0, OLetter),
+        (0x119D1, 0x119D7, Extend), (0x119DA, 0x119E0, Extend), (0x119E1, 0x119E1, OLetter),
+        (0x119E3, 0x119E3, OLetter), (0x119E4, 0x119E4, Extend), (0x11A00, 0x11A00, OLetter),
+        (0x11A01, 0x11A0A, Extend), (0x11A0B, 0x11A32, OLetter), (0x11A33, 0x11A39, Extend),
+        (0x11A3A, 0x11A3A, OLetter), (0x11A3B, 0x11A3E, Extend), (0x11A42, 0x11A43, STerm),
+        (0x11A47, 0x11A47, Extend), (0x11A50, 0x11A50, OLetter), (0x11A51, 0x11A5B, Extend),
+        (0x11A5C, 0x11A89, OLetter), (0x11A8A, 0x11A99, Extend), (0x11A9B, 0x11A9C, STerm),
+        (0x11A9D, 0x11A9D, OLetter), (0x11AB0, 0x11AF8, OLetter), (0x11C00, 0x11C08, OLetter),
+        (0x11C0A, 0x11C2E, OLetter), (0x11C2F, 0x11C36, Extend), (0x11C38, 0x11C3F, Extend),
+        (0x11C40, 0x11C40, OLetter), (0x11C41, 0x11C42, STerm), (0x11C50, 0x11C59, Numeric),
+        (0x11C72, 0x11C8F, OLetter), (0x11C92, 0x11CA7, Extend), (0x11CA9, 0x11CB6, Extend),
+        (0x11D00, 0x11D06, OLetter), (0x11D08, 0x11D09, OLetter), (0x11D0B, 0x11D30, OLetter),
+        (0x11D31, 0x11D36, Extend), (0x11D3A, 0x11D3A, Extend), (0x11D3C, 0x11D3D, Extend),
+        (0x11D3F, 0x11D45, Extend), (0x11D46, 0x11D46, OLetter), (0x11D47, 0x11D47, Extend),
+        (0x11D50, 0x11D59, Numeric), (0x11D60, 0x11D65, OLetter), (0x11D67, 0x11D68, OLetter),
+        (0x11D6A, 0x11D89, OLetter), (0x11D8A, 0x11D8E, Extend), (0x11D90, 0x11D91, Extend),
+        (0x11D93, 0x11D97, Extend), (0x11D98, 0x11D98, OLetter), (0x11DA0, 0x11DA9, Numeric),
+        (0x11EE0, 0x11EF2, OLetter), (0x11EF3, 0x11EF6, Extend), (0x11EF7, 0x11EF8, STerm),
+        (0x11F00, 0x11F01, Extend), (0x11F02, 0x11F02, OLetter), (0x11F03, 0x11F03, Extend),
+        (0x11F04, 0x11F10, OLetter), (0x11F12, 0x11F33, OLetter), (0x11F34, 0x11F3A, Extend),
+        (0x11F3E, 0x11F42, Extend), (0x11F43, 0x11F44, STerm), (0x11F50, 0x11F59, Numeric),
+        (0x11FB0, 0x11FB0, OLetter), (0x12000, 0x12399, OLetter), (0x12400, 0x1246E, OLetter),
+        (0x12480, 0x12543, OLetter), (0x12F90, 0x12FF0, OLetter), (0x13000, 0x1342F, OLetter),
+        (0x13430, 0x1343F, Format), (0x13440, 0x13440, Extend), (0x13441, 0x13446, OLetter),
+        (0x13447, 0x13455, Extend), (0x14400, 0x14646, OLetter), (0x16800, 0x16A38, OLetter),
+        (0x16A40, 0x16A5E, OLetter), (0x16A60, 0x16A69, Numeric), (0x16A6E, 0x16A6F, STerm),
+        (0x16A70, 0x16ABE, OLetter), (0x16AC0, 0x16AC9, Numeric), (0x16AD0, 0x16AED, OLetter),
+        (0x16AF0, 0x16AF4, Extend), (0x16AF5, 0x16AF5, STerm), (0x16B00, 0x16B2F, OLetter),
+        (0x16B30, 0x16B36, Extend), (0x16B37, 0x16B38, STerm), (0x16B40, 0x16B43, OLetter),
+        (0x16B44, 0x16B44, STerm), (0x16B50, 0x16B59, Numeric), (0x16B63, 0x16B77, OLetter),
+        (0x16B7D, 0x16B8F, OLetter), (0x16E40, 0x16E5F, Upper), (0x16E60, 0x16E7F, Lower),
+        (0x16E98, 0x16E98, STerm), (0x16F00, 0x16F4A, OLetter), (0x16F4F, 0x16F4F, Extend),
+        (0x16F50, 0x16F50, OLetter), (0x16F51, 0x16F87, Extend), (0x16F8F, 0x16F92, Extend),
+        (0x16F93, 0x16F9F, OLetter), (0x16FE0, 0x16FE1, OLetter), (0x16FE3, 0x16FE3, OLetter),
+        (0x16FE4, 0x16FE4, Extend), (0x16FF0, 0x16FF1, Extend), (0x17000, 0x187F7, OLetter),
+        (0x18800, 0x18CD5, OLetter), (0x18D00, 0x18D08, OLetter), (0x1AFF0, 0x1AFF3, OLetter),
+        (0x1AFF5, 0x1AFFB, OLetter), (0x1AFFD, 0x1AFFE, OLetter), (0x1B000, 0x1B122, OLetter),
+        (0x1B132, 0x1B132, OLetter), (0x1B150, 0x1B152, OLetter), (0x1B155, 0x1B155, OLetter),
+        (0x1B164, 0x1B167, OLetter), (0x1B170, 0x1B2FB, OLetter), (0x1BC00, 0x1BC6A, OLetter),
+        (0x1BC70, 0x1BC7C, OLetter), (0x1BC80, 0x1BC88, OLetter), (0x1BC90, 0x1BC99, OLetter),
+        (0x1BC9D, 0x1BC9E, Extend), (0x1BC9F, 0x1BC9F, STerm), (0x1BCA0, 0x1BCA3, Format),
+        (0x1CF00, 0x1CF2D, Extend), (0x1CF30, 0x1CF46, Extend), (0x1D165, 0x1D169, Extend),
+        (0x1D16D, 0x1D172, Extend), (0x1D173, 0x1D17A, Format), (0x1D17B, 0x1D182, Extend),
+        (0x1D185, 0x1D18B, Extend), (0x1D1AA, 0x1D1AD, Extend), (0x1D242, 0x1D244, Extend),
+        (0x1D400, 0x1D419, Upper), (0x1D41A, 0x1D433, Lower), (0x1D434, 0x1D44D, Upper),
+        (0x1D44E, 0x1D454, Lower), (0x1D456, 0x1D467, Lower), (0x1D468, 0x1D481, Upper),
+        (0x1D482, 0x1D49B, Lower), (0x1D49C, 0x1D49C, Upper), (0x1D49E, 0x1D49F, Upper),
+        (0x1D4A2, 0x1D4A2, Upper), (0x1D4A5, 0x1D4A6, Upper), (0x1D4A9, 0x1D4AC, Upper),
+        (0x1D4AE, 0x1D4B5, Upper), (0x1D4B6, 0x1D4B9, Lower), (0x1D4BB, 0x1D4BB, Lower),
+        (0x1D4BD, 0x1D4C3, Lower), (0x1D4C5, 0x1D4CF, Lower), (0x1D4D0, 0x1D4E9, Upper),
+        (0x1D4EA, 0x1D503, Lower), (0x1D504, 0x1D505, Upper), (0x1D507, 0x1D50A, Upper),
+        (0x1D50D, 0x1D514, Upper), (0x1D516, 0x1D51C, Upper), (0x1D51E, 0x1D537, Lower),
+        (0x1D538, 0x1D539, Upper), (0x1D53B, 0x1D53E, Upper), (0x1D540, 0x1D544, Upper),
+        (0x1D546, 0x1D546, Upper), (0x1D54A, 0x1D550, Upper), (0x1D552, 0x1D56B, Lower),
+        (0x1D56C, 0x1D585, Upper), (0x1D586, 0x1D59F, Lower), (0x1D5A0, 0x1D5B9, Upper),
+        (0x1D5BA, 0x1D5D3, Lower), (0x1D5D4, 0x1D5ED, Upper), (0x1D5EE, 0x1D607, Lower),
+        (0x1D608, 0x1D621, Upper), (0x1D622, 0x1D63B, Lower), (0x1D63C, 0x1D655, Upper),
+        (0x1D656, 0x1D66F, Lower), (0x1D670, 0x1D689, Upper), (0x1D68A, 0x1D6A5, Lower),
+        (0x1D6A8, 0x1D6C0, Upper), (0x1D6C2, 0x1D6DA, Lower), (0x1D6DC, 0x1D6E1, Lower),
+        (0x1D6E2, 0x1D6FA, Upper), (0x1D6FC, 0x1D714, Lower), (0x1D716, 0x1D71B, Lower),
+        (0x1D71C, 0x1D734, Upper), (0x1D736, 0x1D74E, Lower), (0x1D750, 0x1D755, Lower),
+        (0x1D756, 0x1D76E, Upper), (0x1D770, 0x1D788, Lower), (0x1D78A, 0x1D78F, Lower),
+        (0x1D790, 0x1D7A8, Upper), (0x1D7AA, 0x1D7C2, Lower), (0x1D7C4, 0x1D7C9, Lower),
+        (0x1D7CA, 0x1D7CA, Upper), (0x1D7CB, 0x1D7CB, Lower), (0x1D7CE, 0x1D7FF, Numeric),
+        (0x1DA00, 0x1DA36, Extend), (0x1DA3B, 0x1DA6C, Extend), (0x1DA75, 0x1DA75, Extend),
+        (0x1DA84, 0x1DA84, Extend), (0x1DA88, 0x1DA88, STerm), (0x1DA9B, 0x1DA9F, Extend),
+        (0x1DAA1, 0x1DAAF, Extend), (0x1DF00, 0x1DF09, Lower), (0x1DF0A, 0x1DF0A, OLetter),
+        (0x1DF0B, 0x1DF1E, Lower), (0x1DF25, 0x1DF2A, Lower), (0x1E000, 0x1E006, Extend),
+        (0x1E008, 0x1E018, Extend), (0x1E01B, 0x1E021, Extend), (0x1E023, 0x1E024, Extend),
+        (0x1E026, 0x1E02A, Extend), (0x1E030, 0x1E06D, Lower), (0x1E08F, 0x1E08F, Extend),
+        (0x1E100, 0x1E12C, OLetter), (0x1E130, 0x1E136, Extend), (0x1E137, 0x1E13D, OLetter),
+        (0x1E140, 0x1E149, Numeric), (0x1E14E, 0x1E14E, OLetter), (0x1E290, 0x1E2AD, OLetter),
+        (0x1E2AE, 0x1E2AE, Extend), (0x1E2C0, 0x1E2EB, OLetter), (0x1E2EC, 0x1E2EF, Extend),
+        (0x1E2F0, 0x1E2F9, Numeric), (0x1E4D0, 0x1E4EB, OLetter), (0x1E4EC, 0x1E4EF, Extend),
+        (0x1E4F0, 0x1E4F9, Numeric), (0x1E7E0, 0x1E7E6, OLetter), (0x1E7E8, 0x1E7EB, OLetter),
+        (0x1E7ED, 0x1E7EE, OLetter), (0x1E7F0, 0x1E7FE, OLetter), (0x1E800, 0x1E8C4, OLetter),
+        (0x1E8D0, 0x1E8D6, Extend), (0x1E900, 0x1E921, Upper), (0x1E922, 0x1E943, Lower),
+        (0x1E944, 0x1E94A, Extend), (0x1E94B, 0x1E94B, OLetter), (0x1E950, 0x1E959, Numeric),
+        (0x1EE00, 0x1EE03, OLetter), (0x1EE05, 0x1EE1F, OLetter), (0x1EE21, 0x1EE22, OLetter),
+        (0x1EE24, 0x1EE24, OLetter), (0x1EE27, 0x1EE27, OLetter), (0x1EE29, 0x1EE32, OLetter),
+        (0x1EE34, 0x1EE37, OLetter), (0x1EE39, 0x1EE39, OLetter), (0x1EE3B, 0x1EE3B, OLetter),
+        (0x1EE42, 0x1EE42, OLetter), (0x1EE47, 0x1EE47, OLetter), (0x1EE49, 0x1EE49, OLetter),
+        (0x1EE4B, 0x1EE4B, OLetter), (0x1EE4D, 0x1EE4F, OLetter), (0x1EE51, 0x1EE52, OLetter),
+        (0x1EE54, 0x1EE54, OLetter), (0x1EE57, 0x1EE57, OLetter), (0x1EE59, 0x1EE59, OLetter),
+        (0x1EE5B, 0x1EE5B, OLetter), (0x1EE5D, 0x1EE5D, OLetter), (0x1EE5F, 0x1EE5F, OLetter),
+        (0x1EE61, 0x1EE62, OLetter), (0x1EE64, 0x1EE64, OLetter), (0x1EE67, 0x1EE6A, OLetter),
+        (0x1EE6C, 0x1EE72, OLetter), (0x1EE74, 0x1EE77, OLetter), (0x1EE79, 0x1EE7C, OLetter),
+        (0x1EE7E, 0x1EE7E, OLetter), (0x1EE80, 0x1EE89, OLetter), (0x1EE8B, 0x1EE9B, OLetter),
+        (0x1EEA1, 0x1EEA3, OLetter), (0x1EEA5, 0x1EEA9, OLetter), (0x1EEAB, 0x1EEBB, OLetter),
+        (0x1F130, 0x1F149, Upper), (0x1F150, 0x1F169, Upper), (0x1F170, 0x1F189, Upper),
+        (0x1F676, 0x1F678, Close), (0x1FBF0, 0x1FBF9, Numeric), (0x20000, 0x2A6DF, OLetter),
+        (0x2A700, 0x2B739, OLetter), (0x2B740, 0x2B81D, OLetter), (0x2B820, 0x2CEA1, OLetter),
+        (0x2CEB0, 0x2EBE0, OLetter), (0x2EBF0, 0x2EE5D, OLetter), (0x2F800, 0x2FA1D, OLetter),
+        (0x30000, 0x3134A, OLetter), (0x31350, 0x323AF, OLetter), (0xE0001, 0xE0001, Format),
+        (0xE0020, 0xE007F, Extend), (0xE0100, 0xE01EF, Extend),
+    ];
+}
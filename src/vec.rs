@@ -5,6 +5,9 @@
 // http://opensource.org/licenses/MIT>, at your option. This file may not be
 // copied, modified, or distributed except according to those terms.
 
+use iter::{Interleaving, ZipOptAll};
+use std::ops::Range;
+
 /// Several different methods for getting, or evaluating, uniqueness.
 pub trait Uniq<T> {
     /// `uniq` returns a vector of unique values within itself as compared to
@@ -250,18 +253,16 @@ pub trait Join {
     /// ```text
     /// "1,2,3"
     /// ```
-    fn join(&self, joiner: &'static str) -> String;
+    fn join(&self, joiner: &str) -> String;
 }
 impl<T: ToString> Join for Vec<T> {
-    fn join(&self, joiner: &'static str) -> String {
-        let mut out = String::from("");
-        for x in 0..self.len() {
-            out.push_str(&self[x].to_string());
-            if x < self.len() - 1 {
-                out.push_str(&joiner)
-            }
-        }
-        out
+    fn join(&self, joiner: &str) -> String {
+        let parts: Vec<String> = self.iter().map(ToString::to_string).collect();
+        parts
+            .iter()
+            .map(String::as_str)
+            .intersperse_items(joiner)
+            .collect()
     }
 }
 
@@ -321,3 +322,529 @@ impl<T: PartialEq + Clone> Union for Vec<T> {
         stack.unique()
     }
 }
+
+/// Turn rows into columns, padding the gaps left by shorter rows with `None`.
+pub trait Transpose<T> {
+    /// # Example
+    /// ```
+    /// use array_tool::vec::Transpose;
+    ///
+    /// assert_eq!(
+    ///   vec![vec![1, 2, 3], vec![4], vec![5, 6]].transpose_ragged(),
+    ///   vec![
+    ///     vec![Some(1), Some(4), Some(5)],
+    ///     vec![Some(2), None, Some(6)],
+    ///     vec![Some(3), None, None],
+    ///   ]
+    /// );
+    /// ```
+    fn transpose_ragged(&self) -> Vec<Vec<Option<T>>>;
+}
+impl<T: Clone> Transpose<T> for [Vec<T>] {
+    fn transpose_ragged(&self) -> Vec<Vec<Option<T>>> {
+        self.iter()
+            .map(|row| row.iter().cloned())
+            .collect::<Vec<_>>()
+            .zip_option_all()
+            .collect()
+    }
+}
+
+/// The edit distance between `a` and `b`, or `None` when it is more than `limit`.  With
+/// `transpositions` swapping two neighbours counts as one edit (optimal string alignment).
+/// Only the band of cells within `limit` of the diagonal is filled in.
+fn edit_distance<T: PartialEq>(
+    mut a: &[T],
+    mut b: &[T],
+    limit: usize,
+    transpositions: bool,
+) -> Option<usize> {
+    // A shared start or end takes no edits.
+    while let (Some(x), Some(y)) = (a.first(), b.first()) {
+        if x != y {
+            break;
+        }
+        a = &a[1..];
+        b = &b[1..];
+    }
+    while let (Some(x), Some(y)) = (a.last(), b.last()) {
+        if x != y {
+            break;
+        }
+        a = &a[..a.len() - 1];
+        b = &b[..b.len() - 1];
+    }
+    let (n, m) = (a.len(), b.len());
+    if n.max(m) - n.min(m) > limit {
+        return None;
+    }
+    let limit = limit.min(n.max(m));
+    let over = limit + 1;
+
+    let mut before: Vec<usize> = vec![over; m + 1];
+    let mut previous: Vec<usize> = (0..m + 1).map(|j| j.min(over)).collect();
+    let mut current = vec![over; m + 1];
+    for i in 1..n + 1 {
+        let low = i.saturating_sub(limit).max(1);
+        let high = (i + limit).min(m);
+        current[low - 1] = if low == 1 { i.min(over) } else { over };
+        if high < m {
+            current[high + 1] = over;
+        }
+        let mut row_min = current[low - 1];
+        for j in low..high + 1 {
+            let substitution = previous[j - 1] + usize::from(a[i - 1] != b[j - 1]);
+            let mut cell = substitution.min(previous[j] + 1).min(current[j - 1] + 1);
+            if transpositions && i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                cell = cell.min(before[j - 2] + 1);
+            }
+            current[j] = cell.min(over);
+            row_min = row_min.min(current[j]);
+        }
+        if row_min > limit {
+            return None;
+        }
+        std::mem::swap(&mut before, &mut previous);
+        std::mem::swap(&mut previous, &mut current);
+    }
+    Some(previous[m]).filter(|&distance| distance <= limit)
+}
+
+/// The similarity of `a` and `b` from 0.0 to 1.0 by the Jaro–Winkler measure.
+fn jaro_winkler<T: PartialEq>(a: &[T], b: &[T]) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut b_matched = vec![false; b.len()];
+    let mut a_matches = vec![];
+    for (i, x) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+        for j in start..end {
+            if !b_matched[j] && *x == b[j] {
+                b_matched[j] = true;
+                a_matches.push(x);
+                break;
+            }
+        }
+    }
+    if a_matches.is_empty() {
+        return 0.0;
+    }
+    let b_matches = b.iter().zip(&b_matched).filter(|&(_, &matched)| matched);
+    let half_transpositions = a_matches
+        .iter()
+        .zip(b_matches)
+        .filter(|&(x, (y, _))| *x != y)
+        .count();
+    let matches = a_matches.len() as f64;
+    let transpositions = half_transpositions as f64 / 2.0;
+    let jaro = (matches / a.len() as f64
+        + matches / b.len() as f64
+        + (matches - transpositions) / matches)
+        / 3.0;
+    if jaro <= 0.7 {
+        return jaro;
+    }
+    let prefix = a.iter().zip(b).take(4).take_while(|&(x, y)| x == y).count();
+    jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+}
+
+/// Edit distances and similarity between two sequences, for fuzzy matching.  The
+/// `_within` forms give up as soon as the distance is known to be over `limit`, which is
+/// much faster for a small limit, and make handy comparators for `Uniq::unique_via` and
+/// `Intersect::intersect_if`.
+///
+/// # Example
+/// ```
+/// use array_tool::vec::{EditDistance, Uniq};
+///
+/// let readings = vec![vec![1, 2, 3, 4], vec![1, 2, 4, 3], vec![9, 9, 9, 9], vec![1, 2, 3]];
+/// assert_eq!(
+///   readings.unique_via(|a, b| a.damerau_levenshtein_within(b, 1).is_some()),
+///   vec![vec![1, 2, 3, 4], vec![9, 9, 9, 9]]
+/// );
+/// ```
+pub trait EditDistance<T> {
+    /// The fewest insertions, deletions and substitutions which turn one sequence into the
+    /// other.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::vec::EditDistance;
+    ///
+    /// assert_eq!(vec!['k','i','t','t','e','n'].levenshtein(&['s','i','t','t','i','n','g']), 3);
+    /// ```
+    fn levenshtein(&self, other: &[T]) -> usize;
+
+    /// The Levenshtein distance, or `None` when it is more than `limit`.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::vec::EditDistance;
+    ///
+    /// assert_eq!(vec![1, 2, 3].levenshtein_within(&[1, 3], 1), Some(1));
+    /// assert_eq!(vec![1, 2, 3].levenshtein_within(&[3, 2, 1], 1), None);
+    /// ```
+    fn levenshtein_within(&self, other: &[T], limit: usize) -> Option<usize>;
+
+    /// Like `levenshtein`, with swapping two neighbours counting as one edit.  This is the
+    /// optimal string alignment distance, which never edits the same span twice.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::vec::EditDistance;
+    ///
+    /// assert_eq!(vec![1, 2, 3, 4].damerau_levenshtein(&[2, 1, 3, 4]), 1);
+    /// assert_eq!(vec![1, 2, 3, 4].levenshtein(&[2, 1, 3, 4]), 2);
+    /// ```
+    fn damerau_levenshtein(&self, other: &[T]) -> usize;
+
+    /// The optimal string alignment distance, or `None` when it is more than `limit`.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::vec::EditDistance;
+    ///
+    /// assert_eq!(vec![1, 2, 3, 4].damerau_levenshtein_within(&[2, 1, 4, 3], 2), Some(2));
+    /// assert_eq!(vec![1, 2, 3, 4].damerau_levenshtein_within(&[2, 1, 4, 3], 1), None);
+    /// ```
+    fn damerau_levenshtein_within(&self, other: &[T], limit: usize) -> Option<usize>;
+
+    /// The number of positions at which the sequences differ.  Each element one sequence
+    /// has past the end of the other counts as a difference.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::vec::EditDistance;
+    ///
+    /// assert_eq!(vec![1, 0, 1, 1].hamming(&[1, 1, 1, 0]), 2);
+    /// assert_eq!(vec![1, 0, 1, 1].hamming(&[1, 0]), 2);
+    /// ```
+    fn hamming(&self, other: &[T]) -> usize;
+
+    /// The Hamming distance, or `None` when it is more than `limit`.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::vec::EditDistance;
+    ///
+    /// assert_eq!(vec![1, 0, 1, 1].hamming_within(&[1, 1, 1, 0], 1), None);
+    /// ```
+    fn hamming_within(&self, other: &[T], limit: usize) -> Option<usize>;
+
+    /// The Jaro–Winkler similarity, from 0.0 for nothing in common to 1.0 for equal
+    /// sequences.  Sequences which start the same, up to 4 elements, score higher once
+    /// they are more than 0.7 similar.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::vec::EditDistance;
+    ///
+    /// let martha: Vec<char> = "MARTHA".chars().collect();
+    /// let marhta: Vec<char> = "MARHTA".chars().collect();
+    /// assert!((martha.jaro_winkler(&marhta) - 0.961).abs() < 0.001);
+    /// ```
+    fn jaro_winkler(&self, other: &[T]) -> f64;
+}
+impl<T: PartialEq> EditDistance<T> for [T] {
+    fn levenshtein(&self, other: &[T]) -> usize {
+        edit_distance(self, other, usize::MAX, false).unwrap_or_default()
+    }
+    fn levenshtein_within(&self, other: &[T], limit: usize) -> Option<usize> {
+        edit_distance(self, other, limit, false)
+    }
+    fn damerau_levenshtein(&self, other: &[T]) -> usize {
+        edit_distance(self, other, usize::MAX, true).unwrap_or_default()
+    }
+    fn damerau_levenshtein_within(&self, other: &[T], limit: usize) -> Option<usize> {
+        edit_distance(self, other, limit, true)
+    }
+    fn hamming(&self, other: &[T]) -> usize {
+        self.hamming_within(other, usize::MAX).unwrap_or_default()
+    }
+    fn hamming_within(&self, other: &[T], limit: usize) -> Option<usize> {
+        let mut distance = self.len().max(other.len()) - self.len().min(other.len());
+        for (x, y) in self.iter().zip(other) {
+            if distance > limit {
+                break;
+            }
+            distance += usize::from(x != y);
+        }
+        Some(distance).filter(|&distance| distance <= limit)
+    }
+    fn jaro_winkler(&self, other: &[T]) -> f64 {
+        jaro_winkler(self, other)
+    }
+}
+
+/// A run of elements in a diff, as produced by `Diff`.  Ranges index into the original
+/// sequence (`old`) and the one it is compared with (`new`).  A lone index marks where
+/// the run sits in the sequence it isn't part of.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DiffHunk {
+    /// Elements `old` of the original are found unchanged as elements `new`.
+    Keep {
+        /// The elements in the original.
+        old: Range<usize>,
+        /// The elements in the new sequence.
+        new: Range<usize>,
+    },
+    /// Elements `old` of the original are removed, from before element `new`.
+    Delete {
+        /// The removed elements.
+        old: Range<usize>,
+        /// Where they were removed from in the new sequence.
+        new: usize,
+    },
+    /// Elements `new` are added before element `old` of the original.
+    Insert {
+        /// Where the elements are added in the original.
+        old: usize,
+        /// The added elements.
+        new: Range<usize>,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Step {
+    Keep,
+    Delete,
+    Insert,
+}
+
+/// The shortest edit script turning `a` into `b`, by Myers' O((N+M)D) algorithm.
+fn diff_hunks<T, F: Fn(&T, &T) -> bool>(a: &[T], b: &[T], eq: F) -> Vec<DiffHunk> {
+    let prefix = a.iter().zip(b).take_while(|&(x, y)| eq(x, y)).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|&(x, y)| eq(x, y))
+        .count();
+    let (n, m) = (a.len() - prefix - suffix, b.len() - prefix - suffix);
+    let (x_at, y_at) = (|x: usize| &a[prefix + x], |y: usize| &b[prefix + y]);
+
+    // `furthest[k]` is the furthest x reached on diagonal k = x - y, offset by n + m + 1.
+    // Before each round the diagonals it can reach are kept for tracing the path back.
+    let offset = n + m + 1;
+    let mut furthest = vec![0; 2 * offset + 1];
+    let mut trace: Vec<Vec<usize>> = vec![];
+    'search: for d in 0..n + m + 1 {
+        trace.push(furthest[offset - d - 1..offset + d + 2].to_vec());
+        for k in (offset - d..offset + d + 1).step_by(2) {
+            let mut x = if k == offset - d || k != offset + d && furthest[k - 1] < furthest[k + 1] {
+                furthest[k + 1]
+            } else {
+                furthest[k - 1] + 1
+            };
+            let mut y = x + offset - k;
+            while x < n && y < m && eq(x_at(x), y_at(y)) {
+                x += 1;
+                y += 1;
+            }
+            furthest[k] = x;
+            if x >= n && y >= m {
+                break 'search;
+            }
+        }
+    }
+
+    let mut steps = vec![];
+    let (mut x, mut y) = (n, m);
+    for (d, band) in trace.iter().enumerate().skip(1).rev() {
+        let furthest = |k: usize| band[k + d + 1 - offset];
+        let k = x + offset - y;
+        let previous_k = if k == offset - d || k != offset + d && furthest(k - 1) < furthest(k + 1)
+        {
+            k + 1
+        } else {
+            k - 1
+        };
+        let previous_x = furthest(previous_k);
+        let previous_y = previous_x + offset - previous_k;
+        while x > previous_x && y > previous_y {
+            steps.push(Step::Keep);
+            x -= 1;
+            y -= 1;
+        }
+        steps.push(if x == previous_x {
+            Step::Insert
+        } else {
+            Step::Delete
+        });
+        x = previous_x;
+        y = previous_y;
+    }
+    // The first round follows only the diagonal from the start.
+    steps.extend((0..x).map(|_| Step::Keep));
+    steps.extend((0..prefix).map(|_| Step::Keep));
+    steps.reverse();
+    steps.extend((0..suffix).map(|_| Step::Keep));
+
+    let mut hunks: Vec<DiffHunk> = vec![];
+    let (mut x, mut y) = (0, 0);
+    for step in steps {
+        match (step, hunks.last_mut()) {
+            (
+                Step::Keep,
+                Some(&mut DiffHunk::Keep {
+                    ref mut old,
+                    ref mut new,
+                }),
+            ) => {
+                old.end += 1;
+                new.end += 1;
+            }
+            (Step::Delete, Some(&mut DiffHunk::Delete { ref mut old, .. })) => old.end += 1,
+            (Step::Insert, Some(&mut DiffHunk::Insert { ref mut new, .. })) => new.end += 1,
+            (Step::Keep, _) => hunks.push(DiffHunk::Keep {
+                old: x..x + 1,
+                new: y..y + 1,
+            }),
+            (Step::Delete, _) => hunks.push(DiffHunk::Delete {
+                old: x..x + 1,
+                new: y,
+            }),
+            (Step::Insert, _) => hunks.push(DiffHunk::Insert {
+                old: x,
+                new: y..y + 1,
+            }),
+        }
+        match step {
+            Step::Keep => {
+                x += 1;
+                y += 1;
+            }
+            Step::Delete => x += 1,
+            Step::Insert => y += 1,
+        }
+    }
+    hunks
+}
+
+/// The `@@ -1,3 +1,4 @@` style position of `count` lines from index `start`.
+fn unified_range(start: usize, count: usize) -> String {
+    match count {
+        0 => format!("{},0", start),
+        1 => format!("{}", start + 1),
+        _ => format!("{},{}", start + 1, count),
+    }
+}
+
+/// Find where two sequences differ, as the shortest list of elements to keep, delete
+/// and insert to turn one into the other.
+pub trait Diff<T> {
+    /// Compares with `other` and returns the edit script as runs of kept, deleted and
+    /// inserted elements.  Deletions come before insertions at the same place.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::vec::{Diff, DiffHunk};
+    ///
+    /// assert_eq!(
+    ///   vec!['a','b','c','d'].diff(&['a','x','c','d','e']),
+    ///   vec![
+    ///     DiffHunk::Keep { old: 0..1, new: 0..1 },
+    ///     DiffHunk::Delete { old: 1..2, new: 1 },
+    ///     DiffHunk::Insert { old: 2, new: 1..2 },
+    ///     DiffHunk::Keep { old: 2..4, new: 2..4 },
+    ///     DiffHunk::Insert { old: 4, new: 4..5 },
+    ///   ]
+    /// );
+    /// ```
+    fn diff(&self, other: &[T]) -> Vec<DiffHunk>;
+
+    /// Compares like `diff`, with elements taken as the same when the provided custom
+    /// comparator says so.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::vec::{Diff, DiffHunk};
+    ///
+    /// assert_eq!(
+    ///   vec!["Apple", "pear"].diff_via(&["apple", "Pear"], |l, r| l.eq_ignore_ascii_case(r)),
+    ///   vec![DiffHunk::Keep { old: 0..2, new: 0..2 }]
+    /// );
+    /// ```
+    fn diff_via<F: Fn(&T, &T) -> bool>(&self, other: &[T], f: F) -> Vec<DiffHunk>;
+
+    /// Renders the differences between two lists of lines in the unified diff format,
+    /// with `context` unchanged lines around each change.  Only the `@@` hunks are given,
+    /// without file names, and each line ends with a new line.
+    ///
+    /// # Example
+    /// ```
+    /// use array_tool::vec::Diff;
+    ///
+    /// let old = vec!["fn main() {", "    one();", "    two();", "}"];
+    /// let new = vec!["fn main() {", "    one();", "    three();", "}"];
+    /// assert_eq!(
+    ///   old.unified_diff(&new, 1),
+    ///   "@@ -2,3 +2,3 @@\n     one();\n-    two();\n+    three();\n }\n"
+    /// );
+    /// ```
+    fn unified_diff(&self, other: &[T], context: usize) -> String
+    where
+        T: AsRef<str>;
+}
+impl<T: PartialEq> Diff<T> for [T] {
+    fn diff(&self, other: &[T]) -> Vec<DiffHunk> {
+        diff_hunks(self, other, |l, r| l == r)
+    }
+
+    fn diff_via<F: Fn(&T, &T) -> bool>(&self, other: &[T], f: F) -> Vec<DiffHunk> {
+        diff_hunks(self, other, f)
+    }
+
+    fn unified_diff(&self, other: &[T], context: usize) -> String
+    where
+        T: AsRef<str>,
+    {
+        // Every line with its place in both lists, and whether it was kept, deleted or
+        // inserted.
+        let mut lines = vec![];
+        for hunk in self.diff(other) {
+            match hunk {
+                DiffHunk::Keep { old, new } => {
+                    lines.extend(old.zip(new).map(|(x, y)| (' ', x, y, self[x].as_ref())))
+                }
+                DiffHunk::Delete { old, new } => {
+                    lines.extend(old.map(|x| ('-', x, new, self[x].as_ref())))
+                }
+                DiffHunk::Insert { old, new } => {
+                    lines.extend(new.map(|y| ('+', old, y, other[y].as_ref())))
+                }
+            }
+        }
+        let changes: Vec<usize> = (0..lines.len()).filter(|&i| lines[i].0 != ' ').collect();
+
+        let mut output = String::new();
+        let mut index = 0;
+        while index < changes.len() {
+            // Changes no more than twice the context apart share a hunk.
+            let mut last = index;
+            while last + 1 < changes.len() && changes[last + 1] - changes[last] <= 2 * context + 1 {
+                last += 1;
+            }
+            let start = changes[index].saturating_sub(context);
+            let end = (changes[last] + context + 1).min(lines.len());
+            let hunk = &lines[start..end];
+            let old_count = hunk.iter().filter(|line| line.0 != '+').count();
+            let new_count = hunk.iter().filter(|line| line.0 != '-').count();
+            output.push_str(&format!(
+                "@@ -{} +{} @@\n",
+                unified_range(hunk[0].1, old_count),
+                unified_range(hunk[0].2, new_count)
+            ));
+            for &(sign, _, _, line) in hunk {
+                output.push(sign);
+                output.push_str(line);
+                output.push('\n');
+            }
+            index = last + 1;
+        }
+        output
+    }
+}
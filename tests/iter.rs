@@ -0,0 +1,508 @@
+extern crate array_tool;
+
+#[test]
+fn it_zips_longest_into_either_or_both() {
+    use array_tool::iter::{EitherOrBoth, ZipOpt};
+    let a = [1, 2, 3];
+    let b = ['x'];
+    assert_eq!(
+        a.iter().zip_longest(b.iter()).collect::<Vec<_>>(),
+        vec![
+            EitherOrBoth::Both(&1, &'x'),
+            EitherOrBoth::Left(&2),
+            EitherOrBoth::Left(&3),
+        ]
+    );
+    assert_eq!(
+        b.iter().zip_longest(a.iter()).collect::<Vec<_>>(),
+        vec![
+            EitherOrBoth::Both(&'x', &1),
+            EitherOrBoth::Right(&2),
+            EitherOrBoth::Right(&3),
+        ]
+    );
+    assert_eq!(
+        Vec::<u8>::new()
+            .into_iter()
+            .zip_longest(Vec::<u8>::new())
+            .next(),
+        None
+    );
+}
+
+#[test]
+fn it_zips_longest_from_both_ends() {
+    use array_tool::iter::{EitherOrBoth, ZipOpt};
+    let mut x = vec![1, 2, 3, 4].into_iter().zip_longest(vec![10, 20]);
+    assert_eq!(x.len(), 4);
+    assert_eq!(x.size_hint(), (4, Some(4)));
+    assert_eq!(x.next_back(), Some(EitherOrBoth::Left(4)));
+    assert_eq!(x.next(), Some(EitherOrBoth::Both(1, 10)));
+    assert_eq!(x.next_back(), Some(EitherOrBoth::Left(3)));
+    assert_eq!(x.next_back(), Some(EitherOrBoth::Both(2, 20)));
+    assert_eq!(x.next_back(), None);
+    assert_eq!(x.next(), None);
+    assert_eq!(
+        vec![1]
+            .into_iter()
+            .zip_longest(vec![7, 8, 9])
+            .rev()
+            .collect::<Vec<_>>(),
+        vec![
+            EitherOrBoth::Right(9),
+            EitherOrBoth::Right(8),
+            EitherOrBoth::Both(1, 7)
+        ]
+    );
+}
+
+#[test]
+fn it_inspects_either_or_both() {
+    use array_tool::iter::EitherOrBoth;
+    let both: EitherOrBoth<u8, char> = EitherOrBoth::Both(1, 'a');
+    let left: EitherOrBoth<u8, char> = EitherOrBoth::Left(2);
+    let right: EitherOrBoth<u8, char> = EitherOrBoth::Right('b');
+    assert!(both.has_left() && both.has_right() && both.is_both());
+    assert!(left.has_left() && !left.has_right() && !left.is_both());
+    assert!(!right.has_left() && right.has_right() && !right.is_both());
+    assert_eq!(
+        (both.left(), left.left(), right.left()),
+        (Some(1), Some(2), None)
+    );
+    assert_eq!(
+        (both.right(), left.right(), right.right()),
+        (Some('a'), None, Some('b'))
+    );
+    assert_eq!((both.both(), left.both()), (Some((1, 'a')), None));
+    assert_eq!(left.left_and_right(), (Some(2), None));
+    assert_eq!(right.as_ref(), EitherOrBoth::Right(&'b'));
+}
+
+#[test]
+fn it_fills_and_maps_either_or_both() {
+    use array_tool::iter::EitherOrBoth;
+    let left: EitherOrBoth<u8, char> = EitherOrBoth::Left(2);
+    let right: EitherOrBoth<u8, char> = EitherOrBoth::Right('b');
+    assert_eq!(left.or(0, '-'), (2, '-'));
+    assert_eq!(right.or(0, '-'), (0, 'b'));
+    assert_eq!(left.or_default(), (2, '\0'));
+    assert_eq!(right.or_else(|| 9, || unreachable!()), (9, 'b'));
+    assert_eq!(left.map_left(|a| a * 2), EitherOrBoth::Left(4));
+    assert_eq!(right.map_left(|a| a * 2), EitherOrBoth::Right('b'));
+    assert_eq!(
+        right.map_right(|c| c.to_ascii_uppercase()),
+        EitherOrBoth::Right('B')
+    );
+    assert_eq!(
+        EitherOrBoth::Both(2u8, 'c').map_any(u32::from, u32::from),
+        EitherOrBoth::Both(2, 99)
+    );
+    assert_eq!(EitherOrBoth::Both(2, 3).reduce(|a, b| a * b), 6);
+    assert_eq!(EitherOrBoth::Right(3).reduce(|a: u8, b| a * b), 3);
+}
+
+#[test]
+fn it_still_zips_option() {
+    use array_tool::iter::ZipOpt;
+    let x: Vec<_> = vec![1, 2].into_iter().zip_option(vec![5]).collect();
+    assert_eq!(x, vec![(Some(1), Some(5)), (Some(2), None)]);
+}
+
+#[test]
+fn it_zips_option_across_many_iterators() {
+    use array_tool::iter::ZipOptAll;
+    let columns = vec![vec![1, 2, 3], vec![], vec![4, 5]];
+    assert_eq!(
+        (&columns).zip_option_all().collect::<Vec<_>>(),
+        vec![
+            vec![Some(&1), None, Some(&4)],
+            vec![Some(&2), None, Some(&5)],
+            vec![Some(&3), None, None],
+        ]
+    );
+    assert_eq!(
+        [vec!['a'], vec!['b', 'c']]
+            .zip_option_all()
+            .collect::<Vec<_>>(),
+        vec![vec![Some('a'), Some('b')], vec![None, Some('c')]]
+    );
+    assert_eq!(Vec::<Vec<u8>>::new().zip_option_all().next(), None);
+    assert_eq!(vec![Vec::<u8>::new(); 3].zip_option_all().next(), None);
+}
+
+#[test]
+fn it_zips_option_across_many_iterators_from_both_ends() {
+    use array_tool::iter::ZipOptAll;
+    let mut x = vec![vec![1, 2, 3], vec![4], vec![5, 6]].zip_option_all();
+    assert_eq!(x.len(), 3);
+    assert_eq!(x.next_back(), Some(vec![Some(3), None, None]));
+    assert_eq!(x.size_hint(), (2, Some(2)));
+    assert_eq!(x.next(), Some(vec![Some(1), Some(4), Some(5)]));
+    assert_eq!(x.next_back(), Some(vec![Some(2), None, Some(6)]));
+    assert_eq!(x.next_back(), None);
+    assert_eq!(x.next(), None);
+}
+
+#[test]
+fn it_zips_longest_filling_with_a_value() {
+    use array_tool::iter::ZipOpt;
+    let old_version = [1, 4];
+    let new_version = [1, 4, 2];
+    assert_eq!(
+        old_version
+            .iter()
+            .cloned()
+            .zip_longest_fill(new_version.iter().cloned(), 0, 0)
+            .collect::<Vec<_>>(),
+        vec![(1, 1), (4, 4), (0, 2)]
+    );
+    let mut x = vec!["a", "b", "c"]
+        .into_iter()
+        .zip_longest_fill(vec![1], "-", -1);
+    assert_eq!(x.len(), 3);
+    assert_eq!(x.next_back(), Some(("c", -1)));
+    assert_eq!(x.next(), Some(("a", 1)));
+    assert_eq!(x.next(), Some(("b", -1)));
+    assert_eq!(x.next(), None);
+}
+
+#[test]
+fn it_zips_longest_filling_with_defaults() {
+    use array_tool::iter::ZipOpt;
+    let x: Vec<(String, u8)> = vec![String::from("x")]
+        .into_iter()
+        .zip_longest_default(vec![1, 2])
+        .collect();
+    assert_eq!(x, vec![(String::from("x"), 1), (String::new(), 2)]);
+    assert_eq!(
+        Vec::<u8>::new()
+            .into_iter()
+            .zip_longest_default(Vec::<u8>::new())
+            .next(),
+        None
+    );
+}
+
+#[test]
+fn it_zips_longest_filling_with_a_closure() {
+    use array_tool::iter::ZipOpt;
+    let x: Vec<(usize, char)> = vec![10, 20, 30, 40]
+        .into_iter()
+        .zip_longest_with(vec!['a'], |i| i, |i| (b'a' + i as u8) as char)
+        .collect();
+    assert_eq!(x, vec![(10, 'a'), (20, 'b'), (30, 'c'), (40, 'd')]);
+
+    let mut y =
+        vec![10, 20, 30, 40]
+            .into_iter()
+            .zip_longest_with(vec![1, 2], |i| i * 100, |i| i * 1000);
+    assert_eq!(y.next_back(), Some((40, 3000)));
+    assert_eq!(y.next(), Some((10, 1)));
+    assert_eq!(y.next_back(), Some((30, 2000)));
+    assert_eq!(y.len(), 1);
+    assert_eq!(y.next_back(), Some((20, 2)));
+    assert_eq!(y.next_back(), None);
+
+    let mut z = vec![1]
+        .into_iter()
+        .zip_longest_with(vec![5, 6, 7], |i| i * 100, |i| i);
+    assert_eq!(z.next_back(), Some((200, 7)));
+    assert_eq!(z.next(), Some((1, 5)));
+    assert_eq!(z.next(), Some((100, 6)));
+}
+
+#[test]
+fn it_sizes_zip_option_by_the_longer_iterator() {
+    use array_tool::iter::ZipOpt;
+    let x = vec![1, 2, 3].into_iter().zip_option(vec![4]);
+    assert_eq!(x.size_hint(), (3, Some(3)));
+    assert_eq!(x.len(), 3);
+    let y = vec![1]
+        .into_iter()
+        .zip_option(vec![2, 3].into_iter().cycle());
+    assert_eq!(y.size_hint(), (usize::MAX, None));
+    let z = (0..4).zip_option((0..10).filter(|x| x % 2 == 0));
+    assert_eq!(z.size_hint(), (4, Some(10)));
+    assert_eq!(z.count(), 5);
+}
+
+#[test]
+fn it_zips_option_from_both_ends_without_losing_items() {
+    use array_tool::iter::ZipOpt;
+    let mut x = vec![1, 2, 3, 4].into_iter().zip_option(vec![10, 20]);
+    assert_eq!(x.next_back(), Some((Some(4), None)));
+    assert_eq!(x.next(), Some((Some(1), Some(10))));
+    assert_eq!(x.len(), 2);
+    assert_eq!(x.next_back(), Some((Some(3), None)));
+    assert_eq!(x.next_back(), Some((Some(2), Some(20))));
+    assert_eq!(x.next_back(), None);
+    assert_eq!(x.next(), None);
+    assert_eq!(
+        vec![1]
+            .into_iter()
+            .zip_option(vec![7, 8])
+            .rev()
+            .collect::<Vec<_>>(),
+        vec![(None, Some(8)), (Some(1), Some(7))]
+    );
+    let forward: Vec<_> = (0..5).zip_option(0..3).collect();
+    let mut backward: Vec<_> = (0..5).zip_option(0..3).rev().collect();
+    backward.reverse();
+    assert_eq!(forward, backward);
+}
+
+#[test]
+fn it_fuses_zip_option() {
+    use array_tool::iter::{ZipOpt, ZipOptAll};
+    use std::iter::FusedIterator;
+    fn fused<I: FusedIterator>(_: &I) {}
+    let mut x = vec![1].into_iter().zip_option(vec![2]);
+    fused(&x);
+    assert_eq!(x.next(), Some((Some(1), Some(2))));
+    assert_eq!(x.next(), None);
+    assert_eq!(x.next(), None);
+    fused(&(0..2).zip_longest(0..1));
+    fused(&(0..2).zip_longest_fill(0..1, 0, 0));
+    fused(&(0..2).zip_longest_default(0..1));
+    fused(&vec![0..2, 0..1].zip_option_all());
+}
+
+#[test]
+fn it_groups_each_slice() {
+    use array_tool::iter::Chunking;
+    let mut x = (1..8).each_slice(3);
+    assert_eq!(x.size_hint(), (3, Some(3)));
+    assert_eq!(x.next(), Some(vec![1, 2, 3]));
+    assert_eq!(x.next(), Some(vec![4, 5, 6]));
+    assert_eq!(x.next(), Some(vec![7]));
+    assert_eq!(x.next(), None);
+    assert_eq!(
+        (1..7).each_slice(2).collect::<Vec<_>>(),
+        vec![vec![1, 2], vec![3, 4], vec![5, 6]]
+    );
+    assert_eq!((0..0).each_slice(2).next(), None);
+    assert_eq!(
+        "abcde".chars().each_slice_pad(2, '_').collect::<Vec<_>>(),
+        vec![vec!['a', 'b'], vec!['c', 'd'], vec!['e', '_']]
+    );
+    assert_eq!((0..4).each_slice_pad(2, 9).last(), Some(vec![2, 3]));
+    assert_eq!((0..0).each_slice_pad(2, 9).next(), None);
+}
+
+#[test]
+#[should_panic]
+fn it_refuses_empty_slices() {
+    use array_tool::iter::Chunking;
+    let _ = (1..8).each_slice(0);
+}
+
+#[test]
+fn it_groups_each_cons() {
+    use array_tool::iter::Chunking;
+    let mut x = "abcd".chars().each_cons(3);
+    assert_eq!(x.next(), Some(vec!['a', 'b', 'c']));
+    assert_eq!(x.next(), Some(vec!['b', 'c', 'd']));
+    assert_eq!(x.next(), None);
+    assert_eq!(
+        (1..5).each_cons(1).collect::<Vec<_>>(),
+        vec![vec![1], vec![2], vec![3], vec![4]]
+    );
+    assert_eq!((1..3).each_cons(3).next(), None);
+    let mut y = (0..10).each_cons(4);
+    assert_eq!(y.size_hint(), (7, Some(7)));
+    y.next();
+    assert_eq!(y.size_hint(), (6, Some(6)));
+    assert_eq!(y.count(), 6);
+    let readings = vec![1.0, 2.0, 6.0, 7.0];
+    let averages: Vec<f64> = readings
+        .into_iter()
+        .each_cons(2)
+        .map(|pair| (pair[0] + pair[1]) / 2.0)
+        .collect();
+    assert_eq!(averages, vec![1.5, 4.0, 6.5]);
+}
+
+#[test]
+fn it_slices_when_neighbours_differ() {
+    use array_tool::iter::Chunking;
+    assert_eq!(
+        vec![1, 2, 4, 9, 10, 11, 12, 15]
+            .into_iter()
+            .slice_when(|a, b| a + 1 != *b)
+            .collect::<Vec<_>>(),
+        vec![vec![1, 2], vec![4], vec![9, 10, 11, 12], vec![15]]
+    );
+    assert_eq!(
+        "aabccc"
+            .chars()
+            .slice_when(|a, b| a != b)
+            .collect::<Vec<_>>(),
+        vec![vec!['a', 'a'], vec!['b'], vec!['c', 'c', 'c']]
+    );
+    assert_eq!(
+        (1..4).slice_when(|_, _| false).collect::<Vec<_>>(),
+        vec![vec![1, 2, 3]]
+    );
+    assert_eq!((1..4).slice_when(|_, _| true).count(), 3);
+    assert_eq!((0..0).slice_when(|_, _| true).next(), None);
+    assert_eq!((0..5).slice_when(|_, _| true).size_hint(), (1, Some(5)));
+}
+
+#[test]
+fn it_chunks_while_neighbours_agree() {
+    use array_tool::iter::Chunking;
+    assert_eq!(
+        vec![1, 4, 9, 10, 11, 12, 15, 16, 19, 20, 21]
+            .into_iter()
+            .chunk_while(|a, b| a + 1 == *b)
+            .collect::<Vec<_>>(),
+        vec![
+            vec![1],
+            vec![4],
+            vec![9, 10, 11, 12],
+            vec![15, 16],
+            vec![19, 20, 21]
+        ]
+    );
+    assert_eq!(
+        vec![1, 2, 4, 3, 5]
+            .into_iter()
+            .chunk_while(|a, b| a <= b)
+            .collect::<Vec<_>>(),
+        vec![vec![1, 2, 4], vec![3, 5]]
+    );
+    assert_eq!((0..0).chunk_while(|_, _| true).next(), None);
+    let mut calls = 0;
+    assert_eq!(
+        (0..4)
+            .chunk_while(|_, _| {
+                calls += 1;
+                true
+            })
+            .count(),
+        1
+    );
+    assert_eq!(calls, 3);
+}
+
+#[test]
+fn it_interleaves() {
+    use array_tool::iter::Interleaving;
+    assert_eq!(
+        vec![1, 3, 5, 7]
+            .into_iter()
+            .interleave(vec![2, 4])
+            .collect::<Vec<_>>(),
+        vec![1, 2, 3, 4, 5, 7]
+    );
+    assert_eq!(
+        vec![1]
+            .into_iter()
+            .interleave(vec![2, 4, 6])
+            .collect::<Vec<_>>(),
+        vec![1, 2, 4, 6]
+    );
+    assert_eq!((0..0).interleave(0..0).next(), None);
+    let mut x = (0..3).interleave(10..12);
+    assert_eq!(x.size_hint(), (5, Some(5)));
+    x.next();
+    assert_eq!(x.size_hint(), (4, Some(4)));
+    assert_eq!(x.collect::<Vec<_>>(), vec![10, 1, 11, 2]);
+}
+
+#[test]
+fn it_interleaves_to_the_shortest() {
+    use array_tool::iter::Interleaving;
+    assert_eq!(
+        vec![1, 3, 5, 7]
+            .into_iter()
+            .interleave_shortest(vec![2, 4])
+            .collect::<Vec<_>>(),
+        vec![1, 2, 3, 4, 5]
+    );
+    assert_eq!(
+        vec![1]
+            .into_iter()
+            .interleave_shortest(vec![2, 4, 6])
+            .collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+    assert_eq!((0..0).interleave_shortest(1..5).next(), None);
+    for a in 0..4 {
+        for b in 0..4 {
+            let mut x = (0..a).interleave_shortest(10..10 + b);
+            for _ in 0..2 {
+                let hint = x.size_hint();
+                assert_eq!(hint, (x.clone().count(), Some(x.clone().count())));
+                x.next();
+            }
+        }
+    }
+}
+
+#[test]
+fn it_intersperses() {
+    use array_tool::iter::Interleaving;
+    assert_eq!(
+        vec![1, 2, 3]
+            .into_iter()
+            .intersperse_items(0)
+            .collect::<Vec<_>>(),
+        vec![1, 0, 2, 0, 3]
+    );
+    assert_eq!((0..1).intersperse_items(9).collect::<Vec<_>>(), vec![0]);
+    assert_eq!((0..0).intersperse_items(9).next(), None);
+    let mut x = (0..3).intersperse_items(9);
+    assert_eq!(x.size_hint(), (5, Some(5)));
+    x.next();
+    assert_eq!(x.size_hint(), (4, Some(4)));
+    assert_eq!(x.collect::<Vec<_>>(), vec![9, 1, 9, 2]);
+
+    let tokens: Vec<String> = vec!["let", "x", "=", "1"]
+        .into_iter()
+        .map(String::from)
+        .intersperse_items(String::from(" "))
+        .collect();
+    assert_eq!(tokens.concat(), "let x = 1");
+}
+
+#[test]
+fn it_intersperses_with_a_closure() {
+    use array_tool::iter::Interleaving;
+    let mut separators = 0;
+    let x: Vec<u8> = vec![1, 2, 3]
+        .into_iter()
+        .intersperse_items_with(|| {
+            separators += 1;
+            0
+        })
+        .collect();
+    assert_eq!(x, vec![1, 0, 2, 0, 3]);
+    assert_eq!(separators, 2);
+    let y = (0..4).intersperse_items_with(|| 9);
+    assert_eq!(y.size_hint(), (7, Some(7)));
+}
+
+#[test]
+fn it_interleaves_alongside_zip_option() {
+    use array_tool::iter::{Interleaving, ZipOpt};
+    let a = [1, 3, 5, 7];
+    let b = [2, 4];
+    let flattened: Vec<u8> = a
+        .iter()
+        .zip_option(b.iter())
+        .flat_map(|(x, y)| x.into_iter().chain(y))
+        .cloned()
+        .collect();
+    assert_eq!(
+        flattened,
+        a.iter().interleave(b.iter()).cloned().collect::<Vec<_>>()
+    );
+    let pairs: Vec<_> = a
+        .iter()
+        .zip_option(b.iter())
+        .intersperse_items((None, None))
+        .collect();
+    assert_eq!(pairs.len(), 7);
+    assert_eq!(pairs[1], (None, None));
+}
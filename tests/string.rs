@@ -15,6 +15,114 @@ fn it_squeezes_characters() {
     assert_eq!("".squeeze(""), "");
 }
 
+#[test]
+fn it_squeezes_ruby_character_set_specs() {
+    use array_tool::string::Squeeze;
+
+    assert_eq!("putters shoot balls".squeeze("m-z"), "puters shot balls");
+    assert_eq!("aaabbbccc".squeeze("^b"), "abbbc");
+    assert_eq!("aaa^^^bbb".squeeze("^"), "aaa^bbb");
+    assert_eq!("aa--bb--cc".squeeze("-"), "aa-bb-cc");
+    assert_eq!("aa--bb--cc".squeeze("a-"), "a-bb-cc");
+    assert_eq!("aa--bb--cc".squeeze("-c"), "aa-bb-c");
+    assert_eq!("aa--bb--cc".squeeze("a\\-c"), "a-bb-c");
+    assert_eq!("aa^^bb".squeeze("\\^"), "aa^bb");
+    assert_eq!("aa\\\\bb".squeeze("\\"), "aa\\bb");
+    assert_eq!("aabbcc".squeeze("c-a"), "aabbcc");
+    assert_eq!("ééèèaa".squeeze("à-ê"), "éèaa");
+    assert_eq!("  \t\t\n\n".squeeze("^ "), "  \t\n");
+    assert_eq!("\0\0a".squeeze(""), "\0a");
+
+    let targets = String::from("o");
+    assert_eq!("yellow moon".squeeze(&targets), "yellow mon");
+}
+
+#[test]
+fn it_squeezes_intersection_of_character_sets() {
+    use array_tool::string::Squeeze;
+
+    assert_eq!(
+        "hello  wwoorld".squeeze_sets(&["a-z", "^l"]),
+        "hello  world"
+    );
+    assert_eq!(
+        "hello  wwoorld".squeeze_sets(&["a-z", "^l", "^o"]),
+        "hello  woorld"
+    );
+    assert_eq!("hello  wwoorld".squeeze_sets(&[]), "helo world");
+    assert_eq!("hello  wwoorld".squeeze_sets(&[""]), "hello  wwoorld");
+    assert_eq!("".squeeze_sets(&["a-z"]), "");
+}
+
+#[test]
+fn it_translates_characters() {
+    use array_tool::string::Tr;
+
+    assert_eq!("hello".tr("el", "ip"), "hippo");
+    assert_eq!("hello".tr("aeiou", "*"), "h*ll*");
+    assert_eq!("hello".tr("aeiou", "AA*"), "hAll*");
+    assert_eq!("hello".tr("a-y", "b-z"), "ifmmp");
+    assert_eq!("hello".tr("^l", "*"), "**ll*");
+    assert_eq!("hello".tr("^l", "ab"), "bbllb");
+    assert_eq!("hello".tr("el", ""), "ho");
+    assert_eq!("hello".tr("", "x"), "hello");
+    assert_eq!("hello".tr("ll", "xy"), "heyyo");
+    assert_eq!("hel-lo".tr("\\-", "_"), "hel_lo");
+    assert_eq!("hel-lo".tr("a-", "_"), "hel_lo");
+    assert_eq!("hello".tr("lo", "^"), "he^^^");
+    assert_eq!("héllo wörld".tr("éö", "eo"), "hello world");
+    assert_eq!("abc".tr("a-c", "α-γ"), "αβγ");
+    assert_eq!("abc".tr("c-a", "x"), "abc");
+    assert_eq!("".tr("a-z", "A-Z"), "");
+
+    let from = String::from("a-z");
+    let to = String::from("A-Z");
+    assert_eq!("hello".tr(&from, &to), "HELLO");
+}
+
+#[test]
+fn it_translates_and_squeezes_characters() {
+    use array_tool::string::Tr;
+
+    assert_eq!("hello".tr_s("l", "r"), "hero");
+    assert_eq!("hello".tr_s("el", "-"), "h-o");
+    assert_eq!("hello".tr_s("el", "hx"), "hhxo");
+    assert_eq!("aabbcc".tr_s("a-c", "x"), "x");
+    assert_eq!("aabbcc".tr_s("ab", "xy"), "xycc");
+    assert_eq!("aa  bb".tr_s("^ ", "*"), "*  *");
+    assert_eq!("hello".tr_s("l", ""), "heo");
+}
+
+#[test]
+fn it_deletes_characters() {
+    use array_tool::string::Delete;
+
+    assert_eq!("hello".delete("l"), "heo");
+    assert_eq!("hello".delete("l-o"), "he");
+    assert_eq!("hello".delete("^l"), "ll");
+    assert_eq!("he-llo".delete("a\\-z"), "hello");
+    assert_eq!("héllo".delete("é"), "hllo");
+    // A combining accent is a character of its own.
+    assert_eq!("he\u{301}llo".delete("\u{301}"), "hello");
+    assert_eq!("hello".delete(""), "hello");
+    assert_eq!("hello".delete_sets(&["lo", "o"]), "hell");
+    assert_eq!("hello".delete_sets(&["a-z", "^l"]), "ll");
+}
+
+#[test]
+fn it_counts_characters() {
+    use array_tool::string::Count;
+
+    assert_eq!("hello world".count("lo"), 5);
+    assert_eq!("hello world".count("^a-z"), 1);
+    assert_eq!("hello world".count(""), 0);
+    assert_eq!("hello^world".count("^"), 1);
+    assert_eq!("hello-world".count("a\\-"), 1);
+    assert_eq!("ééé".count("é"), 3);
+    assert_eq!("hello world".count_sets(&["lo", "o"]), 2);
+    assert_eq!("hello world".count_sets(&["a-y", "^l"]), 7);
+}
+
 #[test]
 fn it_iterates_over_every_grapheme_character() {
     use array_tool::string::ToGraphemeBytesIter;
@@ -50,6 +158,239 @@ fn it_iterates_over_every_grapheme_character() {
     assert_eq!("".grapheme_bytes_iter().next(), None);
 }
 
+#[test]
+fn it_reports_remaining_length_of_grapheme_bytes() {
+    use array_tool::string::ToGraphemeBytesIter;
+
+    let mut giter = "a s—d".grapheme_bytes_iter();
+    assert_eq!(giter.len(), 5);
+    giter.next();
+    giter.next();
+    assert_eq!(giter.len(), 3);
+    assert_eq!(giter.size_hint(), (3, Some(3)));
+    assert_eq!(giter.count(), 3);
+}
+
+#[test]
+fn it_iterates_over_every_grapheme_as_str() {
+    use array_tool::string::ToGraphemeIter;
+
+    let temp = "a s—d féZ";
+    let graphemes: Vec<&str> = temp.grapheme_iter().collect();
+    assert_eq!(graphemes, vec!["a", " ", "s", "—", "d", " ", "f", "é", "Z"]);
+
+    let reversed: Vec<&str> = temp.grapheme_iter().rev().collect();
+    assert_eq!(reversed, vec!["Z", "é", "f", " ", "d", "—", "s", " ", "a"]);
+
+    let mut giter = temp.grapheme_iter();
+    assert_eq!(giter.next(), Some("a"));
+    assert_eq!(giter.next_back(), Some("Z"));
+    assert_eq!(giter.next_back(), Some("é"));
+    assert_eq!(giter.as_str(), " s—d f");
+    assert_eq!(giter.next(), Some(" "));
+    assert_eq!(giter.count(), 5);
+
+    let mut giter = "éé".grapheme_iter();
+    assert_eq!(giter.next(), Some("é"));
+    assert_eq!(giter.next_back(), Some("é"));
+    assert_eq!(giter.next(), None);
+    assert_eq!(giter.next_back(), None);
+
+    assert_eq!("".grapheme_iter().next(), None);
+    assert_eq!("".grapheme_iter().next_back(), None);
+
+    // Characters, not extended grapheme clusters.
+    let combined: Vec<&str> = "e\u{301}x".grapheme_iter().collect();
+    assert_eq!(combined, vec!["e", "\u{301}", "x"]);
+}
+
+#[test]
+fn it_gives_bounded_size_hints_for_graphemes() {
+    use array_tool::string::ToGraphemeIter;
+
+    for source in &["", "a", "asdf", "é", "a s—d féZ", "—————", "𝄞𝄞"] {
+        let mut giter = source.grapheme_iter();
+        loop {
+            let (lower, upper) = giter.size_hint();
+            let remaining = giter.clone().count();
+            assert!(lower <= remaining);
+            assert!(upper.unwrap() >= remaining);
+            if giter.next().is_none() {
+                break;
+            }
+        }
+    }
+}
+
+#[test]
+fn it_iterates_over_word_bounds() {
+    use array_tool::string::ToWordIter;
+
+    let pieces: Vec<&str> = "The quick (\"brown\") fox can't jump 32.3 feet, right?"
+        .word_bounds()
+        .collect();
+    assert_eq!(
+        pieces,
+        vec![
+            "The", " ", "quick", " ", "(", "\"", "brown", "\"", ")", " ", "fox", " ", "can't", " ",
+            "jump", " ", "32.3", " ", "feet", ",", " ", "right", "?",
+        ]
+    );
+
+    // Marks, emoji sequences, flags and line endings stay whole.
+    let pieces: Vec<&str> = "e\u{301}t\u{e9} 👨‍👩‍👧 🇩🇪🇫🇷\r\n\n".word_bounds().collect();
+    assert_eq!(
+        pieces,
+        vec!["e\u{301}t\u{e9}", " ", "👨‍👩‍👧", " ", "🇩🇪", "🇫🇷", "\r\n", "\n"]
+    );
+
+    let source = "a_b 東京";
+    let mut bounds = source.word_bounds();
+    assert_eq!(bounds.next(), Some("a_b"));
+    assert_eq!(bounds.offset(), 3);
+    assert_eq!(bounds.as_str(), " 東京");
+    assert_eq!(bounds.collect::<String>(), " 東京");
+
+    assert_eq!("".word_bounds().next(), None);
+}
+
+#[test]
+fn it_iterates_over_unicode_words() {
+    use array_tool::string::ToWordIter;
+
+    let words: Vec<&str> = "The quick (\"brown\") fox can't jump 32.3 feet, right?"
+        .unicode_words()
+        .collect();
+    assert_eq!(
+        words,
+        vec!["The", "quick", "brown", "fox", "can't", "jump", "32.3", "feet", "right"]
+    );
+
+    let words: Vec<&str> = "naïve café—déjà vu; 1,000 🐈 日本語テキスト"
+        .unicode_words()
+        .collect();
+    assert_eq!(
+        words,
+        vec![
+            "naïve",
+            "café",
+            "déjà",
+            "vu",
+            "1,000",
+            "日",
+            "本",
+            "語",
+            "テキスト"
+        ]
+    );
+
+    assert_eq!("... — !!".unicode_words().next(), None);
+}
+
+#[test]
+fn it_iterates_over_sentences() {
+    use array_tool::string::ToSentenceIter;
+
+    let sentences: Vec<&str> = "Hello there. How are you?! I'm fine (really.)  Bye"
+        .sentences()
+        .collect();
+    assert_eq!(
+        sentences,
+        vec![
+            "Hello there. ",
+            "How are you?! ",
+            "I'm fine (really.)  ",
+            "Bye"
+        ]
+    );
+
+    // Abbreviations, decimals and initials don't end a sentence.
+    let sentences: Vec<&str> = "See etc. for more. It cost 3.50 today.\r\n\r\nDone."
+        .sentences()
+        .collect();
+    assert_eq!(
+        sentences,
+        vec![
+            "See etc. for more. ",
+            "It cost 3.50 today.\r\n",
+            "\r\n",
+            "Done."
+        ]
+    );
+
+    let sentences: Vec<&str> = "それは本です。これは？はい".sentences().collect();
+    assert_eq!(sentences, vec!["それは本です。", "これは？", "はい"]);
+
+    let mut sentences = "One. Two.".sentences();
+    assert_eq!(sentences.next(), Some("One. "));
+    assert_eq!(sentences.offset(), 5);
+    assert_eq!(sentences.as_str(), "Two.");
+    assert_eq!(sentences.next(), Some("Two."));
+    assert_eq!(sentences.next(), None);
+    assert_eq!("".sentences().next(), None);
+}
+
+#[test]
+fn it_iterates_over_grapheme_indices() {
+    use array_tool::string::ToGraphemeIter;
+
+    let temp = "a s—d féZ";
+    let indices: Vec<(usize, &str)> = temp.grapheme_indices().collect();
+    assert_eq!(
+        indices,
+        vec![
+            (0, "a"),
+            (1, " "),
+            (2, "s"),
+            (3, "—"),
+            (6, "d"),
+            (7, " "),
+            (8, "f"),
+            (9, "é"),
+            (11, "Z")
+        ]
+    );
+
+    let reversed: Vec<(usize, &str)> = temp.grapheme_indices().rev().take(3).collect();
+    assert_eq!(reversed, vec![(11, "Z"), (9, "é"), (8, "f")]);
+
+    for (offset, grapheme) in temp.grapheme_indices() {
+        assert_eq!(&temp[offset..offset + grapheme.len()], grapheme);
+    }
+
+    let mut giter = "ab".grapheme_indices();
+    assert_eq!(giter.next_back(), Some((1, "b")));
+    assert_eq!(giter.as_str(), "a");
+    assert_eq!(giter.next(), Some((0, "a")));
+    assert_eq!(giter.next(), None);
+}
+
+#[test]
+fn it_measures_display_width() {
+    use array_tool::string::DisplayWidth;
+
+    assert_eq!("".display_width(), 0);
+    assert_eq!("asdf".display_width(), 4);
+    assert_eq!("a s—d féZ".display_width(), 9);
+    assert_eq!("漢字かな".display_width(), 8);
+    assert_eq!("ｱｲｳ".display_width(), 3);
+    assert_eq!("ＡＢＣ".display_width(), 6);
+    assert_eq!("😀".display_width(), 2);
+    assert_eq!("e\u{301}".display_width(), 1);
+    assert_eq!("a\u{200B}b".display_width(), 2);
+    assert_eq!("\u{1100}\u{1161}\u{11A8}".display_width(), 2);
+    assert_eq!("a\tb\n".display_width(), 2);
+    assert_eq!("\u{7F}\u{85}".display_width(), 0);
+
+    assert_eq!('a'.display_width(), 1);
+    assert_eq!('漢'.display_width(), 2);
+    assert_eq!('\u{301}'.display_width(), 0);
+    assert_eq!('°'.display_width(), 1);
+    assert_eq!('°'.display_width_cjk(), 2);
+    assert_eq!("±1°".display_width_cjk(), 5);
+    assert_eq!("漢字".display_width_cjk(), 4);
+}
+
 #[test]
 fn it_justifies_one_line_in_for_string() {
     use array_tool::string::Justify;
@@ -67,6 +408,127 @@ fn it_justifies_one_line_in_for_string() {
     assert_eq!("".justify_line(14), "");
 }
 
+#[test]
+fn it_justifies_one_line_with_space_distribution() {
+    use array_tool::string::{Justify, SpaceDistribution};
+
+    assert_eq!(
+        "asd as df asd".justify_line_with(18, SpaceDistribution::Left),
+        "asd   as   df  asd"
+    );
+    assert_eq!(
+        "asd as df asd".justify_line_with(18, SpaceDistribution::Right),
+        "asd  as   df   asd"
+    );
+    assert_eq!(
+        "asd as df asd".justify_line_with(18, SpaceDistribution::Alternating),
+        "asd   as   df  asd"
+    );
+    assert_eq!("asd  as".justify_line(8), "asd   as");
+    assert_eq!("asd".justify_line_with(8, SpaceDistribution::Right), "asd");
+}
+
+#[test]
+fn it_justifies_paragraphs() {
+    use array_tool::string::Justify;
+
+    assert_eq!(
+        "The quick brown fox jumps over the lazy dog.".justify(16),
+        "The  quick brown\nfox  jumps  over\nthe lazy dog."
+    );
+    assert_eq!(
+        "The quick brown\nfox jumps over\nthe lazy dog.".justify(20),
+        "The  quick brown fox\njumps  over the lazy\ndog."
+    );
+    assert_eq!(
+        "aaa bb c\n\ndd e ffff gg\n".justify(10),
+        "aaa bb c\n\ndd  e ffff\ngg\n"
+    );
+    assert_eq!("a b\n\n\nc d".justify(10), "a b\n\n\nc d");
+    assert_eq!(
+        "tiny averyveryverylongword tiny".justify(8),
+        "tiny\naveryveryverylongword\ntiny"
+    );
+    assert_eq!("  \n".justify(8), "\n");
+    assert_eq!("".justify(8), "");
+}
+
+#[test]
+fn it_justifies_paragraphs_with_space_distribution() {
+    use array_tool::string::{Justify, SpaceDistribution};
+
+    let text = "a b c d e f g h i j k";
+    assert_eq!(
+        text.justify_with(8, SpaceDistribution::Left),
+        "a  b c d\ne  f g h\ni j k"
+    );
+    assert_eq!(
+        text.justify_with(8, SpaceDistribution::Right),
+        "a b c  d\ne f g  h\ni j k"
+    );
+    assert_eq!(
+        text.justify_with(8, SpaceDistribution::Alternating),
+        "a  b c d\ne f g  h\ni j k"
+    );
+    assert_eq!(SpaceDistribution::default(), SpaceDistribution::Left);
+}
+
+#[test]
+fn it_justifies_around_non_breaking_spaces() {
+    use array_tool::string::{Justify, WordWrap};
+
+    assert_eq!("aa 10\u{A0}km bb cc".justify(8), "aa 10\u{A0}km\nbb cc");
+    assert_eq!("aa 10\u{A0}km bb cc".word_wrap(8), "aa 10\u{A0}km\nbb cc");
+    assert_eq!(
+        "aa 10\u{A0}km bb".justify_line(20),
+        "aa      10\u{A0}km     bb"
+    );
+    assert_eq!(
+        "aa 10\u{202F}km bb cc dd\u{2007}ee".justify(9),
+        "aa  10\u{202F}km\nbb     cc\ndd\u{2007}ee"
+    );
+}
+
+#[test]
+fn it_justifies_by_display_width() {
+    use array_tool::string::Justify;
+
+    assert_eq!("漢字 かな".justify_line(9), "漢字 かな");
+    assert_eq!("漢字 かな".justify_line(10), "漢字  かな");
+    assert_eq!("e\u{301}e\u{301} a".justify_line(5), "e\u{301}e\u{301}  a");
+    assert_eq!("漢字 かな カナ 日本".justify(11), "漢字   かな\nカナ 日本");
+}
+
+#[test]
+fn it_justifies_with_wrap_options() {
+    use array_tool::string::{Justify, Penalties, SpaceDistribution, WrapAlgorithm, WrapOptions};
+
+    let text = "The quick brown fox jumps over the lazy dog.\n\nAgain the fox.\n";
+    let options = WrapOptions::new(18)
+        .initial_indent("  * ")
+        .subsequent_indent("    ");
+    assert_eq!(
+        text.justify_with_options(&options, SpaceDistribution::Left),
+        "  * The      quick\n    brown      fox\n    jumps over the\n    lazy dog.\n\n    Again the fox.\n"
+    );
+    let optimal = WrapOptions::new(6).algorithm(WrapAlgorithm::OptimalFit(Penalties::new()));
+    assert_eq!("aaa bb cc ddddd".justify(6), "aaa bb\ncc\nddddd");
+    assert_eq!(
+        "aaa bb cc ddddd".justify_with_options(&optimal, SpaceDistribution::Left),
+        "aaa\nbb  cc\nddddd"
+    );
+    assert_eq!(
+        "extra\u{AD}ordinary ideas"
+            .justify_with_options(&WrapOptions::new(8), SpaceDistribution::Left),
+        "extra-\nordinary\nideas"
+    );
+    assert_eq!(
+        "a b c d e f g h i j k"
+            .justify_with_options(&WrapOptions::new(8), SpaceDistribution::Alternating),
+        "a b c d e f g h i j k".justify_with(8, SpaceDistribution::Alternating)
+    );
+}
+
 #[test]
 fn it_substitutes_character_at_each_indexed_point() {
     use array_tool::string::SubstMarks;
@@ -91,6 +553,124 @@ fn it_substitutes_character_at_each_indexed_point() {
     assert_eq!("".subst_marks(vec![0], "Z"), "");
 }
 
+#[test]
+fn it_substitutes_ranges() {
+    use array_tool::string::{Indexing, SubstMarks};
+
+    let text = "Call me Ülla from Köln.";
+    assert_eq!(
+        text.subst_ranges(&[(8..13, "[name]"), (19..24, "[city]")], Indexing::Byte),
+        Ok("Call me [name] from [city].".to_string())
+    );
+    assert_eq!(
+        text.subst_ranges(&[(8..12, "[name]"), (18..22, "[city]")], Indexing::Char),
+        Ok("Call me [name] from [city].".to_string())
+    );
+    assert_eq!(
+        "e\u{301}a\u{308} ok".subst_ranges(&[(0..2, "X")], Indexing::Char),
+        Ok("Xa\u{308} ok".to_string())
+    );
+    assert_eq!(
+        "e\u{301}a\u{308} ok".subst_ranges(&[(0..2, "X")], Indexing::Grapheme),
+        Ok("X ok".to_string())
+    );
+    assert_eq!(
+        "👨\u{200D}👩 a".subst_ranges(&[(1..2, "_")], Indexing::Grapheme),
+        Ok("👨\u{200D}👩_a".to_string())
+    );
+    assert_eq!(
+        "漢字かな".subst_ranges(&[(1..3, "__")], Indexing::Grapheme),
+        Ok("漢__な".to_string())
+    );
+    // Empty ranges insert, and edits may touch.
+    assert_eq!(
+        "abc".subst_ranges(
+            &[(0..0, "<"), (0..1, "A"), (1..1, "-"), (3..3, ">")],
+            Indexing::Char
+        ),
+        Ok("<A-bc>".to_string())
+    );
+    let owned = vec![(0..1, String::from("x")), (2..3, String::from("z"))];
+    assert_eq!(
+        "abc".subst_ranges(&owned, Indexing::Byte),
+        Ok("xbz".to_string())
+    );
+    let none: &[(std::ops::Range<usize>, &str)] = &[];
+    assert_eq!(
+        "abc".subst_ranges(none, Indexing::Byte),
+        Ok("abc".to_string())
+    );
+    assert_eq!(
+        "".subst_ranges(&[(0..0, "x")], Indexing::Grapheme),
+        Ok("x".to_string())
+    );
+    assert_eq!(
+        String::from("abc").subst_ranges(&[(1..2, "")], Indexing::default()),
+        Ok("ac".to_string())
+    );
+}
+
+#[test]
+fn it_rejects_invalid_substitution_ranges() {
+    use array_tool::string::{Indexing, SubstError, SubstMarks};
+
+    let text = "Call me Ülla.";
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = [(3..1, "")];
+    assert_eq!(
+        text.subst_ranges(&reversed, Indexing::Char),
+        Err(SubstError::InvalidRange(0))
+    );
+    assert_eq!(
+        text.subst_ranges(&[(0..4, "A"), (2..6, "B")], Indexing::Char),
+        Err(SubstError::Overlap(1))
+    );
+    assert_eq!(
+        text.subst_ranges(&[(5..7, "A"), (0..4, "B")], Indexing::Char),
+        Err(SubstError::Unsorted(1))
+    );
+    assert_eq!(
+        text.subst_ranges(&[(0..1, "A"), (0..0, "B")], Indexing::Char),
+        Err(SubstError::Unsorted(1))
+    );
+    assert_eq!(
+        text.subst_ranges(&[(8..14, "A")], Indexing::Char),
+        Err(SubstError::OutOfBounds(0))
+    );
+    assert_eq!(
+        text.subst_ranges(&[(13..13, "!")], Indexing::Char),
+        Ok("Call me Ülla.!".to_string())
+    );
+    assert_eq!(
+        text.subst_ranges(&[(0..1, "c"), (9..10, "A")], Indexing::Byte),
+        Err(SubstError::NotCharBoundary(1))
+    );
+    assert_eq!(
+        text.subst_ranges(&[(0..15, "")], Indexing::Byte),
+        Err(SubstError::OutOfBounds(0))
+    );
+    assert_eq!(
+        SubstError::Overlap(1).to_string(),
+        "edit 1 overlaps the edit before it"
+    );
+}
+
+#[test]
+fn it_substitutes_ranges_in_linear_time() {
+    use array_tool::string::{Indexing, SubstMarks};
+
+    let text = "secret ".repeat(200_000);
+    let edits: Vec<_> = (0..200_000).map(|n| (n * 7..n * 7 + 6, "******")).collect();
+    for &indexing in &[Indexing::Byte, Indexing::Char, Indexing::Grapheme] {
+        assert_eq!(
+            text.subst_ranges(&edits, indexing),
+            Ok("****** ".repeat(200_000))
+        );
+    }
+    let marks: Vec<usize> = (0..text.len()).step_by(7).collect();
+    assert_eq!(text.subst_marks(marks, "S"), "Secret ".repeat(200_000));
+}
+
 #[test]
 fn it_seeks_end_of_whitespace_after_offset() {
     use array_tool::string::AfterWhitespace;
@@ -104,6 +684,139 @@ fn it_seeks_end_of_whitespace_after_offset() {
     assert_eq!("asdf".seek_end_of_whitespace(6), None);
 
     assert_eq!("".seek_end_of_whitespace(6), None);
+
+    // Every kind of white space is skipped, and the count is in bytes.
+    assert_eq!("a \t\r\n b".seek_end_of_whitespace(1), Some(5));
+    assert_eq!(
+        "a\u{3000}\u{A0}\u{2003}b".seek_end_of_whitespace(1),
+        Some(8)
+    );
+    // Offsets inside a character find nothing rather than panic.
+    assert_eq!("é  x".seek_end_of_whitespace(1), None);
+    assert_eq!("é  x".seek_end_of_whitespace(2), Some(2));
+}
+
+#[test]
+fn it_seeks_end_of_whitespace_by_chars_and_graphemes() {
+    use array_tool::string::{AfterWhitespace, Indexing};
+
+    let text = "né\t\u{3000} x";
+    assert_eq!(text.seek_end_of_whitespace_with(2, Indexing::Char), Some(3));
+    assert_eq!(
+        text.seek_end_of_whitespace_with(2, Indexing::Grapheme),
+        Some(3)
+    );
+    assert_eq!(
+        "e\u{301}  x".seek_end_of_whitespace_with(1, Indexing::Char),
+        Some(0)
+    );
+    assert_eq!(
+        "e\u{301}  x".seek_end_of_whitespace_with(1, Indexing::Grapheme),
+        Some(2)
+    );
+    assert_eq!(text.seek_end_of_whitespace_with(3, Indexing::Byte), Some(5));
+    assert_eq!(text.seek_end_of_whitespace_with(5, Indexing::Char), Some(0));
+    assert_eq!(text.seek_end_of_whitespace_with(6, Indexing::Char), None);
+    assert_eq!(text.seek_end_of_whitespace_with(7, Indexing::Char), None);
+    assert_eq!(
+        "漢字  かな".seek_end_of_whitespace_with(2, Indexing::Char),
+        Some(2)
+    );
+}
+
+#[test]
+fn it_seeks_start_of_whitespace_before_offset() {
+    use array_tool::string::{AfterWhitespace, Indexing};
+
+    assert_eq!(
+        "asdf           asdf asdf".seek_start_of_whitespace(13),
+        Some(9)
+    );
+    assert_eq!("asdf".seek_start_of_whitespace(3), Some(0));
+    assert_eq!("asdf".seek_start_of_whitespace(4), Some(0));
+    assert_eq!("   asdf".seek_start_of_whitespace(3), None);
+    assert_eq!("asdf".seek_start_of_whitespace(0), None);
+    assert_eq!("asdf".seek_start_of_whitespace(6), None);
+    assert_eq!("".seek_start_of_whitespace(0), None);
+
+    assert_eq!("é\u{3000}\n x".seek_start_of_whitespace(6), Some(4));
+    assert_eq!("é\u{3000}\n x".seek_start_of_whitespace(4), None);
+    assert_eq!(
+        "é\u{3000}\n x".seek_start_of_whitespace_with(3, Indexing::Char),
+        Some(2)
+    );
+    assert_eq!(
+        "né \n x".seek_start_of_whitespace_with(4, Indexing::Grapheme),
+        Some(2)
+    );
+}
+
+#[test]
+fn it_seeks_word_boundaries() {
+    use array_tool::string::{AfterWhitespace, Indexing};
+
+    let text = "Grüße, world!";
+    let next: Vec<_> = (0..14)
+        .map(|offset| text.seek_next_word_boundary(offset, Indexing::Char))
+        .collect();
+    assert_eq!(
+        next,
+        vec![
+            Some(5),
+            Some(4),
+            Some(3),
+            Some(2),
+            Some(1),
+            Some(2),
+            Some(1),
+            Some(5),
+            Some(4),
+            Some(3),
+            Some(2),
+            Some(1),
+            None,
+            None
+        ]
+    );
+    let previous: Vec<_> = (0..14)
+        .map(|offset| text.seek_previous_word_boundary(offset, Indexing::Char))
+        .collect();
+    assert_eq!(
+        previous,
+        vec![
+            None,
+            Some(1),
+            Some(2),
+            Some(3),
+            Some(4),
+            Some(5),
+            Some(1),
+            Some(2),
+            Some(1),
+            Some(2),
+            Some(3),
+            Some(4),
+            Some(5),
+            Some(1)
+        ]
+    );
+
+    // Byte offsets count the same positions in bytes.
+    assert_eq!(text.seek_next_word_boundary(0, Indexing::Byte), Some(7));
+    assert_eq!(text.seek_next_word_boundary(2, Indexing::Byte), Some(5));
+    assert_eq!(text.seek_next_word_boundary(3, Indexing::Byte), None);
+    assert_eq!(
+        text.seek_previous_word_boundary(15, Indexing::Byte),
+        Some(1)
+    );
+
+    // Combining marks stay with their letters, and underscores are part of words.
+    let text = "cafe\u{301} snake_case";
+    assert_eq!(text.seek_next_word_boundary(0, Indexing::Char), Some(5));
+    assert_eq!(text.seek_next_word_boundary(6, Indexing::Char), Some(10));
+    assert_eq!(text.seek_previous_word_boundary(5, Indexing::Char), Some(5));
+    assert_eq!("".seek_next_word_boundary(0, Indexing::Char), None);
+    assert_eq!("  ".seek_next_word_boundary(0, Indexing::Char), None);
 }
 
 #[test]
@@ -136,3 +849,899 @@ fn it_word_wraps_for_string() {
     assert_eq!("\nAA\nA \nA   \n".word_wrap(1), "\nAA\nA \nA   \n");
     assert_eq!("\n \n \n \n     ".word_wrap(1), "\n \n \n \n     ");
 }
+
+#[test]
+fn it_word_wraps_before_new_lines() {
+    use array_tool::string::WordWrap;
+
+    assert_eq!("ab cd\nx".word_wrap(4), "ab\ncd\nx");
+    assert_eq!("ab cd\nef gh\nx".word_wrap(4), "ab\ncd\nef\ngh\nx");
+    assert_eq!("ab\ncd ef gh".word_wrap(5), "ab\ncd ef\ngh");
+    assert_eq!("é è\nx".word_wrap(2), "é\nè\nx");
+}
+
+#[test]
+fn it_word_wraps_by_display_width() {
+    use array_tool::string::WordWrap;
+
+    assert_eq!("漢字 かな カナ".word_wrap(4), "漢字\nかな\nカナ");
+    assert_eq!("漢字 かな カナ".word_wrap(9), "漢字 かな\nカナ");
+    assert_eq!("é é é é".word_wrap(3), "é é\né é");
+    assert_eq!("ééé ééé".word_wrap(4), "ééé\nééé");
+    assert_eq!(
+        "e\u{301}e\u{301} e\u{301}".word_wrap(4),
+        "e\u{301}e\u{301} e\u{301}"
+    );
+    assert_eq!("😀😀 😀😀".word_wrap(4), "😀😀\n😀😀");
+}
+
+#[test]
+fn it_word_wraps_ascii_without_empty_lines() {
+    use array_tool::string::{WordWrap, WrapOptions};
+
+    assert_eq!(" aaa".word_wrap(3), " aaa");
+    assert_eq!("ab\n aa".word_wrap(2), "ab\n aa");
+    assert_eq!("     a".word_wrap(5), "     a");
+    assert_eq!("aa ab".word_wrap(4), "aa ab");
+    assert_eq!("ab cd\n\nef gh ij".word_wrap(5), "ab cd\n\nef gh\nij");
+    assert_eq!(
+        "ab  cd\n\n漢字 ef  "
+            .wrap_lines(&WrapOptions::new(4).indent("> "))
+            .collect::<Vec<_>>(),
+        vec![
+            (0..2, "ab"),
+            (4..6, "cd"),
+            (7..7, ""),
+            (8..11, "漢"),
+            (11..14, "字"),
+            (15..17, "ef")
+        ]
+    );
+}
+
+/// The recursive `word_wrap` the sliding window replaced, kept to check that it gives
+/// the same output.
+fn recursive_word_wrap(text: &str, width: usize) -> String {
+    fn seek_end_of_whitespace(t: &str, offset: usize) -> Option<usize> {
+        t[offset..].bytes().position(|b| b != b' ')
+    }
+    fn wordwrap(t: &str, chunk: usize, offset: usize, mrkrs: &mut Vec<usize>) {
+        let window = &t[offset..std::cmp::min(offset + chunk, t.len())];
+        match window.rfind('\n') {
+            None => match window.rfind(' ') {
+                Some(x) => {
+                    let mut eows = x; // end of white space
+                    if offset + chunk < t.len() {
+                        // check if white space continues
+                        if let Some(a) = seek_end_of_whitespace(t, offset + x) {
+                            if a != 0 {
+                                eows = x + a - 1;
+                            }
+                        }
+                        // safe to seek ahead by 1 or not end of string
+                        if !b"\n ".contains(&t.as_bytes()[offset + eows + 1]) {
+                            mrkrs.push(offset + eows)
+                        }
+                    };
+                    wordwrap(t, chunk, offset + eows + 1, mrkrs)
+                }
+                None => {
+                    if offset + chunk < t.len() {
+                        // String may continue
+                        wordwrap(t, chunk, offset + 1, mrkrs) // Recurse + 1 until next space
+                    }
+                }
+            },
+            Some(x) => wordwrap(t, chunk, offset + x + 1, mrkrs),
+        }
+    }
+    let mut markers = vec![];
+    wordwrap(text, width + 1, 0, &mut markers);
+    let mut output = text.as_bytes().to_vec();
+    for marker in markers {
+        output[marker] = b'\n';
+    }
+    String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn it_word_wraps_as_the_recursive_version_did() {
+    use array_tool::string::WordWrap;
+
+    let cases = [
+        ("", 0),
+        ("a", 0),
+        ("  ", 1),
+        (" aaa", 3),
+        ("ab\n aa", 2),
+        ("     a", 5),
+        ("aa ab", 4),
+        ("aa  ab  ", 2),
+        ("ab cd\n\nef gh ij", 5),
+        ("a\n\n\n b", 1),
+        ("\t.- aa\tb", 3),
+        ("aaaa bbbb cc", 3),
+    ];
+    for &(text, width) in &cases {
+        assert_eq!(
+            text.word_wrap(width),
+            recursive_word_wrap(text, width),
+            "word_wrap({:?}, {})",
+            text,
+            width
+        );
+    }
+
+    let alphabet = b"aab  \n\t.-";
+    let mut seed: u64 = 7;
+    let mut random = |below: usize| {
+        seed = seed
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        (seed >> 33) as usize % below
+    };
+    for _ in 0..300 {
+        let length = random(40);
+        let text: String = (0..length)
+            .map(|_| alphabet[random(alphabet.len())] as char)
+            .collect();
+        let width = random(12);
+        assert_eq!(
+            text.word_wrap(width),
+            recursive_word_wrap(&text, width),
+            "word_wrap({:?}, {})",
+            text,
+            width
+        );
+    }
+}
+
+#[test]
+fn it_word_wraps_multi_megabyte_strings() {
+    use array_tool::string::{WordWrap, WrapOptions};
+
+    // An unbroken base64 blob has nowhere to wrap.
+    let blob = "QUJDREVGR0hJSktMTU5PUFFSU1RVVldYWVo=".repeat(100_000);
+    assert_eq!(blob.word_wrap(76), blob);
+    // Unless words may be broken, when it is cut into lines of 76 columns.
+    let broken = blob.word_wrap_with(&WrapOptions::new(76).break_words(true));
+    assert_eq!(broken.lines().count(), blob.len().div_ceil(76));
+    assert!(broken.lines().all(|line| line.len() <= 76));
+    assert_eq!(broken.replace('\n', ""), blob);
+
+    let words = "lorem ipsum dolor sit amet ".repeat(200_000);
+    let wrapped = words.word_wrap(12);
+    assert_eq!(wrapped.len(), words.len());
+    assert!(wrapped.lines().all(|line| line.trim_end().len() <= 12));
+    assert_eq!(wrapped.replace('\n', " "), words);
+}
+
+#[test]
+fn it_works_on_runtime_owned_and_borrowed_strings() {
+    use array_tool::string::{
+        AfterWhitespace, Count, Delete, DisplayWidth, Justify, Squeeze, SubstMarks, Tr, WordWrap,
+    };
+    use std::borrow::Cow;
+
+    let owned = String::from("asd asdf asd");
+    let borrowed: Cow<str> = Cow::Borrowed("asd asdf asd");
+    let cowned: Cow<str> = Cow::Owned(String::from("asd asdf asd"));
+    let replacement = String::from("Z");
+
+    fn wrap<T: WordWrap + ?Sized>(text: &T) -> String {
+        text.word_wrap(8)
+    }
+    fn squeeze<T: Squeeze + ?Sized>(text: &T, targets: &str) -> String {
+        text.squeeze(targets)
+    }
+
+    assert_eq!(wrap(&owned), "asd asdf\nasd");
+    assert_eq!(wrap(&borrowed), "asd asdf\nasd");
+    assert_eq!(wrap(&cowned), "asd asdf\nasd");
+    assert_eq!(wrap(owned.as_str()), "asd asdf\nasd");
+    assert_eq!(wrap(&&*owned), "asd asdf\nasd");
+    assert_eq!(owned.word_wrap(8), "asd asdf\nasd");
+    assert_eq!(borrowed.word_wrap(8), "asd asdf\nasd");
+
+    assert_eq!(squeeze(&owned, &String::from("s")), "asd asdf asd");
+    assert_eq!(squeeze(&cowned, ""), "asd asdf asd");
+    assert_eq!(owned.subst_marks(vec![0], &replacement), "Zsd asdf asd");
+    assert_eq!(cowned.subst_marks(vec![0], &replacement), "Zsd asdf asd");
+    assert_eq!(owned.justify_line(14), "asd  asdf  asd");
+    assert_eq!(borrowed.justify(8), "asd asdf\nasd");
+    assert_eq!(owned.tr("a", &replacement), "Zsd Zsdf Zsd");
+    assert_eq!(borrowed.delete("s"), "ad adf ad");
+    assert_eq!(cowned.count("a"), 3);
+    assert_eq!(owned.seek_end_of_whitespace(3), Some(1));
+    assert_eq!(owned.display_width(), 12);
+    assert_eq!(borrowed.display_width(), 12);
+    assert_eq!(cowned.display_width_cjk(), 12);
+}
+
+#[test]
+fn it_word_wraps_with_options() {
+    use array_tool::string::{WordWrap, WrapOptions};
+
+    let options = WrapOptions::new(8);
+    assert_eq!("asd asdf asd".word_wrap_with(&options), "asd asdf\nasd");
+    assert_eq!(
+        "01234 67 9 BC EFG IJ".word_wrap_with(&options.clone().width(6)),
+        "01234\n67 9\nBC EFG\nIJ"
+    );
+    assert_eq!("1   34".word_wrap_with(&WrapOptions::new(3)), "1\n34");
+    assert_eq!("1  \n34 ".word_wrap_with(&WrapOptions::new(3)), "1\n34");
+    assert_eq!("a\n\nb".word_wrap_with(&options), "a\n\nb");
+    assert_eq!(
+        "  indented words".word_wrap_with(&options),
+        "  indented\nwords"
+    );
+    assert_eq!("".word_wrap_with(&options), "");
+    assert_eq!("\n".word_wrap_with(&options), "\n");
+}
+
+#[test]
+fn it_word_wraps_with_indents() {
+    use array_tool::string::{WordWrap, WrapOptions};
+
+    let options = WrapOptions::new(16)
+        .initial_indent("- ")
+        .subsequent_indent("  ");
+    assert_eq!(
+        "Wrap long bullet points neatly".word_wrap_with(&options),
+        "- Wrap long\n  bullet points\n  neatly"
+    );
+
+    let options = WrapOptions::new(14).indent("// ");
+    assert_eq!(
+        "A comment which\n\nspans two paragraphs".word_wrap_with(&options),
+        "// A comment\n// which\n//\n// spans two\n// paragraphs"
+    );
+    assert_eq!(
+        "A comment".word_wrap_with(&options.clone().trim(false)),
+        "// A comment"
+    );
+}
+
+#[test]
+fn it_word_wraps_with_long_words() {
+    use array_tool::string::{WordWrap, WrapOptions};
+
+    let options = WrapOptions::new(6);
+    assert_eq!(
+        "0123456789ABC EFG IJ".word_wrap_with(&options),
+        "0123456789ABC\nEFG IJ"
+    );
+
+    let options = options.break_words(true);
+    assert_eq!(
+        "0123456789ABC EFG IJ".word_wrap_with(&options),
+        "012345\n6789AB\nC EFG\nIJ"
+    );
+    assert_eq!(
+        "ab 0123456789".word_wrap_with(&options),
+        "ab 012\n345678\n9"
+    );
+    assert_eq!(
+        "abcde 0123456789".word_wrap_with(&options),
+        "abcde\n012345\n6789"
+    );
+    assert_eq!("a 0123456789".word_wrap_with(&options), "a 0123\n456789");
+    assert_eq!(
+        "漢字漢字漢字".word_wrap_with(&options.clone().width(5)),
+        "漢字\n漢字\n漢字"
+    );
+    assert_eq!(
+        "e\u{301}e\u{301}e\u{301}".word_wrap_with(&options.clone().width(2)),
+        "e\u{301}e\u{301}\ne\u{301}"
+    );
+    assert_eq!("漢字".word_wrap_with(&options.clone().width(1)), "漢\n字");
+    assert_eq!(
+        "\t".word_wrap_with(&options.clone().width(1).indent("> ")),
+        ">"
+    );
+    assert_eq!(
+        "abcdef".word_wrap_with(&options.clone().initial_indent("> ").subsequent_indent("")),
+        "> abcd\nef"
+    );
+}
+
+#[test]
+fn it_word_wraps_at_soft_hyphens() {
+    use array_tool::string::{WordWrap, WrapOptions};
+
+    let text = "extra\u{AD}ordinary ideas";
+    assert_eq!(
+        text.word_wrap_with(&WrapOptions::new(20)),
+        "extraordinary ideas"
+    );
+    assert_eq!(
+        text.word_wrap_with(&WrapOptions::new(8)),
+        "extra-\nordinary\nideas"
+    );
+    assert_eq!(
+        text.word_wrap_with(&WrapOptions::new(5)),
+        "extra-\nordinary\nideas"
+    );
+    assert_eq!(
+        text.word_wrap_with(&WrapOptions::new(8).soft_hyphens(false)),
+        "extra\u{AD}ordinary\nideas"
+    );
+}
+
+#[test]
+fn it_word_wraps_with_trailing_whitespace() {
+    use array_tool::string::{WordWrap, WrapOptions};
+
+    let options = WrapOptions::new(6).trim(false);
+    assert_eq!(
+        "1   34".word_wrap_with(&options.clone().width(3)),
+        "1   \n34"
+    );
+    assert_eq!("ab  cd  ef  ".word_wrap_with(&options), "ab  cd  \nef  ");
+    assert_eq!(
+        "ab  cd  ef  ".word_wrap_with(&options.trim(true)),
+        "ab  cd\nef"
+    );
+}
+
+#[test]
+fn it_word_wraps_with_tabs() {
+    use array_tool::string::{WordWrap, WrapOptions};
+
+    let options = WrapOptions::new(20).tab_width(4);
+    assert_eq!("\tone\ttwo".word_wrap_with(&options), "    one two");
+    assert_eq!(
+        "a\tb".word_wrap_with(&options.clone().indent("> ")),
+        "> a b"
+    );
+    assert_eq!(
+        "\ta\tb".word_wrap_with(&options.clone().indent("> ")),
+        ">   a   b"
+    );
+    assert_eq!(
+        "\tone two three".word_wrap_with(&options.clone().width(12)),
+        "    one two\nthree"
+    );
+    assert_eq!("a\tb".word_wrap_with(&options.clone().tab_width(0)), "ab");
+    assert_eq!("a\tb".word_wrap_with(&WrapOptions::new(20)), "a       b");
+}
+
+#[test]
+fn it_wraps_lines_as_source_slices() {
+    use array_tool::string::{WordWrap, WrapOptions};
+
+    let text = "asd asdf asd";
+    let lines: Vec<_> = text.wrap_lines(&WrapOptions::new(8)).collect();
+    assert_eq!(lines, vec![(0..8, "asd asdf"), (9..12, "asd")]);
+    for (range, line) in lines {
+        assert_eq!(&text[range], line);
+    }
+
+    let text = "ab  cd\n\n漢字 ef  ";
+    let options = WrapOptions::new(6).indent("> ");
+    assert_eq!(
+        text.wrap_lines(&options).collect::<Vec<_>>(),
+        vec![
+            (0..2, "ab"),
+            (4..6, "cd"),
+            (7..7, ""),
+            (8..14, "漢字"),
+            (15..17, "ef")
+        ]
+    );
+    assert_eq!(
+        text.wrap_lines(&options.clone().trim(false))
+            .collect::<Vec<_>>(),
+        vec![
+            (0..4, "ab  "),
+            (4..6, "cd"),
+            (7..7, ""),
+            (8..15, "漢字 "),
+            (15..19, "ef  ")
+        ]
+    );
+    assert_eq!(text.wrap_lines(&options).len(), 5);
+    assert_eq!(text.wrap_lines(&options).next_back(), Some((15..17, "ef")));
+    assert_eq!(
+        "extra\u{AD}ordinary"
+            .wrap_lines(&WrapOptions::new(8))
+            .collect::<Vec<_>>(),
+        vec![(0..5, "extra"), (7..15, "ordinary")]
+    );
+    assert_eq!(
+        String::from("0123456789")
+            .wrap_lines(&WrapOptions::new(4).break_words(true))
+            .map(|(range, _)| range)
+            .collect::<Vec<_>>(),
+        vec![0..4, 4..8, 8..10]
+    );
+    assert_eq!(
+        "".wrap_lines(&options).collect::<Vec<_>>(),
+        vec![(0..0, "")]
+    );
+}
+
+#[test]
+fn it_word_wraps_with_optimal_fit() {
+    use array_tool::string::{Penalties, WordWrap, WrapAlgorithm, WrapOptions};
+
+    let optimal = WrapAlgorithm::OptimalFit(Penalties::new());
+    let options = WrapOptions::new(6).algorithm(optimal);
+    assert_eq!(
+        "aaa bb cc ddddd".word_wrap_with(&WrapOptions::new(6)),
+        "aaa bb\ncc\nddddd"
+    );
+    assert_eq!(
+        "aaa bb cc ddddd".word_wrap_with(&options),
+        "aaa\nbb cc\nddddd"
+    );
+    assert_eq!(
+        "tiny enormousword".word_wrap_with(&options),
+        "tiny\nenormousword"
+    );
+    assert_eq!(
+        "ab 0123456789".word_wrap_with(&options.clone().break_words(true)),
+        "ab\n012345\n6789"
+    );
+    assert_eq!("a\n\nb c".word_wrap_with(&options), "a\n\nb c");
+    assert_eq!("".word_wrap_with(&options), "");
+
+    let text = "Far out in the uncharted backwaters of the unfashionable end of the western \
+                spiral arm of the Galaxy lies a small unregarded yellow sun.";
+    assert_eq!(
+        text.word_wrap_with(&WrapOptions::new(16)),
+        "Far out in the\nuncharted\nbackwaters of\nthe\nunfashionable\nend of the\nwestern spiral\n\
+         arm of the\nGalaxy lies a\nsmall unregarded\nyellow sun."
+    );
+    assert_eq!(
+        text.word_wrap_with(&WrapOptions::new(16).algorithm(optimal)),
+        "Far out in\nthe uncharted\nbackwaters\nof the\nunfashionable\nend of the\nwestern spiral\n\
+         arm of the\nGalaxy lies a\nsmall unregarded\nyellow sun."
+    );
+    assert_eq!(
+        text.wrap_lines(&WrapOptions::new(16).algorithm(optimal))
+            .next(),
+        Some((0..10, "Far out in"))
+    );
+}
+
+#[test]
+fn it_word_wraps_with_optimal_fit_penalties() {
+    use array_tool::string::{Penalties, WordWrap, WrapAlgorithm, WrapOptions};
+
+    let with =
+        |penalties: Penalties| WrapOptions::new(5).algorithm(WrapAlgorithm::OptimalFit(penalties));
+    // A word too long for any line may follow others when running past is cheap.
+    assert_eq!(
+        "a bbbbbbb".word_wrap_with(&with(Penalties::new())),
+        "a\nbbbbbbb"
+    );
+    assert_eq!(
+        "a bbbbbbb".word_wrap_with(&with(Penalties::new().overflow(0))),
+        "a bbbbbbb"
+    );
+    let penalties = Penalties::new().overflow(1000);
+    assert_eq!("a bbbbbbb".word_wrap_with(&with(penalties)), "a\nbbbbbbb");
+    assert_eq!(
+        "a bbbbbbb".word_wrap_with(&with(penalties.line(5000))),
+        "a bbbbbbb"
+    );
+    // Breaking at a soft hyphen only wins when it is cheap enough.
+    let with =
+        |penalties: Penalties| WrapOptions::new(8).algorithm(WrapAlgorithm::OptimalFit(penalties));
+    let text = "abc de\u{AD}fgh";
+    assert_eq!(text.word_wrap_with(&WrapOptions::new(8)), "abc de-\nfgh");
+    assert_eq!(text.word_wrap_with(&with(Penalties::new())), "abc\ndefgh");
+    assert_eq!(
+        text.word_wrap_with(&with(Penalties::new().hyphen(0))),
+        "abc de-\nfgh"
+    );
+    assert_eq!(Penalties::default(), Penalties::new());
+}
+
+#[test]
+fn it_word_wraps_a_unicode_corpus() {
+    use array_tool::string::{WordWrap, WrapOptions};
+
+    // (text, width, word_wrap, word_wrap_with).  Thai has no spaces between words and
+    // needs a dictionary to break, so it only wraps at its spaces.
+    #[rustfmt::skip]
+    let corpus: &[(&str, usize, &str, &str)] = &[
+        ("Ünïcödé wörds wräp bÿ cölümns", 10, "Ünïcödé\nwörds wräp\nbÿ cölümns", "Ünïcödé\nwörds wräp\nbÿ cölümns"),
+        ("Καλημέρα κόσμε, τι κάνεις", 10, "Καλημέρα\nκόσμε, τι\nκάνεις", "Καλημέρα\nκόσμε, τι\nκάνεις"),
+        ("Съешь же ещё этих мягких французских булок", 12, "Съешь же ещё\nэтих мягких\nфранцузских\nбулок", "Съешь же ещё\nэтих мягких\nфранцузских\nбулок"),
+        ("שלום עולם מה שלומך היום", 9, "שלום עולם\nמה שלומך\nהיום", "שלום עולם\nמה שלומך\nהיום"),
+        ("مرحبا بالعالم كيف حالك", 8, "مرحبا\nبالعالم\nكيف حالك", "مرحبا\nبالعالم\nكيف حالك"),
+        ("नमस\u{94d}त\u{947} द\u{941}निया आप क\u{948}स\u{947} ह\u{948}\u{902}", 8, "नमस\u{94d}त\u{947}\nद\u{941}निया आप\nक\u{948}स\u{947} ह\u{948}\u{902}", "नमस\u{94d}त\u{947}\nद\u{941}निया आप\nक\u{948}स\u{947} ह\u{948}\u{902}"),
+        ("สว\u{e31}สด\u{e35}ชาวโลก ย\u{e34}นด\u{e35}ต\u{e49}อนร\u{e31}บ", 6, "สว\u{e31}สด\u{e35}ชาวโลก\nย\u{e34}นด\u{e35}ต\u{e49}อนร\u{e31}บ", "สว\u{e31}สด\u{e35}ชาวโลก\nย\u{e34}นด\u{e35}ต\u{e49}อนร\u{e31}บ"),
+        ("我能吞下玻璃而不伤身体。", 8, "我能吞下\n玻璃而不\n伤身体。", "我能吞下\n玻璃而不\n伤身体。"),
+        ("私はガラスを食べられます。それは私を傷つけません。", 10, "私はガラス\nを食べられ\nます。それ\nは私を傷つ\nけません。", "私はガラス\nを食べられ\nます。それ\nは私を傷つ\nけません。"),
+        ("「こんにちは」と言った。", 6, "「こん\nにち\nは」と\n言っ\nた。", "「こん\nにち\nは」と\n言っ\nた。"),
+        ("ラーメンとチャーシュー", 5, "ラー\nメン\nと\nチャー\nシュー", "ラー\nメン\nと\nチャー\nシュー"),
+        ("나는 유리를 먹을 수 있어요. 그래도 아프지 않아요", 10, "나는 유리\n를 먹을 수\n있어요. 그\n래도 아프\n지 않아요", "나는 유리\n를 먹을 수\n있어요. 그\n래도 아프\n지 않아요"),
+        ("English with 中文字符 mixed in", 10, "English\nwith 中文\n字符 mixed\nin", "English\nwith 中文\n字符 mixed\nin"),
+        ("価格は100円です", 6, "価格は\n100円\nです", "価格は\n100円\nです"),
+        ("e\u{301}e\u{301}e\u{301} a\u{308}a\u{308}", 3, "e\u{301}e\u{301}e\u{301}\na\u{308}a\u{308}", "e\u{301}e\u{301}e\u{301}\na\u{308}a\u{308}"),
+        ("👩\u{200d}👩\u{200d}👧 family 🇯🇵 flag", 8, "👩\u{200d}👩\u{200d}👧\nfamily\n🇯🇵 flag", "👩\u{200d}👩\u{200d}👧\nfamily\n🇯🇵 flag"),
+        ("10\u{a0}km and 20\u{a0}km", 6, "10\u{a0}km\nand\n20\u{a0}km", "10\u{a0}km\nand\n20\u{a0}km"),
+        ("Mr.\u{a0}Smith met Mrs.\u{a0}Jones", 10, "Mr.\u{a0}Smith\nmet\nMrs.\u{a0}Jones", "Mr.\u{a0}Smith\nmet\nMrs.\u{a0}Jones"),
+        ("漢\u{a0}字漢\u{2060}字漢字", 4, "漢\u{a0}字\n漢\u{2060}字\n漢字", "漢\u{a0}字\n漢\u{2060}字\n漢字"),
+        ("zero\u{200b}width\u{200b}space", 6, "zero\u{200b}\nwidth\u{200b}\nspace", "zero\u{200b}\nwidth\u{200b}\nspace"),
+        ("ＦＵＬＬ ｗｉｄｔｈ", 6, "ＦＵＬ\nＬ ｗ\nｉｄｔ\nｈ", "ＦＵＬ\nＬ ｗ\nｉｄｔ\nｈ"),
+        ("ｈａｌｆ ｶﾀｶﾅ ﾃｷｽﾄ", 6, "ｈａｌ\nｆ\nｶﾀｶﾅ\nﾃｷｽﾄ", "ｈａｌ\nｆ\nｶﾀｶﾅ\nﾃｷｽﾄ"),
+        ("\u{3000}全角スペース\u{3000}です", 8, "\u{3000}全角ス\nペース\u{3000}\nです", "\u{3000}全角ス\nペース\nです"),
+        ("(漢字)と[カナ]", 4, "(漢\n字)\nと\n[カ\nナ]", "(漢\n字)\nと\n[カ\nナ]"),
+        ("Ｔｈｅ（括弧）です。", 6, "Ｔｈｅ\n（括\n弧）で\nす。", "Ｔｈｅ\n（括\n弧）で\nす。"),
+        ("ǅemal ǈubljana ǋak", 6, "ǅemal\nǈubljana\nǋak", "ǅemal\nǈubljana\nǋak"),
+        ("ﬁne ﬂour ﬀ", 4, "ﬁne\nﬂour\nﬀ", "ﬁne\nﬂour\nﬀ"),
+        ("a\u{feff}b c", 2, "a\u{feff}b\nc", "a\u{feff}b\nc"),
+    ];
+    for &(text, width, wrapped, wrapped_with) in corpus {
+        assert_eq!(
+            text.word_wrap(width),
+            wrapped,
+            "word_wrap({:?}, {})",
+            text,
+            width
+        );
+        assert_eq!(
+            text.word_wrap_with(&WrapOptions::new(width)),
+            wrapped_with,
+            "word_wrap_with({:?}, {})",
+            text,
+            width
+        );
+    }
+}
+
+#[test]
+fn it_word_wraps_every_unicode_scalar_value() {
+    use array_tool::string::{DisplayWidth, Penalties, WordWrap, WrapAlgorithm, WrapOptions};
+
+    let content = |text: &str| -> String {
+        text.chars()
+            .filter(|&c| !c.is_whitespace() && c != '\u{AD}' && c != '-')
+            .collect()
+    };
+    let characters: Vec<char> = (0..0x11_0000).filter_map(std::char::from_u32).collect();
+    for (block, chars) in characters.chunks(0x4000).enumerate() {
+        let mut text = String::new();
+        for (index, &character) in chars.iter().enumerate() {
+            text.push(character);
+            if index % 7 == 6 {
+                text.push(' ');
+            }
+        }
+        let width = 1 + block % 9;
+        let wrapped = text.word_wrap(width);
+        assert_eq!(
+            wrapped.replace(['\n', ' '], ""),
+            text.replace(['\n', ' '], "")
+        );
+
+        // Each block goes through one of the wrapping engine's configurations.
+        let options = WrapOptions::new(width).break_words(block % 2 == 0);
+        let options = match block % 4 {
+            0 | 1 => options,
+            _ => options.algorithm(WrapAlgorithm::OptimalFit(Penalties::new())),
+        };
+        let mut lines = String::new();
+        for (range, line) in text.wrap_lines(&options) {
+            assert_eq!(&text[range], line);
+            // Only white space at the start of a source line, or a single wide
+            // character, may run past the width.
+            let line = line.trim_start();
+            let visible = line.chars().filter(|c| c.display_width() > 0).count();
+            assert!(
+                block % 2 == 1 || line.display_width() <= width || visible == 1,
+                "{:?}",
+                line
+            );
+            lines.push_str(line);
+        }
+        assert_eq!(content(&lines), content(&text));
+    }
+}
+
+#[test]
+fn it_expands_tabs() {
+    use array_tool::string::{ExpandTabs, TabStops};
+
+    assert_eq!("a\tbc\td".expand_tabs(4), "a   bc  d");
+    assert_eq!("\t\tx".expand_tabs(2), "    x");
+    assert_eq!("abcd\tx".expand_tabs(4), "abcd    x");
+    assert_eq!(
+        "a\tb\r\n\tc\n\td".expand_tabs(8),
+        "a       b\r\n        c\n        d"
+    );
+    assert_eq!("a\tb".expand_tabs(0), "ab");
+    assert_eq!("no tabs".expand_tabs(8), "no tabs");
+    assert_eq!("".expand_tabs(8), "");
+
+    // Columns are counted in display width.
+    assert_eq!("漢字\tx".expand_tabs(8), "漢字    x");
+    assert_eq!("e\u{301}\tx".expand_tabs(4), "e\u{301}   x");
+
+    let stops = TabStops::at(vec![4, 10, 12]);
+    assert_eq!(
+        "\ta\tb\tc\td\te".expand_tabs_with(&stops),
+        "    a     b c d e"
+    );
+    let stops = stops.then_every(4);
+    assert_eq!(
+        "\ta\tb\tc\td\te".expand_tabs_with(&stops),
+        "    a     b c   d   e"
+    );
+    assert_eq!("a\tb".expand_tabs_with(&TabStops::at(vec![])), "a b");
+    assert_eq!(TabStops::default(), TabStops::every(8));
+}
+
+#[test]
+fn it_unexpands_tabs() {
+    use array_tool::string::{ExpandTabs, TabStops};
+
+    assert_eq!("        x = 1;".unexpand_tabs(4), "\t\tx = 1;");
+    assert_eq!("      x".unexpand_tabs(4), "\t  x");
+    assert_eq!("   x".unexpand_tabs(4), "   x");
+    assert_eq!("  \t  x".unexpand_tabs(4), "\t  x");
+    assert_eq!("a       b".unexpand_tabs(4), "a       b");
+    assert_eq!("    a\n\n        b\n".unexpand_tabs(4), "\ta\n\n\t\tb\n");
+    assert_eq!("        ".unexpand_tabs(8), "\t");
+    assert_eq!("".unexpand_tabs(8), "");
+
+    let stops = TabStops::at(vec![2, 6]);
+    assert_eq!("         x".unexpand_tabs_with(&stops), "\t\t   x");
+    let stops = stops.then_every(3);
+    assert_eq!("          x".unexpand_tabs_with(&stops), "\t\t\t x");
+
+    let source = "fn main() {\n\tif x {\n\t\ty = 1;\t// one\n\t}\n}";
+    assert_eq!(
+        source.expand_tabs(4).unexpand_tabs(4),
+        source.replace("\t//", "  //")
+    );
+}
+
+#[test]
+fn it_word_wraps_with_expanded_tabs() {
+    use array_tool::string::{TabStops, WordWrap, WrapOptions};
+
+    let code = "\tif x {\n\t\ty = 1;\n\t}";
+    let options = WrapOptions::new(30).indent("// ");
+    assert_eq!(
+        code.word_wrap_with(&options),
+        "//      if x {\n//              y = 1;\n//      }"
+    );
+    assert_eq!(
+        code.word_wrap_with(&options.clone().expand_tabs(true)),
+        "//         if x {\n//                 y = 1;\n//         }"
+    );
+    assert_eq!(
+        code.word_wrap_with(&options.clone().expand_tabs(true).tab_width(2)),
+        "//   if x {\n//     y = 1;\n//   }"
+    );
+
+    let options = WrapOptions::new(20).tab_stops(TabStops::at(vec![6, 12]));
+    assert_eq!("id\tname\tage".word_wrap_with(&options), "id    name  age");
+    assert_eq!(
+        "id\tname\tage".word_wrap_with(&options.clone().indent("> ")),
+        "> id  name  age"
+    );
+    assert_eq!(
+        "id\tname\tage".word_wrap_with(&options.clone().indent("> ").expand_tabs(true)),
+        "> id    name  age"
+    );
+}
+
+#[test]
+fn it_dedents_text() {
+    use array_tool::string::Indent;
+
+    assert_eq!("    a\n      b\n    c".dedent(), "a\n  b\nc");
+    assert_eq!("    a\n\n      b\n".dedent(), "a\n\n  b\n");
+    assert_eq!("  a\n      \n  b".dedent(), "a\n\nb");
+    assert_eq!("  a\r\n    b\r\n  \r\n".dedent(), "a\r\n  b\r\n\r\n");
+    assert_eq!("\t\ta\n\tb".dedent(), "\ta\nb");
+    assert_eq!("\t a\n\t  b".dedent(), "a\n b");
+    assert_eq!("  a\n\tb".dedent(), "  a\n\tb");
+    assert_eq!("a\n  b".dedent(), "a\n  b");
+    assert_eq!("   \n  ".dedent(), "\n");
+    assert_eq!("".dedent(), "");
+
+    let text = String::from("  é\n   漢");
+    assert_eq!(text.dedent(), "é\n 漢");
+}
+
+#[test]
+fn it_indents_text() {
+    use array_tool::string::Indent;
+
+    assert_eq!("a\nb".indent("  "), "  a\n  b");
+    assert_eq!("a\n\n \nb\n".indent("> "), "> a\n\n \n> b\n");
+    assert_eq!("a\r\nb\r\n".indent("\t"), "\ta\r\n\tb\r\n");
+    assert_eq!("".indent("  "), "");
+
+    assert_eq!("a\n\nb\n".indent_if("# ", |_| true), "# a\n# \n# b\n");
+    assert_eq!("a\r\nb".indent_if("# ", |line| line == "a"), "# a\r\nb");
+    assert_eq!(
+        "x = 1\n# note\ny = 2".indent_if("    ", |line| !line.starts_with('#')),
+        "    x = 1\n# note\n    y = 2"
+    );
+}
+
+#[test]
+fn it_reindents_text() {
+    use array_tool::string::Indent;
+
+    let block = "        if x {\n            y();\n        \n        }\n";
+    assert_eq!(
+        block.reindent("    "),
+        "    if x {\n        y();\n\n    }\n"
+    );
+    assert_eq!(block.reindent(""), block.dedent());
+    assert_eq!(
+        block.reindent("\t").reindent("        "),
+        block.replace("        \n", "\n")
+    );
+    assert_eq!("a\n  b".reindent("  "), "  a\n    b");
+}
+
+#[test]
+fn it_truncates_to_width() {
+    use array_tool::string::{DisplayWidth, Truncate};
+
+    assert_eq!("Ada Lovelace".truncate_to_width(8), "Ada Lov…");
+    assert_eq!("Ada Lovelace".truncate_to_width(12), "Ada Lovelace");
+    assert_eq!("Ada Lovelace".truncate_to_width(1), "…");
+    assert_eq!("Ada Lovelace".truncate_to_width(0), "");
+    assert_eq!("".truncate_to_width(0), "");
+
+    // Wide characters which don't fit leave the column empty.
+    assert_eq!("東京都庁".truncate_to_width(6), "東京…");
+    assert_eq!("東京都庁".truncate_to_width(5), "東京…");
+    assert_eq!("東京都庁".truncate_to_width(4), "東…");
+
+    // Marks, emoji sequences and flags are never split.
+    assert_eq!(
+        "Re\u{301}sume\u{301} done".truncate_to_width(7),
+        "Re\u{301}sume\u{301}…"
+    );
+    assert_eq!("ab👨‍👩‍👧cd".truncate_to_width(4), "ab…");
+    assert_eq!("🇯🇵🇰🇷🇨🇳".truncate_to_width(5), "🇯🇵🇰🇷…");
+    assert_eq!("🇯🇵🇰🇷🇨🇳".truncate_to_width(4), "🇯🇵…");
+    assert_eq!("한국어 이름".truncate_to_width(5), "한국…");
+    assert_eq!(
+        "\u{1100}\u{1161}\u{11A8}\u{1100}\u{1161}xyz".truncate_to_width(3),
+        "\u{1100}\u{1161}\u{11A8}…"
+    );
+
+    for width in 0..20 {
+        let name = "Zoë 👍🏽 Ο'Brien-東";
+        assert!(name.truncate_to_width(width).display_width() <= width);
+    }
+
+    let name = String::from("Grace Hopper");
+    assert_eq!(name.truncate_to_width(6), "Grace…");
+}
+
+#[test]
+fn it_truncates_to_width_at_a_position() {
+    use array_tool::string::{Truncate, TruncatePosition};
+
+    let text = "abcdefghij";
+    assert_eq!(
+        text.truncate_to_width_with(6, "..", TruncatePosition::End),
+        "abcd.."
+    );
+    assert_eq!(
+        text.truncate_to_width_with(6, "..", TruncatePosition::Start),
+        "..ghij"
+    );
+    assert_eq!(
+        text.truncate_to_width_with(6, "..", TruncatePosition::Middle),
+        "ab..ij"
+    );
+    assert_eq!(
+        text.truncate_to_width_with(7, "..", TruncatePosition::Middle),
+        "abc..ij"
+    );
+    assert_eq!(
+        text.truncate_to_width_with(4, "", TruncatePosition::Middle),
+        "abij"
+    );
+    assert_eq!(
+        text.truncate_to_width_with(10, "..", TruncatePosition::Middle),
+        text
+    );
+    assert_eq!(
+        text.truncate_to_width_with(2, "...", TruncatePosition::End),
+        ".."
+    );
+    assert_eq!(
+        text.truncate_to_width_with(3, "...", TruncatePosition::Start),
+        "..."
+    );
+    assert_eq!(TruncatePosition::default(), TruncatePosition::End);
+
+    // Room a wide character can't use goes to the other side.
+    let text = "漢字abc漢字";
+    assert_eq!(
+        text.truncate_to_width_with(6, "…", TruncatePosition::Middle),
+        "漢…字"
+    );
+    assert_eq!(
+        text.truncate_to_width_with(7, "…", TruncatePosition::Middle),
+        "漢…漢字"
+    );
+    assert_eq!(
+        text.truncate_to_width_with(8, "…", TruncatePosition::Middle),
+        "漢字…字"
+    );
+    assert_eq!(
+        text.truncate_to_width_with(8, " → ", TruncatePosition::Start),
+        " → c漢字"
+    );
+    assert_eq!(
+        "e\u{301}e\u{301}e\u{301}".truncate_to_width_with(2, "-", TruncatePosition::Start),
+        "-e\u{301}"
+    );
+}
+
+#[test]
+fn it_measures_grapheme_distances() {
+    use array_tool::string::GraphemeDistance;
+
+    assert_eq!("kitten".levenshtein("sitting"), 3);
+    assert_eq!("".levenshtein("abc"), 3);
+    assert_eq!("Köln".levenshtein("Koln"), 1);
+    assert_eq!("Ko\u{308}ln".levenshtein("Koln"), 1);
+    assert_eq!("👨‍👩‍👧 family".levenshtein("👨‍👩‍👦 family"), 1);
+    assert_eq!("東京都".levenshtein("京都"), 1);
+    assert_eq!("kitten".levenshtein_within("sitting", 3), Some(3));
+    assert_eq!("kitten".levenshtein_within("sitting", 2), None);
+
+    assert_eq!("recieve".damerau_levenshtein("receive"), 1);
+    assert_eq!("recieve".levenshtein("receive"), 2);
+    assert_eq!("e\u{301}a".damerau_levenshtein("ae\u{301}"), 1);
+    assert_eq!("abcd".damerau_levenshtein_within("badc", 1), None);
+
+    assert_eq!("karolin".hamming("kathrin"), 3);
+    assert_eq!("karolin".hamming("karol"), 2);
+    assert_eq!("🇫🇷🇩🇪".hamming("🇫🇷🇮🇹"), 1);
+    assert_eq!("karolin".hamming_within("kathrin", 3), Some(3));
+
+    assert!(("MARTHA".jaro_winkler("MARHTA") - 0.961).abs() < 0.001);
+    assert_eq!("e\u{301}".jaro_winkler("e\u{301}"), 1.0);
+    assert_eq!("abc".jaro_winkler("xyz"), 0.0);
+
+    let name = String::from("Grace Hopper");
+    assert_eq!(name.levenshtein("Grace Hoper"), 1);
+}
+
+#[test]
+fn it_fuzzy_dedups_with_grapheme_distances() {
+    use array_tool::string::GraphemeDistance;
+    use array_tool::vec::{Intersect, Uniq};
+
+    let products = vec![
+        "Crème brûlée",
+        "Creme brulee",
+        "crème brûlée ",
+        "Tarte tatin",
+        "Tarte Tatin",
+        "Éclair",
+    ];
+    let same = |a: &&str, b: &&str| {
+        a.trim()
+            .to_lowercase()
+            .levenshtein_within(&b.trim().to_lowercase(), 3)
+            .is_some()
+    };
+    assert_eq!(
+        products.unique_via(same),
+        vec!["Crème brûlée", "Tarte tatin", "Éclair"]
+    );
+    assert_eq!(
+        vec!["Eclair", "Madeleine"].intersect_if(products, |a, b| a.jaro_winkler(b) > 0.85),
+        vec!["Eclair"]
+    );
+}
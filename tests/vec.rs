@@ -147,7 +147,11 @@ fn it_multiplies() {
 #[test]
 fn it_joins() {
     use array_tool::vec::Join;
-    assert_eq!(vec![1, 2, 3].join(","), "1,2,3")
+    assert_eq!(vec![1, 2, 3].join(","), "1,2,3");
+
+    let joiner = String::from(" | ");
+    assert_eq!(vec![1, 2, 3].join(&joiner), "1 | 2 | 3");
+    assert_eq!(Vec::<i32>::new().join(&joiner), "");
 }
 
 #[test]
@@ -166,3 +170,251 @@ fn it_creates_union() {
         vec![1, 2, 3, 4, 6, 5, 7, 8, 0]
     );
 }
+
+#[test]
+fn it_measures_levenshtein_distance() {
+    use array_tool::vec::EditDistance;
+    assert_eq!([1, 2, 3].levenshtein(&[1, 2, 3]), 0);
+    assert_eq!([1, 2, 3].levenshtein(&[]), 3);
+    assert_eq!(Vec::<i32>::new().levenshtein(&[4, 5]), 2);
+    assert_eq!([1, 2, 3, 4].levenshtein(&[0, 2, 3, 4, 5]), 2);
+    assert_eq!([1, 2, 3, 4].levenshtein(&[2, 1, 3, 4]), 2);
+    assert_eq!(["a", "b"].levenshtein(&["b", "a", "b"]), 1);
+    assert_eq!([1, 2, 3][..].levenshtein(&[3, 2, 1]), 2);
+
+    assert_eq!(
+        [1, 2, 3, 4].levenshtein_within(&[0, 2, 3, 4, 5], 2),
+        Some(2)
+    );
+    assert_eq!([1, 2, 3, 4].levenshtein_within(&[0, 2, 3, 4, 5], 1), None);
+    assert_eq!([1, 2, 3].levenshtein_within(&[1, 2, 3], 0), Some(0));
+    assert_eq!([1, 2, 3].levenshtein_within(&[1, 2, 3, 4, 5], 1), None);
+
+    // Only a narrow band is worked out for a small limit.
+    let a: Vec<u32> = (0..200_000).collect();
+    let mut b = a.clone();
+    b[1000] = 7;
+    b.remove(150_000);
+    assert_eq!(a.levenshtein_within(&b, 2), Some(2));
+    assert_eq!(a.levenshtein_within(&b, 1), None);
+    assert_eq!(a.damerau_levenshtein_within(&b, 3), Some(2));
+}
+
+#[test]
+fn it_measures_damerau_levenshtein_distance() {
+    use array_tool::vec::EditDistance;
+    assert_eq!([1, 2, 3, 4].damerau_levenshtein(&[2, 1, 3, 4]), 1);
+    assert_eq!([1, 2, 3, 4].damerau_levenshtein(&[2, 1, 4, 3]), 2);
+    assert_eq!([1, 2].damerau_levenshtein(&[2, 3, 1]), 3);
+    assert_eq!([1, 2, 3].damerau_levenshtein(&[1, 2, 3]), 0);
+    assert_eq!(Vec::<u8>::new().damerau_levenshtein(&[]), 0);
+    assert_eq!(
+        [1, 2, 3, 4].damerau_levenshtein_within(&[2, 1, 4, 3], 1),
+        None
+    );
+    assert_eq!(
+        [1, 2, 3, 4].damerau_levenshtein_within(&[2, 1, 4, 3], 2),
+        Some(2)
+    );
+}
+
+#[test]
+fn it_measures_hamming_distance() {
+    use array_tool::vec::EditDistance;
+    assert_eq!([1, 0, 1, 1].hamming(&[1, 1, 1, 0]), 2);
+    assert_eq!([1, 0, 1, 1].hamming(&[1, 0, 1, 1]), 0);
+    assert_eq!([1, 0].hamming(&[1, 0, 1, 1]), 2);
+    assert_eq!(Vec::<u8>::new().hamming(&[1]), 1);
+    assert_eq!([1, 0, 1, 1].hamming_within(&[0, 1, 0, 0], 3), None);
+    assert_eq!([1, 0, 1, 1].hamming_within(&[0, 1, 1, 1], 3), Some(2));
+    assert_eq!([1].hamming_within(&[1, 2, 3], 1), None);
+}
+
+#[test]
+fn it_measures_jaro_winkler_similarity() {
+    use array_tool::vec::EditDistance;
+    let chars = |s: &str| s.chars().collect::<Vec<char>>();
+    let close = |a: f64, b: f64| (a - b).abs() < 0.001;
+    assert!(close(chars("MARTHA").jaro_winkler(&chars("MARHTA")), 0.961));
+    assert!(close(chars("DWAYNE").jaro_winkler(&chars("DUANE")), 0.840));
+    assert!(close(
+        chars("DIXON").jaro_winkler(&chars("DICKSONX")),
+        0.813
+    ));
+    assert!(close(chars("abc").jaro_winkler(&chars("xyz")), 0.0));
+    assert!(close(chars("abc").jaro_winkler(&chars("abc")), 1.0));
+    assert!(close(chars("").jaro_winkler(&chars("")), 1.0));
+    assert!(close(chars("a").jaro_winkler(&chars("")), 0.0));
+    assert!(close([1, 2, 3].jaro_winkler(&[1, 2, 3]), 1.0));
+}
+
+#[test]
+fn it_fuzzy_matches_with_edit_distances() {
+    use array_tool::vec::{EditDistance, Intersect, Uniq};
+    let readings = vec![
+        vec![1, 2, 3, 4],
+        vec![1, 2, 4, 3],
+        vec![9, 9, 9, 9],
+        vec![1, 2, 3],
+    ];
+    assert_eq!(
+        readings.unique_via(|a, b| a.damerau_levenshtein_within(b, 1).is_some()),
+        vec![vec![1, 2, 3, 4], vec![9, 9, 9, 9]]
+    );
+    assert_eq!(
+        readings.intersect_if(vec![vec![9, 9, 9, 8]], |a, b| a
+            .hamming_within(b, 1)
+            .is_some()),
+        vec![vec![9, 9, 9, 9]]
+    );
+}
+
+#[test]
+fn it_diffs_into_hunks() {
+    use array_tool::vec::{Diff, DiffHunk};
+    assert_eq!(
+        ['a', 'b', 'c', 'd'].diff(&['a', 'x', 'c', 'd', 'e']),
+        vec![
+            DiffHunk::Keep {
+                old: 0..1,
+                new: 0..1
+            },
+            DiffHunk::Delete { old: 1..2, new: 1 },
+            DiffHunk::Insert { old: 2, new: 1..2 },
+            DiffHunk::Keep {
+                old: 2..4,
+                new: 2..4
+            },
+            DiffHunk::Insert { old: 4, new: 4..5 },
+        ]
+    );
+    assert_eq!(
+        [1, 2, 3].diff(&[]),
+        vec![DiffHunk::Delete { old: 0..3, new: 0 }]
+    );
+    assert_eq!(
+        Vec::<u8>::new().diff(&[1, 2]),
+        vec![DiffHunk::Insert { old: 0, new: 0..2 }]
+    );
+    assert_eq!(Vec::<u8>::new().diff(&[]), vec![]);
+    assert_eq!(
+        [1, 2, 3].diff(&[1, 2, 3]),
+        vec![DiffHunk::Keep {
+            old: 0..3,
+            new: 0..3
+        }]
+    );
+    assert_eq!(
+        [1, 2, 3, 4, 5, 6].diff(&[0, 2, 3, 5, 6, 7]),
+        vec![
+            DiffHunk::Delete { old: 0..1, new: 0 },
+            DiffHunk::Insert { old: 1, new: 0..1 },
+            DiffHunk::Keep {
+                old: 1..3,
+                new: 1..3
+            },
+            DiffHunk::Delete { old: 3..4, new: 3 },
+            DiffHunk::Keep {
+                old: 4..6,
+                new: 3..5
+            },
+            DiffHunk::Insert { old: 6, new: 5..6 },
+        ]
+    );
+}
+
+#[test]
+fn it_diffs_long_sequences_with_few_changes() {
+    use array_tool::vec::{Diff, DiffHunk};
+    let old: Vec<u32> = (0..100_000).collect();
+    let mut new = old.clone();
+    new[500] = 1_000_000;
+    new.remove(70_000);
+    let hunks = old.diff(&new);
+    assert_eq!(hunks.len(), 6);
+    assert_eq!(
+        hunks[4],
+        DiffHunk::Delete {
+            old: 70_000..70_001,
+            new: 70_000
+        }
+    );
+}
+
+#[test]
+fn it_diffs_via_a_comparator() {
+    use array_tool::vec::{Diff, DiffHunk};
+    assert_eq!(
+        ["Apple", "pear"].diff_via(&["apple", "Pear"], |l, r| l.eq_ignore_ascii_case(r)),
+        vec![DiffHunk::Keep {
+            old: 0..2,
+            new: 0..2
+        }]
+    );
+    assert_eq!(
+        [1, 12, 23].diff_via(&[11, 2, 33], |l, r| l % 10 == r % 10),
+        vec![DiffHunk::Keep {
+            old: 0..3,
+            new: 0..3
+        }]
+    );
+}
+
+#[test]
+fn it_renders_unified_diffs() {
+    use array_tool::vec::Diff;
+    let old: Vec<String> = "a b c d e f g h i j".split(' ').map(String::from).collect();
+    let mut new = old.clone();
+    new[1] = String::from("B");
+    new.insert(8, String::from("x"));
+    assert_eq!(
+        old.unified_diff(&new, 1),
+        "@@ -1,3 +1,3 @@\n a\n-b\n+B\n c\n@@ -8,2 +8,3 @@\n h\n+x\n i\n"
+    );
+    assert_eq!(
+        old.unified_diff(&new, 2),
+        "@@ -1,4 +1,4 @@\n a\n-b\n+B\n c\n d\n@@ -7,4 +7,5 @@\n g\n h\n+x\n i\n j\n"
+    );
+    assert_eq!(
+        old.unified_diff(&new, 3),
+        "@@ -1,10 +1,11 @@\n a\n-b\n+B\n c\n d\n e\n f\n g\n h\n+x\n i\n j\n"
+    );
+    assert_eq!(["a"].unified_diff(&["x", "a"], 0), "@@ -0,0 +1 @@\n+x\n");
+    assert_eq!(["a", "b"].unified_diff(&["a"], 0), "@@ -2 +1,0 @@\n-b\n");
+    assert_eq!(["a"].unified_diff(&["a"], 3), "");
+    assert_eq!(Vec::<&str>::new().unified_diff(&[], 3), "");
+}
+
+#[test]
+fn it_transposes_ragged_rows() {
+    use array_tool::vec::Transpose;
+    let per_host = [vec![0.5, 0.7, 0.9], vec![0.1], vec![0.2, 0.4]];
+    assert_eq!(
+        per_host.transpose_ragged(),
+        vec![
+            vec![Some(0.5), Some(0.1), Some(0.2)],
+            vec![Some(0.7), None, Some(0.4)],
+            vec![Some(0.9), None, None],
+        ]
+    );
+    assert_eq!(
+        [vec![1, 2], vec![3, 4]].transpose_ragged(),
+        vec![vec![Some(1), Some(3)], vec![Some(2), Some(4)]]
+    );
+    assert_eq!(
+        Vec::<Vec<u8>>::new().transpose_ragged(),
+        Vec::<Vec<Option<u8>>>::new()
+    );
+    assert_eq!(
+        [vec![], vec![1]].transpose_ragged(),
+        vec![vec![None, Some(1)]]
+    );
+}
+
+#[test]
+fn it_joins_through_intersperse_items() {
+    use array_tool::vec::Join;
+    assert_eq!(vec![1, 2, 3].join(", "), "1, 2, 3");
+    assert_eq!(vec!["a"].join(","), "a");
+    assert_eq!(Vec::<u8>::new().join(","), "");
+}